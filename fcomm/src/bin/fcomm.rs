@@ -10,7 +10,7 @@ use pairing_lib::{Engine, MultiMillerLoop};
 use serde::de::DeserializeOwned;
 use serde::{Deserialize, Serialize};
 
-use lurk::eval::IO;
+use lurk::eval::{Oracle, IO};
 use lurk::field::LurkField;
 use lurk::store::{Ptr, Store};
 
@@ -18,8 +18,8 @@ use clap::{AppSettings, Args, Parser, Subcommand};
 use clap_verbosity_flag::{Verbosity, WarnLevel};
 
 use fcomm::{
-    self, committed_function_store, evaluate, Claim, Commitment, Error, Evaluation, Expression,
-    FileStore, Function, LurkPtr, Opening, OpeningRequest, Proof,
+    self, committed_function_store, evaluate, witness_oracle, Claim, Commitment, Error, Evaluation,
+    Expression, FileStore, Function, LurkPtr, Opening, OpeningRequest, Proof,
 };
 
 /// Functional commitments
@@ -129,6 +129,10 @@ struct Eval {
     // Expression is lurk source.
     #[clap(long, value_parser)]
     lurk: bool,
+
+    /// Path to the values of witness and read forms, as an association list of names and values
+    #[clap(long, value_parser)]
+    witnesses: Option<PathBuf>,
 }
 
 #[derive(Args, Debug)]
@@ -148,6 +152,10 @@ struct Prove {
     // Expression is lurk source.
     #[clap(long, value_parser)]
     lurk: bool,
+
+    /// Path to the values of witness and read forms, as an association list of names and values
+    #[clap(long, value_parser)]
+    witnesses: Option<PathBuf>,
 }
 
 #[derive(Args, Debug)]
@@ -291,8 +299,16 @@ impl Eval {
         let s = &mut Store::<Scalar>::default();
 
         let expr = expression(s, &self.expression, self.lurk)?;
+        let mut oracle = witnesses(s, self.witnesses.as_ref())?;
 
-        let evaluation = Evaluation::eval(s, expr, limit);
+        let evaluation = Evaluation::eval(
+            s,
+            expr,
+            limit,
+            oracle
+                .as_mut()
+                .map(|oracle| oracle as &mut dyn Oracle<Scalar>),
+        );
 
         match &self.claim {
             Some(out_path) => {
@@ -311,6 +327,10 @@ impl Eval {
 impl Prove {
     fn prove(&self, limit: usize) -> Result<(), Error> {
         let s = &mut Store::<Scalar>::default();
+        let mut oracle = witnesses(s, self.witnesses.as_ref())?;
+        let oracle = oracle
+            .as_mut()
+            .map(|oracle| oracle as &mut dyn Oracle<Scalar>);
 
         let proof = match &self.claim {
             Some(claim) => {
@@ -318,7 +338,7 @@ impl Prove {
                     self.expression.is_none(),
                     "claim and expression must not both be supplied"
                 );
-                Proof::prove_claim(s, Claim::read_from_path(claim)?, limit, false)?
+                Proof::prove_claim(s, Claim::read_from_path(claim)?, limit, false, oracle)?
             }
 
             None => {
@@ -328,7 +348,7 @@ impl Prove {
                     self.lurk,
                 )?;
 
-                Proof::eval_and_prove(s, expr, limit, false)?
+                Proof::eval_and_prove(s, expr, limit, false, oracle)?
             }
         };
 
@@ -368,6 +388,20 @@ fn read_from_path<P: AsRef<Path>, F: LurkField + Serialize>(
     Ok(src)
 }
 
+// Reads the oracle supplying the values of witness and read forms, if there is one.
+fn witnesses<P: AsRef<Path>, F: LurkField + Serialize>(
+    store: &mut Store<F>,
+    witnesses_path: Option<P>,
+) -> Result<Option<impl FnMut(&mut Store<F>, Ptr<F>) -> Option<Ptr<F>>>, Error> {
+    match witnesses_path {
+        Some(path) => {
+            let witnesses = read_from_path(store, path)?;
+            Ok(Some(witness_oracle(store, witnesses)))
+        }
+        None => Ok(None),
+    }
+}
+
 fn read_eval_from_path<P: AsRef<Path>, F: LurkField + Serialize>(
     store: &mut Store<F>,
     path: P,
@@ -381,6 +415,7 @@ fn read_eval_from_path<P: AsRef<Path>, F: LurkField + Serialize>(
             cont: _,
         },
         _iterations,
+        _emitted,
     ) = evaluate(store, src, limit);
 
     Ok((expr, src))
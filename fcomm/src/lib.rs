@@ -7,7 +7,7 @@ use std::str::FromStr;
 
 use bellperson::{groth16, SynthesisError};
 use blstrs::{Bls12, Scalar};
-use ff::PrimeField;
+use ff::{Field, PrimeField};
 use hex::FromHex;
 use libipld::{
     cbor::DagCborCodec,
@@ -18,15 +18,15 @@ use libipld::{
     Cid, Ipld,
 };
 use lurk::{
-    circuit::ToInputs,
-    eval::{empty_sym_env, Evaluable, Evaluator, Status, IO},
+    circuit::public_inputs_with_emitted,
+    eval::{empty_sym_env, Evaluable, Evaluator, Oracle, ReductionError, Status, IO},
     field::LurkField,
     proof::{
         self,
         groth16::{Groth16, Groth16Prover, INNER_PRODUCT_SRS},
     },
     scalar_store::ScalarStore,
-    store::{Pointer, Ptr, ScalarPointer, ScalarPtr, Store, Tag},
+    store::{Continuation, ErrorCode, Pointer, Ptr, ScalarPointer, ScalarPtr, Store, Tag},
     writer::Write,
 };
 use once_cell::sync::OnceCell;
@@ -92,6 +92,19 @@ pub struct Evaluation {
     pub cont_out: String,
     pub status: Status,
     pub iterations: Option<usize>,
+    /// The hashes of the values emitted during evaluation, in order, as CIDs. Unlike their
+    /// printed form, these can be checked for any value, such as a function.
+    #[serde(default, skip_serializing_if = "Vec::is_empty")]
+    pub emitted: Vec<Cid>,
+    /// Why evaluation failed, if it did.
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub error_code: Option<ErrorCode>,
+    /// The offending expression recorded by the error continuation.
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub error_expr: Option<String>,
+    /// The hash of the expression the proof is about, if `expr` expands into another one.
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub expansion: Option<String>,
 }
 
 #[derive(Clone, Copy, Debug, Eq, PartialEq)]
@@ -172,6 +185,9 @@ pub struct Opening<F: LurkField> {
     pub status: Status,
     pub commitment: Commitment<F>,
     pub new_commitment: Option<Commitment<F>>,
+    /// The hashes of the values emitted while applying the function, in order, as CIDs.
+    #[serde(default, skip_serializing_if = "Vec::is_empty")]
+    pub emitted: Vec<Cid>,
 }
 
 #[derive(Serialize, Deserialize, Clone, Debug, Eq, PartialEq)]
@@ -327,6 +343,7 @@ pub enum Error {
     IOError(io::Error),
     JsonError(serde_json::Error),
     SynthesisError(SynthesisError),
+    ReductionError(ReductionError),
     CommitmentParseError(hex::FromHexError),
     UnknownCommitment,
     OpeningFailure,
@@ -348,6 +365,11 @@ impl From<SynthesisError> for Error {
         Error::SynthesisError(err)
     }
 }
+impl From<ReductionError> for Error {
+    fn from(err: ReductionError) -> Error {
+        Error::ReductionError(err)
+    }
+}
 
 pub trait Id
 where
@@ -423,18 +445,19 @@ impl Evaluation {
         s: &mut Store<F>,
         input: IO<F>,
         output: IO<F>,
+        emitted: &[Ptr<F>],
         iterations: Option<usize>, // This might be padded, so is not quite 'iterations' in the sense of number of actual reduction steps required
                                    // to evaluate.
     ) -> Self {
         let status: Status = output.cont.into();
-        let terminal = status.is_terminal();
+        let complete = status.is_complete();
 
-        // For now, conservatively hide all outputs unless output is terminal. TODO: let evaluator configure this in a
-        // more fine-grained way, including no hiding.
+        // For now, conservatively hide all outputs unless evaluation is complete. TODO: let evaluator configure this
+        // in a more fine-grained way, including no hiding.
         // NOTE: If anything is hidden, a proof won't be possible.
         macro_rules! maybe_hide {
             ($x:expr) => {
-                if terminal {
+                if complete {
                     $x
                 } else {
                     "".to_string()
@@ -450,6 +473,22 @@ impl Evaluation {
         let env_out = maybe_hide!(output.env.fmt_to_string(s));
         let cont_out = maybe_hide!(output.cont.fmt_to_string(s));
 
+        let emitted = emitted_cids(s, emitted);
+
+        // Bind the claim to the expression its proof starts from, which the verifier checks.
+        let expansion = s
+            .hash_expansion(input.expr)
+            .filter(|(expr_hash, expansion_hash)| expr_hash != expansion_hash)
+            .map(|(_, expansion_hash)| hash_to_hex(expansion_hash.value()));
+
+        // The error continuation is not readable from cont_out, so its parts are kept to rebuild it.
+        let (error_code, error_expr) = match s.fetch_cont(&output.cont) {
+            Some(Continuation::Error { code, expr }) if complete => {
+                (Some(code), Some(expr.fmt_to_string(s)))
+            }
+            _ => (None, None),
+        };
+
         Self {
             expr,
             env,
@@ -459,22 +498,33 @@ impl Evaluation {
             cont_out,
             status,
             iterations,
+            emitted,
+            error_code,
+            error_expr,
+            expansion,
         }
     }
 
+    /// Evaluates `expr`, reading the values of its `witness` and `read` forms from `oracle`.
     pub fn eval<F: LurkField + Serialize>(
         store: &mut Store<F>,
         expr: Ptr<F>,
         limit: usize,
+        oracle: Option<&mut dyn Oracle<F>>,
     ) -> Self {
         let env = empty_sym_env(store);
-        let mut evaluator = Evaluator::new(expr, env, store, limit);
-
-        let input = evaluator.initial();
+        let cont = store.intern_cont_outermost();
+        // The claim is about `expr` as written. Its verifier expands it again, as the evaluator
+        // does, to find the input the proof is about.
+        let input = IO { expr, env, cont };
 
-        let (output, iterations, _) = evaluator.eval();
+        let mut evaluator = Evaluator::new(expr, env, store, limit);
+        if let Some(oracle) = oracle {
+            evaluator = evaluator.with_oracle(oracle);
+        }
+        let (output, iterations, emitted) = evaluator.eval();
 
-        Self::new(store, input, output, Some(iterations))
+        Self::new(store, input, output, &emitted, Some(iterations))
     }
 }
 
@@ -546,7 +596,7 @@ impl<F: LurkField + Serialize + DeserializeOwned> Function<F> {
         let source_ptr = self.fun.ptr(s);
 
         // Evaluate the source to get an actual function.
-        let (output, _iterations) = evaluate(s, source_ptr, limit);
+        let (output, _iterations, _emitted) = evaluate(s, source_ptr, limit);
         // TODO: Verify that result actually is a function.
 
         output.expr
@@ -592,7 +642,7 @@ impl Expression {
         limit: usize,
     ) -> Result<Ptr<F>, Error> {
         let expr = self.expr.ptr(s);
-        let (io, _iterations) = evaluate(s, expr, limit);
+        let (io, _iterations, _emitted) = evaluate(s, expr, limit);
 
         Ok(io.expr)
     }
@@ -609,7 +659,7 @@ impl Opening<Scalar> {
     ) -> Result<Proof<Bls12>, Error> {
         let claim = Self::apply(s, input, function, limit, chain)?;
 
-        Proof::prove_claim(s, claim, limit, only_use_cached_proofs)
+        Proof::prove_claim(s, claim, limit, only_use_cached_proofs, None)
     }
 
     pub fn open_and_prove(
@@ -680,7 +730,7 @@ impl Opening<Scalar> {
 
         let (commitment, expression) =
             Commitment::construct_with_fun_application(s, function, input, limit);
-        let (public_output, _iterations) = evaluate(s, expression, limit);
+        let (public_output, _iterations, emitted) = evaluate(s, expression, limit);
 
         let (new_commitment, output_expr) = if chain {
             // FIXME: update for explicit commitments.
@@ -753,6 +803,7 @@ impl Opening<Scalar> {
             input: input_string,
             output: output_string,
             status,
+            emitted: emitted_cids(s, &emitted),
         });
 
         Ok(claim)
@@ -760,28 +811,40 @@ impl Opening<Scalar> {
 }
 
 impl Proof<Bls12> {
+    /// Evaluates and proves `expr`, reading the values of its `witness` and `read` forms from
+    /// `oracle`. The values are private: the claim only records the output.
     pub fn eval_and_prove(
         s: &mut Store<Scalar>,
         expr: Ptr<Scalar>,
         limit: usize,
         only_use_cached_proofs: bool,
+        mut oracle: Option<&mut dyn Oracle<Scalar>>,
     ) -> Result<Self, Error> {
         let env = empty_sym_env(s);
         let cont = s.intern_cont_outermost();
         let input = IO { expr, env, cont };
 
-        let (public_output, _iterations) = evaluate(s, expr, limit);
-        let evaluation = Evaluation::new(s, input, public_output, None);
+        // An evaluation which ends in an error is proved like any other complete one, but one
+        // which cannot be reduced cannot be proved at all.
+        let mut evaluator = Evaluator::new(expr, env, s, limit);
+        if let Some(oracle) = oracle.as_mut() {
+            evaluator = evaluator.with_oracle(&mut **oracle);
+        }
+        let (public_output, _iterations, emitted) = evaluator.try_eval()?;
+        let evaluation = Evaluation::new(s, input, public_output, &emitted, None);
         let claim = Claim::Evaluation(evaluation);
 
-        Self::prove_claim(s, claim, limit, only_use_cached_proofs)
+        Self::prove_claim(s, claim, limit, only_use_cached_proofs, oracle)
     }
 
+    /// Proves `claim`. An evaluation claim whose expression has `witness` or `read` forms needs
+    /// the `oracle` which supplied their values when it was made.
     pub fn prove_claim(
         s: &mut Store<Scalar>,
         claim: Claim<Scalar>,
         limit: usize,
         only_use_cached_proofs: bool,
+        oracle: Option<&mut dyn Oracle<Scalar>>,
     ) -> Result<Self, Error> {
         let rng = OsRng;
         let proof_map = bls12_proof_cache();
@@ -826,9 +889,16 @@ impl Proof<Bls12> {
             }
         };
 
-        let (groth_proof, _public_input, public_output) = groth_prover
-            .outer_prove(groth_params, &INNER_PRODUCT_SRS, expr, env, s, limit, rng)
-            .expect("Groth proving failed");
+        let (groth_proof, _public_input, public_output, _emitted) = groth_prover.outer_prove(
+            groth_params,
+            &INNER_PRODUCT_SRS,
+            expr,
+            env,
+            s,
+            limit,
+            oracle,
+            rng,
+        )?;
         assert!(public_output.is_complete());
 
         let proof = Proof {
@@ -844,7 +914,7 @@ impl Proof<Bls12> {
                 };
             }
             Claim::Evaluation(e) => {
-                if e.status != Status::Terminal {
+                if !e.status.is_complete() {
                     return Err(Error::EvaluationFailure);
                 };
             }
@@ -903,28 +973,52 @@ impl Proof<Bls12> {
                 .read(&evaluation.env)
                 .ok_or_else(|| Error::VerificationError("failed to read env".into()))?;
 
+            // The proof is about the expression the evaluator starts from, once it has expanded
+            // the one claimed.
             // FIXME: We ignore cont and assume Outermost, since we can't read a Cont.
-            let cont = s.intern_cont_outermost();
+            let input_io = Evaluator::new(expr, env, &mut s, 0).initial();
+
+            let input_hash = s
+                .hash_expr(&input_io.expr)
+                .ok_or_else(|| Error::VerificationError("failed to hash expr".into()))?;
+            let expansion_matches = match &evaluation.expansion {
+                Some(expansion) => *expansion == hash_to_hex(input_hash.value()),
+                None => input_io.expr == expr,
+            };
+            if !expansion_matches {
+                return Err(Error::VerificationError(
+                    "expansion does not match expr".into(),
+                ));
+            }
 
-            IO::<Scalar> { expr, env, cont }
+            input_io
         };
 
-        let public_inputs = input_io.to_inputs(&s);
+        // Nothing has been emitted before evaluation starts.
+        let public_inputs = public_inputs_with_emitted(&input_io, Scalar::zero(), &s);
 
         let output_io = {
             let expr = s
                 .read(&evaluation.expr_out)
                 .ok_or_else(|| Error::VerificationError("failed to read expr_out".into()))?;
             let env = s.read(&evaluation.env_out).expect("failed to read env_out");
-            let cont = evaluation
-                .status
-                .to_cont(&mut s)
-                .ok_or_else(|| Error::VerificationError("continuation cannot be proved".into()))?;
+            let cont = match (evaluation.error_code, &evaluation.error_expr) {
+                (Some(code), Some(error_expr)) if evaluation.status.is_error() => {
+                    let error_expr = s.read(error_expr).ok_or_else(|| {
+                        Error::VerificationError("failed to read error_expr".into())
+                    })?;
+                    s.intern_cont_error_with(code, error_expr)
+                }
+                _ => evaluation.status.to_cont(&mut s).ok_or_else(|| {
+                    Error::VerificationError("continuation cannot be proved".into())
+                })?,
+            };
 
             IO::<Scalar> { expr, env, cont }
         };
 
-        let public_outputs = output_io.to_inputs(&s);
+        let emitted_hash = emitted_hash(&s, &evaluation.emitted)?;
+        let public_outputs = public_inputs_with_emitted(&output_io, emitted_hash, &s);
 
         Ok((public_inputs, public_outputs))
     }
@@ -956,6 +1050,7 @@ impl Proof<Bls12> {
             *empty_env.value(),
             *cont.tag(),
             *cont.value(),
+            Scalar::zero(),
         ];
 
         let output_io = IO::<Scalar> {
@@ -964,7 +1059,8 @@ impl Proof<Bls12> {
             cont: s.intern_cont_terminal(),
         };
 
-        let public_outputs = output_io.to_inputs(&s);
+        let emitted_hash = emitted_hash(&s, &opening.emitted)?;
+        let public_outputs = public_inputs_with_emitted(&output_io, emitted_hash, &s);
 
         Ok((public_inputs, public_outputs))
     }
@@ -988,14 +1084,73 @@ impl VerificationResult {
     }
 }
 
-pub fn evaluate<F: LurkField>(store: &mut Store<F>, expr: Ptr<F>, limit: usize) -> (IO<F>, usize) {
+pub fn evaluate<F: LurkField>(
+    store: &mut Store<F>,
+    expr: Ptr<F>,
+    limit: usize,
+) -> (IO<F>, usize, Vec<Ptr<F>>) {
     let env = empty_sym_env(store);
     let mut evaluator = Evaluator::new(expr, env, store, limit);
 
-    let (io, iterations, _) = evaluator.eval();
+    let (io, iterations, emitted) = evaluator.eval();
 
     assert!(io.is_terminal());
-    (io, iterations)
+    (io, iterations, emitted)
+}
+
+/// Returns an oracle supplying the values of `witness` and `read` forms from `witnesses`, an
+/// association list of names and values such as `((:secret . 42))`. `(read)` reads the value of
+/// `nil`.
+pub fn witness_oracle<F: LurkField>(
+    s: &Store<F>,
+    mut witnesses: Ptr<F>,
+) -> impl FnMut(&mut Store<F>, Ptr<F>) -> Option<Ptr<F>> {
+    let mut values = Vec::new();
+    while witnesses.is_cons() && !witnesses.is_opaque() {
+        let (binding, more) = s.car_cdr(&witnesses);
+        if binding.is_cons() && !binding.is_opaque() {
+            values.push(s.car_cdr(&binding));
+        }
+        witnesses = more;
+    }
+
+    move |_: &mut Store<F>, name: Ptr<F>| {
+        values
+            .iter()
+            .find(|(witness, _)| *witness == name)
+            .map(|(_, value)| *value)
+    }
+}
+
+// Writes a hash as hex, the way a commitment is written.
+fn hash_to_hex<F: LurkField>(hash: &F) -> String {
+    let be_bytes: Vec<u8> = hash.to_repr().as_ref().iter().rev().copied().collect();
+    hex::encode(be_bytes)
+}
+
+// Returns the hashes of emitted values as CIDs, the way a claim records them.
+fn emitted_cids<F: LurkField>(s: &Store<F>, emitted: &[Ptr<F>]) -> Vec<Cid> {
+    emitted
+        .iter()
+        .map(|e| {
+            let hash = s.hash_expr(e).expect("failed to hash emitted value");
+            F::to_cid(*hash.tag(), *hash.value()).expect("emitted value has no CID")
+        })
+        .collect()
+}
+
+// Returns the hash a proof binds the emitted values of a claim to.
+fn emitted_hash<F: LurkField>(s: &Store<F>, emitted: &[Cid]) -> Result<F, Error> {
+    let emitted = emitted
+        .iter()
+        .map(|cid| {
+            let (tag, value) = F::from_cid(*cid)?;
+            Some(ScalarPtr::from_parts(tag, value))
+        })
+        .collect::<Option<Vec<_>>>()
+        .ok_or_else(|| Error::VerificationError("invalid emitted value hash".into()))?;
+
+    Ok(s.emitted_scalars_hash(&emitted))
 }
 
 #[cfg(test)]
@@ -1025,4 +1180,34 @@ mod test {
         let cert_again: Cert = serde_json::from_str(&string).unwrap();
         assert_eq!(cert, cert_again);
     }
+
+    #[test]
+    fn test_emitted_hash() {
+        let s = &mut Store::<Scalar>::default();
+        let expr = s.read("(begin (emit (lambda (x) x)) (emit 1) 2)").unwrap();
+        let (_, _, emitted) = evaluate(s, expr, 1000);
+        let evaluation = Evaluation::eval(s, expr, 1000, None);
+
+        // A claim records the hashes of emitted values, which it can be verified against even
+        // when the values, like functions, cannot be read back.
+        let json = serde_json::to_string(&evaluation).unwrap();
+        let evaluation: Evaluation = serde_json::from_str(&json).unwrap();
+        assert_eq!(2, evaluation.emitted.len());
+        assert_eq!(
+            s.emitted_hash(&emitted).unwrap(),
+            emitted_hash(s, &evaluation.emitted).unwrap()
+        );
+    }
+
+    #[test]
+    fn test_eval_and_prove_opaque() {
+        let s = &mut Store::<Scalar>::default();
+        let expr = s.read("(open 123)").unwrap();
+
+        // Opening an unknown commitment cannot be proved, not even as an error.
+        assert!(matches!(
+            Proof::eval_and_prove(s, expr, 1000, false, None),
+            Err(Error::ReductionError(ReductionError::Opaque))
+        ));
+    }
 }
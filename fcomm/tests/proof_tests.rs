@@ -9,7 +9,7 @@ use tempdir::TempDir;
 use blstrs::{Bls12, Scalar};
 
 use fcomm::{Commitment, FileStore, Function, LurkPtr, Proof};
-use lurk::store::Store;
+use lurk::store::{ErrorCode, Store};
 
 fn fcomm_cmd() -> std::process::Command {
     Command::cargo_bin("fcomm").unwrap()
@@ -47,6 +47,69 @@ fn test_eval_expression() {
         .stdout("{\"expr\":\"((LAMBDA (A B) (+ (* A 3) B)) 9 7)\",\"env\":\"NIL\",\"cont\":\"Outermost\",\"expr_out\":\"34\",\"env_out\":\"NIL\",\"cont_out\":\"Terminal\",\"status\":\"Terminal\",\"iterations\":17}");
 }
 
+#[test]
+fn test_eval_expression_with_witnesses() {
+    let mut cmd = fcomm_cmd();
+
+    let tmp_dir = TempDir::new("tmp").unwrap();
+    let expression_path = tmp_dir.path().join("expression.lurk");
+    let witnesses_path = tmp_dir.path().join("witnesses.lurk");
+
+    let mut expression_file = File::create(&expression_path).unwrap();
+    write!(expression_file, "(+ (witness :secret) (read))").unwrap();
+    let mut witnesses_file = File::create(&witnesses_path).unwrap();
+    write!(witnesses_file, "((:secret . 40) (nil . 2))").unwrap();
+
+    cmd.arg("eval")
+        .arg("--expression")
+        .arg(expression_path)
+        .arg("--lurk")
+        .arg("--witnesses")
+        .arg(witnesses_path);
+
+    // The claim records the expression and its output, but not the values it read.
+    cmd.assert().success().stdout(
+        predicate::str::contains("\"expr\":\"(+ (WITNESS :SECRET) (READ))\"")
+            .and(predicate::str::contains("\"expr_out\":\"42\""))
+            .and(predicate::str::contains("40").not()),
+    );
+}
+
+#[test]
+#[ignore]
+fn test_prove_and_verify_expression_with_witnesses() {
+    let tmp_dir = TempDir::new("tmp").unwrap();
+    let proof_path = tmp_dir.path().join("proof.json");
+    let fcomm_data_path = tmp_dir.path().join("fcomm_data");
+    let expression_path = tmp_dir.path().join("expression.lurk");
+    let witnesses_path = tmp_dir.path().join("witnesses.lurk");
+
+    let mut expression_file = File::create(&expression_path).unwrap();
+    write!(expression_file, "(* (witness :secret) 2)").unwrap();
+    let mut witnesses_file = File::create(&witnesses_path).unwrap();
+    write!(witnesses_file, "((:secret . 21))").unwrap();
+
+    fcomm_cmd()
+        .env("FCOMM_DATA_PATH", &fcomm_data_path)
+        .arg("prove")
+        .arg("--expression")
+        .arg(&expression_path)
+        .arg("--proof")
+        .arg(&proof_path)
+        .arg("--lurk")
+        .arg("--witnesses")
+        .arg(&witnesses_path)
+        .assert()
+        .success();
+
+    let proof = Proof::<Bls12>::read_from_path(&proof_path).unwrap();
+    let evaluation = proof.claim.evaluation().expect("expected evaluation claim");
+    assert_eq!(evaluation.expr_out, "42");
+
+    // Verifying needs neither the oracle nor the values it supplied.
+    test_verify_expression_proof(fcomm_cmd(), &proof_path, &fcomm_data_path);
+}
+
 fn test_prove_expression<T: AsRef<OsStr>>(
     cmd: &mut Command,
     expression_path: T,
@@ -103,9 +166,38 @@ fn test_verify_opening<T: AsRef<OsStr>>(mut cmd: Command, proof_path: T, _data_p
 #[test]
 #[ignore]
 fn test_prove_and_verify_expression() {
-    let expression = "(* 9 7)";
-    let expected = "63";
+    test_prove_and_verify_expression_aux("(* 9 7)", "63", None);
+}
+
+#[test]
+#[ignore]
+fn test_prove_and_verify_quasiquoted_expression() {
+    // The claim records the template, and the proof is about its expansion.
+    test_prove_and_verify_expression_aux("(let ((b 2)) `(a ,b))", "(A 2)", None);
+}
+
+#[test]
+#[ignore]
+fn test_prove_and_verify_error_expression() {
+    test_prove_and_verify_expression_aux("(car 1)", "1", Some(ErrorCode::InvalidArgument));
+}
+
+#[test]
+#[ignore]
+fn test_prove_and_verify_unexpandable_expression() {
+    // The proof is about the macro call which cannot be expanded, and its error.
+    test_prove_and_verify_expression_aux(
+        "(macrolet ((bad (x) (car x))) (bad 1))",
+        "(BAD 1)",
+        Some(ErrorCode::InvalidForm),
+    );
+}
 
+fn test_prove_and_verify_expression_aux(
+    expression: &str,
+    expected: &str,
+    expected_error_code: Option<ErrorCode>,
+) {
     let tmp_dir = TempDir::new("tmp").unwrap();
     let proof_path = tmp_dir.path().join("proof.json");
     let fcomm_data_path = tmp_dir.path().join("fcomm_data");
@@ -123,15 +215,10 @@ fn test_prove_and_verify_expression() {
         );
 
         let proof = Proof::<Bls12>::read_from_path(&proof_path).unwrap();
+        let evaluation = proof.claim.evaluation().expect("expected evaluation claim");
 
-        assert_eq!(
-            proof
-                .claim
-                .evaluation()
-                .expect("expected evaluation claim")
-                .expr_out,
-            expected
-        );
+        assert_eq!(evaluation.expr_out, expected);
+        assert_eq!(evaluation.error_code, expected_error_code);
     }
 
     test_verify_expression_proof(fcomm_cmd(), &proof_path, &fcomm_data_path);
@@ -0,0 +1,125 @@
+//! Checkpoints of an evaluation, from which it can be resumed later, possibly in another process.
+//!
+//! A `Checkpoint` holds the scalar representation of an `IO` (expression, environment and
+//! continuation), together with a `ScalarStore` containing everything reachable from it, including
+//! continuations and thunks. It can be serialized, e.g. to IPLD, and `Checkpoint::io` interns the
+//! `IO` in any store, from which `Evaluator::resume` continues the evaluation.
+
+use serde::{Deserialize, Serialize};
+
+use crate::eval::IO;
+use crate::field::LurkField;
+use crate::scalar_store::ScalarStore;
+use crate::store::{ContTag, ScalarContPtr, ScalarPtr, Store, Tag};
+
+#[derive(Debug, Clone, PartialEq, Eq, Serialize, Deserialize)]
+pub struct Checkpoint<F: LurkField> {
+    scalar_store: ScalarStore<F>,
+    expr: ScalarPtr<F>,
+    env: ScalarPtr<F>,
+    cont: ScalarContPtr<F>,
+}
+
+impl<F: LurkField> Checkpoint<F> {
+    /// Returns a checkpoint of `io`, or `None` if some part of it cannot be hashed.
+    pub fn new(store: &mut Store<F>, io: &IO<F>) -> Option<Self> {
+        store.hydrate_scalar_cache();
+
+        let mut scalar_store = ScalarStore::default();
+        let expr = scalar_store.add_ptr(store, &io.expr)?;
+        let env = scalar_store.add_ptr(store, &io.env)?;
+        let cont = scalar_store.add_cont_ptr(store, &io.cont)?;
+        scalar_store.finalize(store);
+
+        Some(Self {
+            scalar_store,
+            expr,
+            env,
+            cont,
+        })
+    }
+
+    /// Interns the checkpoint's `IO` in `store`, or returns `None` if the checkpoint is missing
+    /// some part of it, or if its dummy continuation does not follow a thunk, so no evaluation
+    /// could have reached it.
+    pub fn io(&self, store: &mut Store<F>) -> Option<IO<F>> {
+        let expr = store.intern_scalar_ptr(self.expr, &self.scalar_store)?;
+        let env = store.intern_scalar_ptr(self.env, &self.scalar_store)?;
+        let cont = store.intern_scalar_cont_ptr(self.cont, &self.scalar_store)?;
+        if cont.tag() == ContTag::Dummy && expr.tag() != Tag::Thunk {
+            return None;
+        }
+
+        Some(IO { expr, env, cont })
+    }
+}
+
+#[cfg(test)]
+mod test {
+    use super::*;
+    use crate::eval::{empty_sym_env, Evaluable, Evaluator};
+    use blstrs::Scalar as Fr;
+    use libipld::serde::{from_ipld, to_ipld};
+
+    #[test]
+    fn resume_from_checkpoint() {
+        let limit = 1000;
+        let src = "(letrec ((f (lambda (n) (if (= n 0) (emit 0) (+ 1 (f (- n 1))))))) (f 5))";
+
+        let s = &mut Store::<Fr>::default();
+        let expr = s.read(src).unwrap();
+        let env = empty_sym_env(s);
+        let (expected, expected_iterations, expected_emitted) =
+            Evaluator::new(expr, env, s, limit).eval();
+        assert!(expected.is_terminal());
+        let expected = s.hash_expr(&expected.expr).unwrap();
+
+        // Stop every few iterations, and resume in a new store from a serialized checkpoint.
+        let step = 7;
+        let mut store = Store::<Fr>::default();
+        let expr = store.read(src).unwrap();
+        let env = empty_sym_env(&store);
+        let (mut io, mut iterations, emitted) = Evaluator::new(expr, env, &mut store, step).eval();
+        let mut emitted = emitted.len();
+        let mut checkpoints = 0;
+        while !io.is_terminal() {
+            let checkpoint = Checkpoint::new(&mut store, &io).unwrap();
+            let ipld = to_ipld(checkpoint.clone()).unwrap();
+            let checkpoint2: Checkpoint<Fr> = from_ipld(ipld).unwrap();
+            assert_eq!(checkpoint, checkpoint2);
+
+            store = Store::<Fr>::default();
+            let input = checkpoint2.io(&mut store).unwrap();
+            let (output, more_iterations, more_emitted) =
+                Evaluator::resume(input, &mut store, step).eval();
+            io = output;
+            iterations += more_iterations;
+            emitted += more_emitted.len();
+            checkpoints += 1;
+        }
+
+        assert!(checkpoints > 1);
+        assert_eq!(expected_iterations, iterations);
+        assert_eq!(expected, store.hash_expr(&io.expr).unwrap());
+        assert_eq!(expected_emitted.len(), emitted);
+    }
+
+    #[test]
+    fn dummy_continuation_without_thunk() {
+        let s = &mut Store::<Fr>::default();
+        let env = empty_sym_env(s);
+        let io = IO {
+            expr: s.num(5),
+            env,
+            cont: s.intern_cont_dummy(),
+        };
+
+        // Only a thunk is followed by a dummy continuation, so no evaluation reaches this input.
+        let checkpoint = Checkpoint::new(s, &io).unwrap();
+        assert_eq!(None, checkpoint.io(&mut Store::<Fr>::default()));
+
+        // Resuming from it anyway is an error, rather than a panic.
+        let (output, _, _) = Evaluator::resume(io, s, 10).eval();
+        assert!(output.cont.is_error());
+    }
+}
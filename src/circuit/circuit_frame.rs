@@ -1,7 +1,10 @@
 use std::fmt::Debug;
 
 use bellperson::{
-    gadgets::{boolean::Boolean, num::AllocatedNum},
+    gadgets::{
+        boolean::{AllocatedBit, Boolean},
+        num::AllocatedNum,
+    },
     util_cs::Comparable,
     Circuit, ConstraintSystem, SynthesisError,
 };
@@ -9,20 +12,22 @@ use bellperson::{
 use crate::{
     circuit::gadgets::{
         case::{case, multi_case, CaseClause},
-        data::GlobalAllocations,
+        data::{allocate_constant, GlobalAllocations},
         pointer::{AllocatedContPtr, AllocatedPtr, AsAllocatedHashComponents},
     },
     field::LurkField,
     store::ScalarPointer,
 };
+use neptune::circuit2::poseidon_hash_allocated as poseidon_hash;
 
 use super::gadgets::constraints::{
-    self, alloc_equal, alloc_is_zero, enforce_implication, or, pick,
+    self, alloc_div_rem, alloc_equal, alloc_fits_in_bits, alloc_is_less_than, alloc_is_zero,
+    alloc_low_bits, enforce_implication, or, pick,
 };
-use crate::circuit::ToInputs;
+use crate::circuit::{public_inputs_with_emitted, ToInputs};
 use crate::eval::{Frame, Witness, IO};
 use crate::proof::Provable;
-use crate::store::{ContPtr, ContTag, Op1, Op2, Ptr, Store, Tag, Thunk};
+use crate::store::{ContPtr, ContTag, Continuation, Op1, Op2, Ptr, Store, Tag, Thunk};
 
 #[derive(Clone, Copy, Debug)]
 pub struct CircuitFrame<'a, F: LurkField, T, W> {
@@ -37,6 +42,9 @@ pub struct MultiFrame<'a, F: LurkField, T, W> {
     pub store: &'a Store<F>,
     pub input: Option<T>,
     pub output: Option<T>,
+    // The running hashes of the values emitted before the input and the output.
+    pub input_emitted_hash: Option<F>,
+    pub output_emitted_hash: Option<F>,
     pub frames: Option<Vec<CircuitFrame<'a, F, T, W>>>,
     pub count: usize,
 }
@@ -69,6 +77,8 @@ impl<'a, F: LurkField, T: Clone + Copy + Debug + std::cmp::PartialEq, W: Copy>
             store,
             input: None,
             output: None,
+            input_emitted_hash: None,
+            output_emitted_hash: None,
             frames: None,
             count,
         }
@@ -77,19 +87,29 @@ impl<'a, F: LurkField, T: Clone + Copy + Debug + std::cmp::PartialEq, W: Copy>
     pub fn frame_count(&self) -> usize {
         self.count
     }
+}
 
-    pub fn from_frames(count: usize, frames: &[Frame<T, W>], store: &'a Store<F>) -> Vec<Self> {
+impl<'a, F: LurkField, W: Copy> MultiFrame<'a, F, IO<F>, W> {
+    pub fn from_frames(count: usize, frames: &[Frame<IO<F>, W>], store: &'a Store<F>) -> Vec<Self> {
         // `count` is the number of `Frames` to include per `MultiFrame`.
         let total_frames = frames.len();
         let n = total_frames / count + (total_frames % count != 0) as usize;
         let mut multi_frames = Vec::with_capacity(n);
+        let mut emitted_hash = F::zero();
 
         for chunk in frames.chunks(count) {
             let mut inner_frames = Vec::with_capacity(count);
+            let input_emitted_hash = emitted_hash;
 
             for x in chunk {
                 let circuit_frame = CircuitFrame::from_frame(x, store);
                 inner_frames.push(circuit_frame);
+
+                if let Some(emitted) = x.output.maybe_emitted_expression(store) {
+                    emitted_hash = store
+                        .hash_emitted(emitted_hash, &emitted)
+                        .expect("emitted value can be hashed");
+                }
             }
 
             let last_frame = chunk.last().expect("chunk must not be empty");
@@ -107,6 +127,8 @@ impl<'a, F: LurkField, T: Clone + Copy + Debug + std::cmp::PartialEq, W: Copy>
                 store,
                 input: Some(chunk[0].input),
                 output: Some(output),
+                input_emitted_hash: Some(input_emitted_hash),
+                output_emitted_hash: Some(emitted_hash),
                 frames: Some(inner_frames),
                 count,
             };
@@ -117,10 +139,12 @@ impl<'a, F: LurkField, T: Clone + Copy + Debug + std::cmp::PartialEq, W: Copy>
         multi_frames
     }
 
-    /// Make a dummy `MultiFrame`, duplicating `self`'s final `CircuitFrame`.
+    /// Make a dummy `MultiFrame`, duplicating `self`'s final `CircuitFrame`. That frame must not
+    /// emit a value, so the running hash of emitted values stays `emitted_hash`.
     pub(crate) fn make_dummy(
         count: usize,
-        circuit_frame: Option<CircuitFrame<'a, F, T, W>>,
+        circuit_frame: Option<CircuitFrame<'a, F, IO<F>, W>>,
+        emitted_hash: Option<F>,
         store: &'a Store<F>,
     ) -> Self {
         let (frames, input, output) = if let Some(circuit_frame) = circuit_frame {
@@ -136,6 +160,8 @@ impl<'a, F: LurkField, T: Clone + Copy + Debug + std::cmp::PartialEq, W: Copy>
             store,
             input,
             output,
+            input_emitted_hash: emitted_hash,
+            output_emitted_hash: emitted_hash,
             frames,
             count,
         }
@@ -150,7 +176,7 @@ impl<F: LurkField, T: PartialEq + Debug, W> CircuitFrame<'_, F, T, W> {
 
 impl<F: LurkField, T: PartialEq + Debug, W> MultiFrame<'_, F, T, W> {
     pub fn precedes(&self, maybe_next: &Self) -> bool {
-        self.output == maybe_next.input
+        self.output == maybe_next.input && self.output_emitted_hash == maybe_next.input_emitted_hash
     }
 }
 
@@ -158,13 +184,13 @@ impl<F: LurkField, W> Provable<F> for MultiFrame<'_, F, IO<F>, W> {
     fn public_inputs(&self) -> Vec<F> {
         let mut inputs: Vec<_> = Vec::with_capacity(Self::public_input_size());
 
-        if let Some(input) = &self.input {
-            inputs.extend(input.to_inputs(self.store));
+        if let (Some(input), Some(emitted_hash)) = (&self.input, self.input_emitted_hash) {
+            inputs.extend(public_inputs_with_emitted(input, emitted_hash, self.store));
         } else {
             panic!("public inputs for blank circuit");
         }
-        if let Some(output) = &self.output {
-            inputs.extend(output.to_inputs(self.store));
+        if let (Some(output), Some(emitted_hash)) = (&self.output, self.output_emitted_hash) {
+            inputs.extend(public_inputs_with_emitted(output, emitted_hash, self.store));
         } else {
             panic!("public outputs for blank circuit");
         }
@@ -173,7 +199,8 @@ impl<F: LurkField, W> Provable<F> for MultiFrame<'_, F, IO<F>, W> {
     }
 
     fn public_input_size() -> usize {
-        let input_output_size = IO::<F>::input_size();
+        // Each side also has the running hash of the values emitted before it.
+        let input_output_size = IO::<F>::input_size() + 1;
         input_output_size * 2
     }
 
@@ -190,19 +217,22 @@ impl<F: LurkField> CircuitFrame<'_, F, IO<F>, Witness<F>> {
         cs: &mut CS,
         i: usize,
         inputs: AllocatedIO<F>,
+        emitted_hash: AllocatedNum<F>,
         g: &GlobalAllocations<F>,
-    ) -> Result<AllocatedIO<F>, SynthesisError> {
+    ) -> Result<(AllocatedIO<F>, AllocatedNum<F>), SynthesisError> {
         let (input_expr, input_env, input_cont) = inputs;
 
-        reduce_expression(
+        let (expr, env, cont, emitted_hash) = reduce_expression(
             &mut cs.namespace(|| format!("reduce expression {}", i)),
             &input_expr,
             &input_env,
             &input_cont,
+            &emitted_hash,
             &self.witness,
             self.store,
             g,
-        )
+        )?;
+        Ok(((expr, env, cont), emitted_hash))
     }
 }
 
@@ -230,6 +260,11 @@ impl<F: LurkField> Circuit<F> for MultiFrame<'_, F, IO<F>, Witness<F>> {
             self.store,
         )?;
 
+        let input_emitted_hash = bind_emitted_hash(
+            &mut cs.namespace(|| "outer input emitted hash"),
+            self.input_emitted_hash,
+        )?;
+
         // Final output:
         let output_expr = AllocatedPtr::bind_input(
             &mut cs.namespace(|| "outer output expression"),
@@ -249,22 +284,34 @@ impl<F: LurkField> Circuit<F> for MultiFrame<'_, F, IO<F>, Witness<F>> {
             self.store,
         )?;
 
+        let output_emitted_hash = bind_emitted_hash(
+            &mut cs.namespace(|| "outer output emitted hash"),
+            self.output_emitted_hash,
+        )?;
+
         //
         // End public inputs.
         ////////////////////////////////////////////////////////////////////////////////
         let g = GlobalAllocations::new(&mut cs.namespace(|| "global_allocations"), self.store)?;
 
-        let acc = (input_expr, input_env, input_cont);
+        let acc = ((input_expr, input_env, input_cont), input_emitted_hash);
 
         let frames = match self.frames {
             Some(f) => f,
             None => vec![CircuitFrame::blank(self.store); self.count],
         };
 
-        let (_, (new_expr, new_env, new_cont)) =
-            frames.iter().fold((0, acc), |(i, allocated_io), frame| {
-                (i + 1, frame.synthesize(cs, i, allocated_io, &g).unwrap())
-            });
+        let (_, ((new_expr, new_env, new_cont), new_emitted_hash)) =
+            frames
+                .iter()
+                .fold((0, acc), |(i, (allocated_io, emitted_hash)), frame| {
+                    (
+                        i + 1,
+                        frame
+                            .synthesize(cs, i, allocated_io, emitted_hash, &g)
+                            .unwrap(),
+                    )
+                });
 
         // dbg!(
         //     (&new_expr, &output_expr),
@@ -284,11 +331,28 @@ impl<F: LurkField> Circuit<F> for MultiFrame<'_, F, IO<F>, Witness<F>> {
             &mut cs.namespace(|| "outer output cont is correct"),
             &new_cont,
         );
+        cs.enforce(
+            || "outer output emitted hash is correct",
+            |lc| lc + output_emitted_hash.get_variable(),
+            |lc| lc + CS::one(),
+            |lc| lc + new_emitted_hash.get_variable(),
+        );
 
         Ok(())
     }
 }
 
+fn bind_emitted_hash<F: LurkField, CS: ConstraintSystem<F>>(
+    cs: &mut CS,
+    emitted_hash: Option<F>,
+) -> Result<AllocatedNum<F>, SynthesisError> {
+    let hash = AllocatedNum::alloc(cs.namespace(|| "hash"), || {
+        emitted_hash.ok_or(SynthesisError::AssignmentMissing)
+    })?;
+    hash.inputize(cs.namespace(|| "hash input"))?;
+    Ok(hash)
+}
+
 #[derive(Default)]
 struct Results<'a, F: LurkField> {
     expr_tag_clauses: Vec<CaseClause<'a, F>>,
@@ -517,10 +581,19 @@ fn reduce_expression<F: LurkField, CS: ConstraintSystem<F>>(
     expr: &AllocatedPtr<F>,
     env: &AllocatedPtr<F>,
     cont: &AllocatedContPtr<F>,
+    emitted_hash: &AllocatedNum<F>,
     witness: &Option<Witness<F>>,
     store: &Store<F>,
     g: &GlobalAllocations<F>,
-) -> Result<(AllocatedPtr<F>, AllocatedPtr<F>, AllocatedContPtr<F>), SynthesisError> {
+) -> Result<
+    (
+        AllocatedPtr<F>,
+        AllocatedPtr<F>,
+        AllocatedContPtr<F>,
+        AllocatedNum<F>,
+    ),
+    SynthesisError,
+> {
     // dbg!("reduce_expression");
     // dbg!(&expr.fetch_and_write_str(store));
     // dbg!(&expr);
@@ -535,6 +608,7 @@ fn reduce_expression<F: LurkField, CS: ConstraintSystem<F>>(
         results.add_clauses_expr(Tag::Char, expr, env, cont, &g.true_num);
         results.add_clauses_expr(Tag::Str, expr, env, cont, &g.true_num);
         results.add_clauses_expr(Tag::Comm, expr, env, cont, &g.true_num);
+        results.add_clauses_expr(Tag::U64, expr, env, cont, &g.true_num);
     };
 
     let cont_is_terminal = alloc_equal(
@@ -542,6 +616,13 @@ fn reduce_expression<F: LurkField, CS: ConstraintSystem<F>>(
         cont.tag(),
         g.terminal_ptr.tag(),
     )?;
+    let cont_is_error = alloc_equal(
+        &mut cs.namespace(|| "cont_is_error"),
+        cont.tag(),
+        g.error_ptr_cont.tag(),
+    )?;
+    // Evaluation is complete once the continuation is terminal or an error.
+    let cont_is_complete = or!(cs, &cont_is_terminal, &cont_is_error)?;
 
     // If expr is a thunk, this will allocate its components and hash, etc.
     // If not, these will be dummies.
@@ -576,13 +657,9 @@ fn reduce_expression<F: LurkField, CS: ConstraintSystem<F>>(
     }
 
     // --
-    let reduce_sym_not_dummy = alloc_equal(
-        &mut cs.namespace(|| "reduce_sym_not_dummy"),
-        expr.tag(),
-        &g.sym_tag,
-    )?;
-    let cont_is_not_terminal = Boolean::not(&cont_is_terminal);
-    let reduce_sym_not_dummy = and!(cs, &reduce_sym_not_dummy, &cont_is_not_terminal)?;
+    let expr_is_sym = alloc_equal(&mut cs.namespace(|| "expr_is_sym"), expr.tag(), &g.sym_tag)?;
+    let cont_is_not_complete = Boolean::not(&cont_is_complete);
+    let reduce_sym_not_dummy = and!(cs, &expr_is_sym, &cont_is_not_complete)?;
 
     let (sym_result, sym_env, sym_cont, sym_apply_cont) = reduce_sym(
         &mut cs.namespace(|| "eval Sym"),
@@ -655,7 +732,7 @@ fn reduce_expression<F: LurkField, CS: ConstraintSystem<F>>(
     let first_result_expr_0 = AllocatedPtr::by_index(0, &case_results);
     let first_result_expr = AllocatedPtr::pick(
         &mut cs.namespace(|| "first_result_expr"),
-        &cont_is_terminal,
+        &cont_is_complete,
         &g.nil_ptr,
         &first_result_expr_0,
     )?;
@@ -672,7 +749,7 @@ fn reduce_expression<F: LurkField, CS: ConstraintSystem<F>>(
     let apply_continuation_boolean = Boolean::and(
         &mut cs.namespace(|| "apply_continuation_boolean"),
         &apply_continuation_boolean_0,
-        &Boolean::not(&cont_is_terminal),
+        &cont_is_not_complete,
     )?;
 
     let apply_continuation_results = apply_continuation(
@@ -722,6 +799,35 @@ fn reduce_expression<F: LurkField, CS: ConstraintSystem<F>>(
         &make_thunk_num,
     )?);
 
+    // A value is emitted when the thunk made for it continues with EMIT, which is what
+    // IO::maybe_emitted_expression finds in the output. It is added to the running hash.
+    let new_emitted_hash = {
+        let mut cs = cs.namespace(|| "emitted hash");
+        let cont_is_emit = alloc_equal(
+            &mut cs.namespace(|| "cont_is_emit"),
+            result_cont0.tag(),
+            &g.emit_cont_tag,
+        )?;
+        let is_emitted = and!(cs, &make_thunk_boolean, &cont_is_emit)?;
+        let preimage = vec![
+            g.emit_cont_tag.clone(),
+            emitted_hash.clone(),
+            result_expr0.tag().clone(),
+            result_expr0.hash().clone(),
+        ];
+        let hash = poseidon_hash(
+            cs.namespace(|| "hash"),
+            preimage,
+            store.poseidon_constants().c4(),
+        )?;
+        pick(
+            &mut cs.namespace(|| "pick emitted hash"),
+            &is_emitted,
+            &hash,
+            emitted_hash,
+        )?
+    };
+
     let thunk_results = make_thunk(
         &mut cs.namespace(|| "make_thunk"),
         &result_cont0,
@@ -754,6 +860,103 @@ fn reduce_expression<F: LurkField, CS: ConstraintSystem<F>>(
         &result_cont0,
     )?;
 
+    // Attribute an unclassified error to the phase which raised it, as attribute_error does in eval.rs.
+    let result_cont_candidate = {
+        let cs = &mut cs.namespace(|| "attribute error");
+
+        let result_is_error = result_cont_candidate
+            .alloc_equal(&mut cs.namespace(|| "result_is_error"), &g.error_ptr_cont)?;
+        let error_in_reduce = first_result_cont
+            .alloc_equal(&mut cs.namespace(|| "error_in_reduce"), &g.error_ptr_cont)?;
+
+        let reduce_error_code = pick(
+            &mut cs.namespace(|| "reduce_error_code"),
+            &expr_is_sym,
+            &g.error_unbound_variable_code,
+            &g.error_invalid_form_code,
+        )?;
+
+        let first_cont_is_call0 = alloc_equal(
+            &mut cs.namespace(|| "first_cont_is_call0"),
+            first_result_cont.tag(),
+            &g.call0_cont_tag,
+        )?;
+        let first_cont_is_call = alloc_equal(
+            &mut cs.namespace(|| "first_cont_is_call"),
+            first_result_cont.tag(),
+            &g.call_cont_tag,
+        )?;
+        let first_cont_is_call2 = alloc_equal(
+            &mut cs.namespace(|| "first_cont_is_call2"),
+            first_result_cont.tag(),
+            &g.call2_cont_tag,
+        )?;
+        let first_cont_is_apply = alloc_equal(
+            &mut cs.namespace(|| "first_cont_is_apply"),
+            first_result_cont.tag(),
+            &g.apply_cont_tag,
+        )?;
+        let first_cont_is_call0_or_call = or!(cs, &first_cont_is_call0, &first_cont_is_call)?;
+        let first_cont_is_call2_or_apply = or!(cs, &first_cont_is_call2, &first_cont_is_apply)?;
+        let first_cont_is_any_call = or!(
+            cs,
+            &first_cont_is_call0_or_call,
+            &first_cont_is_call2_or_apply
+        )?;
+
+        let first_cont_is_fail = alloc_equal(
+            &mut cs.namespace(|| "first_cont_is_fail"),
+            first_result_cont.tag(),
+            &g.fail_cont_tag,
+        )?;
+        let first_cont_is_throw = alloc_equal(
+            &mut cs.namespace(|| "first_cont_is_throw"),
+            first_result_cont.tag(),
+            &g.throw_cont_tag,
+        )?;
+        let first_cont_is_user_failure = or!(cs, &first_cont_is_fail, &first_cont_is_throw)?;
+
+        let call_or_argument_error_code = pick(
+            &mut cs.namespace(|| "call_or_argument_error_code"),
+            &first_cont_is_any_call,
+            &g.error_invalid_call_code,
+            &g.error_invalid_argument_code,
+        )?;
+        let apply_error_code = pick(
+            &mut cs.namespace(|| "apply_error_code"),
+            &first_cont_is_user_failure,
+            &g.error_user_failure_code,
+            &call_or_argument_error_code,
+        )?;
+
+        let error_code = pick(
+            &mut cs.namespace(|| "error_code"),
+            &error_in_reduce,
+            &reduce_error_code,
+            &apply_error_code,
+        )?;
+
+        let error_components: &[&dyn AsAllocatedHashComponents<F>; 4] = &[
+            &[&error_code, &g.default_num],
+            &result_expr_candidate,
+            &[&g.default_num, &g.default_num],
+            &[&g.default_num, &g.default_num],
+        ];
+        let error_cont = AllocatedContPtr::construct(
+            &mut cs.namespace(|| "error_cont"),
+            store,
+            g.error_ptr_cont.tag(),
+            error_components,
+        )?;
+
+        AllocatedContPtr::pick(
+            &mut cs.namespace(|| "pick maybe attributed error cont"),
+            &result_is_error,
+            &error_cont,
+            &result_cont_candidate,
+        )?
+    };
+
     // dbg!(&result_expr_candidate.fetch_and_write_str(store));
     // dbg!(&result_env_candidate.fetch_and_write_str(store));
     // dbg!(&result_cont_candidate.fetch_and_write_cont_str(store));
@@ -761,26 +964,26 @@ fn reduce_expression<F: LurkField, CS: ConstraintSystem<F>>(
 
     let result_expr = AllocatedPtr::<F>::pick(
         &mut cs.namespace(|| "result_expr"),
-        &cont_is_terminal,
+        &cont_is_complete,
         expr,
         &result_expr_candidate,
     )?;
 
     let result_env = AllocatedPtr::<F>::pick(
         &mut cs.namespace(|| "result_env"),
-        &cont_is_terminal,
+        &cont_is_complete,
         env,
         &result_env_candidate,
     )?;
 
     let result_cont = AllocatedContPtr::<F>::pick(
         &mut cs.namespace(|| "result_cont"),
-        &cont_is_terminal,
+        &cont_is_complete,
         cont,
         &result_cont_candidate,
     )?;
 
-    Ok((result_expr, result_env, result_cont))
+    Ok((result_expr, result_env, result_cont, new_emitted_hash))
 }
 
 fn reduce_sym<F: LurkField, CS: ConstraintSystem<F>>(
@@ -1117,7 +1320,7 @@ fn reduce_cons<F: LurkField, CS: ConstraintSystem<F>>(
     env: &AllocatedPtr<F>,
     cont: &AllocatedContPtr<F>,
     _not_dummy: &Boolean,
-    _witness: &Option<Witness<F>>,
+    witness: &Option<Witness<F>>,
     store: &Store<F>,
     g: &GlobalAllocations<F>,
 ) -> Result<
@@ -1141,7 +1344,9 @@ fn reduce_cons<F: LurkField, CS: ConstraintSystem<F>>(
     };
 
     let lambda_hash = hash_sym("lambda");
-    let quote_hash = hash_sym("quote");
+    let quote_sym = hash_sym("quote");
+    let quote_t = AllocatedPtr::alloc_constant(&mut cs.namespace(|| "quote"), quote_sym)?;
+    let quote_hash = quote_sym.value();
     let let_sym = hash_sym("let");
     let let_t = AllocatedPtr::alloc_constant(&mut cs.namespace(|| "let"), let_sym)?;
     let let_hash = let_sym.value();
@@ -1149,7 +1354,12 @@ fn reduce_cons<F: LurkField, CS: ConstraintSystem<F>>(
     let letrec_t = AllocatedPtr::alloc_constant(&mut cs.namespace(|| "letrec"), letrec)?;
     let letrec_hash = letrec.value();
     let cons_hash = hash_sym("cons");
+    let cons_t = AllocatedPtr::alloc_constant(&mut cs.namespace(|| "cons"), cons_hash)?;
+    let strcons_hash = hash_sym("strcons");
     let begin_hash = hash_sym("begin");
+    let begin_t = AllocatedPtr::alloc_constant(&mut cs.namespace(|| "begin"), begin_hash)?;
+    let more_sym = hash_sym("&more");
+    let more_t = AllocatedPtr::alloc_constant(&mut cs.namespace(|| "&more"), more_sym)?;
     let car_hash = hash_sym("car");
     let cdr_hash = hash_sym("cdr");
     let atom_hash = hash_sym("atom");
@@ -1158,15 +1368,37 @@ fn reduce_cons<F: LurkField, CS: ConstraintSystem<F>>(
     let diff_hash = hash_sym("-");
     let product_hash = hash_sym("*");
     let quotient_hash = hash_sym("/");
+    let modulo_hash = hash_sym("%");
+    let int_quotient_hash = hash_sym("div");
     let numequal_hash = hash_sym("=");
+    let less_hash = hash_sym("<");
+    let greater_hash = hash_sym(">");
+    let lessequal_hash = hash_sym("<=");
+    let greaterequal_hash = hash_sym(">=");
     let equal_hash = hash_sym("eq");
     let current_env_hash = hash_sym("current-env");
     let if_hash = hash_sym("if");
+    let and_hash = hash_sym("and");
+    let or_hash = hash_sym("or");
+    let cond_sym = hash_sym("cond");
+    let cond_t = AllocatedPtr::alloc_constant(&mut cs.namespace(|| "cond"), cond_sym)?;
+    let cond_hash = cond_sym.value();
+    let eval_hash = hash_sym("eval");
+    let fail_sym = hash_sym("fail");
+    let fail_t = AllocatedPtr::alloc_constant(&mut cs.namespace(|| "fail"), fail_sym)?;
+    let fail_hash = fail_sym.value();
+    let assert_hash = hash_sym("assert");
+    let apply_hash = hash_sym("apply");
+    let catch_hash = hash_sym("catch");
+    let throw_hash = hash_sym("throw");
+    let witness_hash = hash_sym("witness");
+    let read_hash = hash_sym("read");
     let hide_hash = hash_sym("hide");
     let commit_hash = hash_sym("commit");
     let num_hash = hash_sym("num");
     let comm_hash = hash_sym("comm");
     let char_hash = hash_sym("char");
+    let u64_hash = hash_sym("u64");
     let open_hash = hash_sym("open");
     let secret_hash = hash_sym("secret");
 
@@ -1180,15 +1412,40 @@ fn reduce_cons<F: LurkField, CS: ConstraintSystem<F>>(
 
     let end_is_nil = more.alloc_equal(&mut cs.namespace(|| "end_is_nil"), &g.nil_ptr)?;
 
+    // (- x) is (- 0 x), so with a single argument - and / evaluate their identity first and keep
+    // all of rest as the unevaluated arguments.
+    let more_or_rest = AllocatedPtr::pick(
+        &mut cs.namespace(|| "more or rest"),
+        &end_is_nil,
+        &rest,
+        &more,
+    )?;
+
     let mut results = Results::default();
 
+    // The bodies of LAMBDA, LET and LETREC may have several forms, which are evaluated as if
+    // wrapped in BEGIN.
+    let (body1, rest_body) = car_cdr(&mut cs.namespace(|| "car_cdr body"), g, &more, store)?;
+    let rest_body_is_nil =
+        rest_body.alloc_equal(&mut cs.namespace(|| "rest_body_is_nil"), &g.nil_ptr)?;
+    let implicit_begin = AllocatedPtr::construct_cons(
+        &mut cs.namespace(|| "implicit begin"),
+        g,
+        store,
+        &begin_t,
+        &more,
+    )?;
+
+    // For LAMBDA arg1 is the argument list, and for COND it is the first clause.
+    let (car_arg1, cdr_arg1) = car_cdr(&mut cs.namespace(|| "car_cdr arg1"), g, &arg1, store)?;
+
     // --
     let function = {
         // head == LAMBDA
         let (args, body) = (arg1.clone(), more.clone());
         let args_is_nil = args.alloc_equal(&mut cs.namespace(|| "args_is_nil"), &g.nil_ptr)?;
 
-        let (car_args, cdr_args) = car_cdr(&mut cs.namespace(|| "car_cdr args"), g, &args, store)?;
+        let (car_args, cdr_args) = (car_arg1.clone(), cdr_arg1.clone());
 
         // FIXME: There may be some cases where cdr_args is wrong/differs from eval.rs.
 
@@ -1199,6 +1456,17 @@ fn reduce_cons<F: LurkField, CS: ConstraintSystem<F>>(
             &car_args,
         )?;
 
+        // (LAMBDA REST STUFF) is variadic, its argument is (REST), and its cdr_args is nil.
+        let args_is_sym = alloc_equal(&mut cs.namespace(|| "args_is_sym"), args.tag(), &g.sym_tag)?;
+        let rest_arg =
+            AllocatedPtr::construct_list(&mut cs.namespace(|| "rest arg"), g, store, &[&args])?;
+        let arg = AllocatedPtr::pick(
+            &mut cs.namespace(|| "maybe rest arg"),
+            &args_is_sym,
+            &rest_arg,
+            &arg,
+        )?;
+
         let inner = AllocatedPtr::construct_cons(
             &mut cs.namespace(|| "inner"),
             g,
@@ -1211,10 +1479,24 @@ fn reduce_cons<F: LurkField, CS: ConstraintSystem<F>>(
             cdr_args.alloc_equal(&mut cs.namespace(|| "cdr_args_is_nil"), &g.nil_ptr)?;
 
         let list = AllocatedPtr::construct_list(&mut cs.namespace(|| "list"), g, store, &[&l])?;
+
+        let begin_body = AllocatedPtr::construct_list(
+            &mut cs.namespace(|| "begin body"),
+            g,
+            store,
+            &[&implicit_begin],
+        )?;
+        let single_body = AllocatedPtr::pick(
+            &mut cs.namespace(|| "single body"),
+            &rest_body_is_nil,
+            &body,
+            &begin_body,
+        )?;
+
         let inner_body = AllocatedPtr::pick(
             &mut cs.namespace(|| "inner_body"),
             &cdr_args_is_nil,
-            &body,
+            &single_body,
             &list,
         )?;
 
@@ -1249,13 +1531,7 @@ fn reduce_cons<F: LurkField, CS: ConstraintSystem<F>>(
         (arg1_or_expr, the_cont)
     };
 
-    results.add_clauses_cons(
-        *quote_hash.value(),
-        &arg1_or_expr,
-        env,
-        &the_cont,
-        &g.true_num,
-    );
+    results.add_clauses_cons(*quote_hash, &arg1_or_expr, env, &the_cont, &g.true_num);
 
     /*
      * Returns the expression, which can be the value of the first binding
@@ -1264,7 +1540,7 @@ fn reduce_cons<F: LurkField, CS: ConstraintSystem<F>>(
      * is found and bindings is nil. Otherwise the continuation is a new
      * pointer, created using the expanded env.
      * Errors:
-     *  - rest_body_is_nil
+     *  - body_is_nil
      *  - end_is_nil rest (and not bindings_is_nil)
      */
     let (the_expr, var_let_letrec, expanded_let, expanded_letrec, bindings_is_nil, cond_error) = {
@@ -1274,11 +1550,11 @@ fn reduce_cons<F: LurkField, CS: ConstraintSystem<F>>(
         let mut cs_let_letrec = cs.namespace(|| "LET_LETREC");
 
         let (bindings, body) = (arg1.clone(), more.clone());
-        let (body1, rest_body) = car_cdr(
-            &mut cs_let_letrec.namespace(|| "car_cdr body"),
-            g,
-            &body,
-            store,
+        let body1 = AllocatedPtr::pick(
+            &mut cs_let_letrec.namespace(|| "body1 or implicit begin"),
+            &rest_body_is_nil,
+            &body1,
+            &implicit_begin,
         )?;
         let (binding1, rest_bindings) = car_cdr(
             &mut cs_let_letrec.namespace(|| "car_cdr bindings"),
@@ -1297,11 +1573,6 @@ fn reduce_cons<F: LurkField, CS: ConstraintSystem<F>>(
             &g.nil_ptr,
         )?;
 
-        let rest_body_is_nil = rest_body.alloc_equal(
-            &mut cs_let_letrec.namespace(|| "rest_body_is_nil"),
-            &g.nil_ptr,
-        )?;
-
         let (val, end) = car_cdr(
             &mut cs_let_letrec.namespace(|| "car_cdr vals"),
             g,
@@ -1318,15 +1589,10 @@ fn reduce_cons<F: LurkField, CS: ConstraintSystem<F>>(
         /*
          * We get the condition for error by using OR of each individual error.
          */
-        let mut cond_error = constraints::or(
-            &mut cs_let_letrec.namespace(|| "cond error1"),
-            &Boolean::not(&rest_body_is_nil),
-            &Boolean::not(&end_is_nil),
-        )?;
-        cond_error = constraints::or(
-            &mut cs_let_letrec.namespace(|| "cond error2"),
+        let cond_error = constraints::or(
+            &mut cs_let_letrec.namespace(|| "cond error"),
             &body_is_nil,
-            &cond_error,
+            &Boolean::not(&end_is_nil),
         )?;
 
         let expanded1 = AllocatedPtr::construct_list(
@@ -1413,6 +1679,16 @@ fn reduce_cons<F: LurkField, CS: ConstraintSystem<F>>(
         cons_continuation_components,
     );
 
+    // head == STRCONS preimage
+    /////////////////////////////////////////////////////////////////////////////
+    let strcons_continuation_components: &[&dyn AsAllocatedHashComponents<F>; 4] =
+        &[&[&g.op2_strcons_tag, &g.default_num], env, &more, cont];
+    hash_default_results.add_hash_input_clauses(
+        *strcons_hash.value(),
+        &g.binop_cont_tag,
+        strcons_continuation_components,
+    );
+
     // head == HIDE preimage
     /////////////////////////////////////////////////////////////////////////////
     let hide_continuation_components: &[&dyn AsAllocatedHashComponents<F>; 4] =
@@ -1507,6 +1783,20 @@ fn reduce_cons<F: LurkField, CS: ConstraintSystem<F>>(
         char_continuation_components,
     );
 
+    // head == U64 preimage
+    /////////////////////////////////////////////////////////////////////////////
+    let u64_continuation_components: &[&dyn AsAllocatedHashComponents<F>; 4] = &[
+        &[&g.op1_u64_tag, &g.default_num],
+        &[cont.tag(), cont.hash()],
+        &[&g.default_num, &g.default_num],
+        &[&g.default_num, &g.default_num],
+    ];
+    hash_default_results.add_hash_input_clauses(
+        *u64_hash.value(),
+        &g.unop_cont_tag,
+        u64_continuation_components,
+    );
+
     // head == BEGIN preimage
     /////////////////////////////////////////////////////////////////////////////
     let begin_continuation_components: &[&dyn AsAllocatedHashComponents<F>; 4] =
@@ -1573,6 +1863,35 @@ fn reduce_cons<F: LurkField, CS: ConstraintSystem<F>>(
         emit_continuation_components,
     );
 
+    // head == TYPE-OF and the type predicates, preimages
+    /////////////////////////////////////////////////////////////////////////////
+    let mut type_op_tags = Vec::new();
+    for op in Op1::TYPE_OPS.iter() {
+        type_op_tags.push(op.allocate_constant(&mut cs.namespace(|| format!("op1 {} tag", op)))?);
+    }
+    let type_op_tag_pairs: Vec<[&AllocatedNum<F>; 2]> = type_op_tags
+        .iter()
+        .map(|tag| [tag, &g.default_num])
+        .collect();
+    let default_num_pair = &[&g.default_num, &g.default_num];
+    let type_op_continuation_components: Vec<[&dyn AsAllocatedHashComponents<F>; 4]> =
+        type_op_tag_pairs
+            .iter()
+            .map(|pair| -> [&dyn AsAllocatedHashComponents<F>; 4] {
+                [pair, cont, default_num_pair, default_num_pair]
+            })
+            .collect();
+    for (op, components) in Op1::TYPE_OPS
+        .iter()
+        .zip(type_op_continuation_components.iter())
+    {
+        hash_default_results.add_hash_input_clauses(
+            *hash_sym(op.type_op_name().unwrap()).value(),
+            &g.unop_cont_tag,
+            components,
+        );
+    }
+
     // head == + preimage
     /////////////////////////////////////////////////////////////////////////////
     let sum_continuation_components: &[&dyn AsAllocatedHashComponents<F>; 4] =
@@ -1586,7 +1905,7 @@ fn reduce_cons<F: LurkField, CS: ConstraintSystem<F>>(
     // head == - preimage
     /////////////////////////////////////////////////////////////////////////////
     let diff_continuation_components: &[&dyn AsAllocatedHashComponents<F>; 4] =
-        &[&[&g.op2_diff_tag, &g.default_num], env, &more, cont];
+        &[&[&g.op2_diff_tag, &g.default_num], env, &more_or_rest, cont];
     hash_default_results.add_hash_input_clauses(
         *diff_hash.value(),
         &g.binop_cont_tag,
@@ -1606,14 +1925,38 @@ fn reduce_cons<F: LurkField, CS: ConstraintSystem<F>>(
     // head == / preimage
     /////////////////////////////////////////////////////////////////////////////
 
-    let quotient_continuation_components: &[&dyn AsAllocatedHashComponents<F>; 4] =
-        &[&[&g.op2_quotient_tag, &g.default_num], env, &more, cont];
+    let quotient_continuation_components: &[&dyn AsAllocatedHashComponents<F>; 4] = &[
+        &[&g.op2_quotient_tag, &g.default_num],
+        env,
+        &more_or_rest,
+        cont,
+    ];
     hash_default_results.add_hash_input_clauses(
         *quotient_hash.value(),
         &g.binop_cont_tag,
         quotient_continuation_components,
     );
 
+    // head == % preimage
+    /////////////////////////////////////////////////////////////////////////////
+    let modulo_continuation_components: &[&dyn AsAllocatedHashComponents<F>; 4] =
+        &[&[&g.op2_modulo_tag, &g.default_num], env, &more, cont];
+    hash_default_results.add_hash_input_clauses(
+        *modulo_hash.value(),
+        &g.binop_cont_tag,
+        modulo_continuation_components,
+    );
+
+    // head == DIV preimage
+    /////////////////////////////////////////////////////////////////////////////
+    let int_quotient_continuation_components: &[&dyn AsAllocatedHashComponents<F>; 4] =
+        &[&[&g.op2_int_quotient_tag, &g.default_num], env, &more, cont];
+    hash_default_results.add_hash_input_clauses(
+        *int_quotient_hash.value(),
+        &g.binop_cont_tag,
+        int_quotient_continuation_components,
+    );
+
     // head == = preimage
     /////////////////////////////////////////////////////////////////////////////
 
@@ -1625,6 +1968,50 @@ fn reduce_cons<F: LurkField, CS: ConstraintSystem<F>>(
         numequal_continuation_components,
     );
 
+    // head == < preimage
+    /////////////////////////////////////////////////////////////////////////////
+    let less_continuation_components: &[&dyn AsAllocatedHashComponents<F>; 4] =
+        &[&[&g.rel2_less_tag, &g.default_num], env, &more, cont];
+    hash_default_results.add_hash_input_clauses(
+        *less_hash.value(),
+        &g.relop_cont_tag,
+        less_continuation_components,
+    );
+
+    // head == > preimage
+    /////////////////////////////////////////////////////////////////////////////
+    let greater_continuation_components: &[&dyn AsAllocatedHashComponents<F>; 4] =
+        &[&[&g.rel2_greater_tag, &g.default_num], env, &more, cont];
+    hash_default_results.add_hash_input_clauses(
+        *greater_hash.value(),
+        &g.relop_cont_tag,
+        greater_continuation_components,
+    );
+
+    // head == <= preimage
+    /////////////////////////////////////////////////////////////////////////////
+    let lessequal_continuation_components: &[&dyn AsAllocatedHashComponents<F>; 4] =
+        &[&[&g.rel2_lessequal_tag, &g.default_num], env, &more, cont];
+    hash_default_results.add_hash_input_clauses(
+        *lessequal_hash.value(),
+        &g.relop_cont_tag,
+        lessequal_continuation_components,
+    );
+
+    // head == >= preimage
+    /////////////////////////////////////////////////////////////////////////////
+    let greaterequal_continuation_components: &[&dyn AsAllocatedHashComponents<F>; 4] = &[
+        &[&g.rel2_greaterequal_tag, &g.default_num],
+        env,
+        &more,
+        cont,
+    ];
+    hash_default_results.add_hash_input_clauses(
+        *greaterequal_hash.value(),
+        &g.relop_cont_tag,
+        greaterequal_continuation_components,
+    );
+
     // head == EQ preimage
     /////////////////////////////////////////////////////////////////////////////
     let equal_continuation_components: &[&dyn AsAllocatedHashComponents<F>; 4] =
@@ -1649,71 +2036,303 @@ fn reduce_cons<F: LurkField, CS: ConstraintSystem<F>>(
         if_continuation_components,
     );
 
-    // head == CURRENT-ENV
-    let the_cont_if_rest_is_nil = AllocatedContPtr::pick(
-        &mut cs.namespace(|| "the_cont_if_rest_is_nil"),
-        &rest_is_nil,
+    // head == AND preimage
+    /////////////////////////////////////////////////////////////////////////////
+    let and_continuation_components: &[&dyn AsAllocatedHashComponents<F>; 4] = &[
+        &more,
         cont,
-        &g.error_ptr_cont,
+        &[&g.default_num, &g.default_num],
+        &[&g.default_num, &g.default_num],
+    ];
+    hash_default_results.add_hash_input_clauses(
+        *and_hash.value(),
+        &g.and_cont_tag,
+        and_continuation_components,
+    );
+
+    // head == OR preimage
+    /////////////////////////////////////////////////////////////////////////////
+    let or_continuation_components: &[&dyn AsAllocatedHashComponents<F>; 4] = &[
+        &more,
+        cont,
+        &[&g.default_num, &g.default_num],
+        &[&g.default_num, &g.default_num],
+    ];
+    hash_default_results.add_hash_input_clauses(
+        *or_hash.value(),
+        &g.or_cont_tag,
+        or_continuation_components,
+    );
+
+    // head == COND preimage
+    /////////////////////////////////////////////////////////////////////////////
+    // (COND (C . BODY) . CLAUSES) becomes (IF C (BEGIN . BODY) (COND . CLAUSES)), and
+    // (COND (C) . CLAUSES) becomes (OR C (COND . CLAUSES)).
+    let cond_then = AllocatedPtr::construct_cons(
+        &mut cs.namespace(|| "cond then"),
+        g,
+        store,
+        &begin_t,
+        &cdr_arg1,
     )?;
-    results.add_clauses_cons(
-        *current_env_hash.value(),
-        env,
+    let cond_otherwise = AllocatedPtr::construct_cons(
+        &mut cs.namespace(|| "cond otherwise"),
+        g,
+        store,
+        &cond_t,
+        &more,
+    )?;
+    let cond_else = AllocatedPtr::construct_list(
+        &mut cs.namespace(|| "cond else"),
+        g,
+        store,
+        &[&cond_otherwise],
+    )?;
+    let cond_more = AllocatedPtr::construct_cons(
+        &mut cs.namespace(|| "cond more"),
+        g,
+        store,
+        &cond_then,
+        &cond_else,
+    )?;
+    let cond_body_is_nil =
+        cdr_arg1.alloc_equal(&mut cs.namespace(|| "cond body is nil"), &g.nil_ptr)?;
+    let cond_cont_tag = pick(
+        &mut cs.namespace(|| "cond cont tag"),
+        &cond_body_is_nil,
+        &g.or_cont_tag,
+        &g.if_cont_tag,
+    )?;
+    let cond_unevaled_args = AllocatedPtr::pick(
+        &mut cs.namespace(|| "cond unevaled args"),
+        &cond_body_is_nil,
+        &cond_else,
+        &cond_more,
+    )?;
+    let cond_continuation_components: &[&dyn AsAllocatedHashComponents<F>; 4] = &[
+        &cond_unevaled_args,
+        cont,
+        &[&g.default_num, &g.default_num],
+        &[&g.default_num, &g.default_num],
+    ];
+    hash_default_results.add_hash_input_clauses(
+        *cond_hash,
+        &cond_cont_tag,
+        cond_continuation_components,
+    );
+
+    // head == EVAL preimage
+    /////////////////////////////////////////////////////////////////////////////
+    let eval_continuation_components: &[&dyn AsAllocatedHashComponents<F>; 4] = &[
         env,
-        &the_cont_if_rest_is_nil,
-        &g.true_num,
+        cont,
+        &[&g.default_num, &g.default_num],
+        &[&g.default_num, &g.default_num],
+    ];
+    hash_default_results.add_hash_input_clauses(
+        *eval_hash.value(),
+        &g.eval_cont_tag,
+        eval_continuation_components,
     );
 
-    let (
-        cont_tag,
-        component0_tag,
-        component0_hash,
-        component1_tag,
-        component1_hash,
-        component2_tag,
-        component2_hash,
-    ) = {
-        let cont_tag = pick(
-            &mut cs.namespace(|| "pick cont_tag"),
-            &rest_is_nil,
-            &g.call0_cont_tag,
-            &g.call_cont_tag,
+    // head == ASSERT preimage
+    /////////////////////////////////////////////////////////////////////////////
+    // (ASSERT C) becomes (IF C T (FAIL (QUOTE C)))
+    let assert_more = {
+        let mut cs = cs.namespace(|| "assert");
+        let quoted = AllocatedPtr::construct_list(
+            &mut cs.namespace(|| "quoted condition"),
+            g,
+            store,
+            &[&quote_t, &arg1],
         )?;
-        let component0_tag = pick(
-            &mut cs.namespace(|| "pick component0_tag"),
-            &rest_is_nil,
+        let failure = AllocatedPtr::construct_list(
+            &mut cs.namespace(|| "failure"),
+            g,
+            store,
+            &[&fail_t, &quoted],
+        )?;
+        AllocatedPtr::construct_list(
+            &mut cs.namespace(|| "assert more"),
+            g,
+            store,
+            &[&g.t_ptr, &failure],
+        )?
+    };
+    let assert_continuation_components: &[&dyn AsAllocatedHashComponents<F>; 4] = &[
+        &assert_more,
+        cont,
+        &[&g.default_num, &g.default_num],
+        &[&g.default_num, &g.default_num],
+    ];
+    hash_default_results.add_hash_input_clauses(
+        *assert_hash.value(),
+        &g.if_cont_tag,
+        assert_continuation_components,
+    );
+
+    // A variadic function collects the arguments of its own call, which are held by the Call
+    // continuations of a multi-arg call's (FN ARG . &MORE) forms. The Call continuation of any other
+    // call is wrapped in a Tail, as call_boundary does in eval.rs.
+    let more_is_more_t = more.alloc_equal(&mut cs.namespace(|| "more_is_more_t"), &more_t)?;
+    let (boundary_cont, call_cont) = {
+        let mut cs = cs.namespace(|| "call boundary");
+        let cont_is_call = alloc_equal(
+            &mut cs.namespace(|| "cont_is_call"),
             cont.tag(),
-            env.tag(),
+            &g.call_cont_tag,
         )?;
-        let component0_hash = pick(
-            &mut cs.namespace(|| "pick component0_hash"),
-            &rest_is_nil,
-            cont.hash(),
-            env.hash(),
+        let tail_components: &[&dyn AsAllocatedHashComponents<F>; 4] = &[
+            env,
+            cont,
+            &[&g.default_num, &g.default_num],
+            &[&g.default_num, &g.default_num],
+        ];
+        let tail_cont = AllocatedContPtr::construct(
+            &mut cs.namespace(|| "tail_cont"),
+            store,
+            &g.tail_cont_tag,
+            tail_components,
+        )?;
+        let boundary_cont = AllocatedContPtr::pick(
+            &mut cs.namespace(|| "boundary_cont"),
+            &cont_is_call,
+            &tail_cont,
+            cont,
+        )?;
+        let call_cont = AllocatedContPtr::pick(
+            &mut cs.namespace(|| "call_cont"),
+            &more_is_more_t,
+            cont,
+            &boundary_cont,
+        )?;
+        (boundary_cont, call_cont)
+    };
+
+    // head == APPLY preimage
+    /////////////////////////////////////////////////////////////////////////////
+    // The Apply continuation starts with no unevaluated or collected arguments, and is laid out
+    // like Call.
+    let apply_continuation_components: &[&dyn AsAllocatedHashComponents<F>; 4] =
+        &[env, &g.nil_ptr, &boundary_cont, &g.nil_ptr];
+    hash_default_results.add_hash_input_clauses(
+        *apply_hash.value(),
+        &g.apply_cont_tag,
+        apply_continuation_components,
+    );
+
+    // head == CATCH preimage
+    /////////////////////////////////////////////////////////////////////////////
+    // The Catch continuation is laid out like Call, with the tag as its argument.
+    let catch_continuation_components: &[&dyn AsAllocatedHashComponents<F>; 4] =
+        &[env, &arg1, cont, &[&g.default_num, &g.default_num]];
+    hash_default_results.add_hash_input_clauses(
+        *catch_hash.value(),
+        &g.catch_cont_tag,
+        catch_continuation_components,
+    );
+
+    // head == THROW preimage
+    /////////////////////////////////////////////////////////////////////////////
+    let throw_continuation_components: &[&dyn AsAllocatedHashComponents<F>; 4] = &[
+        &arg1,
+        cont,
+        &[&g.default_num, &g.default_num],
+        &[&g.default_num, &g.default_num],
+    ];
+    hash_default_results.add_hash_input_clauses(
+        *throw_hash.value(),
+        &g.throw_cont_tag,
+        throw_continuation_components,
+    );
+
+    // head == CURRENT-ENV
+    let the_expr_if_rest_is_nil = AllocatedPtr::pick(
+        &mut cs.namespace(|| "the_expr_if_rest_is_nil"),
+        &rest_is_nil,
+        env,
+        expr,
+    )?;
+    let the_cont_if_rest_is_nil = AllocatedContPtr::pick(
+        &mut cs.namespace(|| "the_cont_if_rest_is_nil"),
+        &rest_is_nil,
+        cont,
+        &g.error_ptr_cont,
+    )?;
+    results.add_clauses_cons(
+        *current_env_hash.value(),
+        &the_expr_if_rest_is_nil,
+        env,
+        &the_cont_if_rest_is_nil,
+        &g.true_num,
+    );
+
+    // Call0 is laid out as [continuation, reversed_args, collected_args, _] and Call as [saved_env,
+    // unevaled_arg, continuation, reversed_args], with no collected arguments.
+    let (
+        cont_tag,
+        component0_tag,
+        component0_hash,
+        component1_tag,
+        component1_hash,
+        component2_tag,
+        component2_hash,
+        component3_tag,
+        component3_hash,
+    ) = {
+        let cont_tag = pick(
+            &mut cs.namespace(|| "pick cont_tag"),
+            &rest_is_nil,
+            &g.call0_cont_tag,
+            &g.call_cont_tag,
+        )?;
+        let component0_tag = pick(
+            &mut cs.namespace(|| "pick component0_tag"),
+            &rest_is_nil,
+            cont.tag(),
+            env.tag(),
+        )?;
+        let component0_hash = pick(
+            &mut cs.namespace(|| "pick component0_hash"),
+            &rest_is_nil,
+            cont.hash(),
+            env.hash(),
         )?;
         let component1_tag = pick(
             &mut cs.namespace(|| "pick component1_tag"),
             &rest_is_nil,
-            &g.default_num,
+            g.nil_ptr.tag(),
             arg1.tag(),
         )?;
         let component1_hash = pick(
             &mut cs.namespace(|| "pick component1_hash"),
             &rest_is_nil,
-            &g.default_num,
+            g.nil_ptr.hash(),
             arg1.hash(),
         )?;
         let component2_tag = pick(
             &mut cs.namespace(|| "pick component2_tag"),
             &rest_is_nil,
-            &g.default_num,
-            cont.tag(),
+            g.nil_ptr.tag(),
+            call_cont.tag(),
         )?;
         let component2_hash = pick(
             &mut cs.namespace(|| "pick component2_hash"),
             &rest_is_nil,
+            g.nil_ptr.hash(),
+            call_cont.hash(),
+        )?;
+        let component3_tag = pick(
+            &mut cs.namespace(|| "pick component3_tag"),
+            &rest_is_nil,
             &g.default_num,
-            cont.hash(),
+            g.nil_ptr.tag(),
+        )?;
+        let component3_hash = pick(
+            &mut cs.namespace(|| "pick component3_hash"),
+            &rest_is_nil,
+            &g.default_num,
+            g.nil_ptr.hash(),
         )?;
 
         (
@@ -1724,6 +2343,8 @@ fn reduce_cons<F: LurkField, CS: ConstraintSystem<F>>(
             component1_hash,
             component2_tag,
             component2_hash,
+            component3_tag,
+            component3_hash,
         )
     };
 
@@ -1735,8 +2356,8 @@ fn reduce_cons<F: LurkField, CS: ConstraintSystem<F>>(
         &component1_hash,
         &component2_tag,
         &component2_hash,
-        &g.default_num,
-        &g.default_num,
+        &component3_tag,
+        &component3_hash,
     ];
 
     /////////////////////////// multicase (hash preimage)
@@ -1804,6 +2425,17 @@ fn reduce_cons<F: LurkField, CS: ConstraintSystem<F>>(
     )?;
     results.add_clauses_cons(*cons_hash.value(), &arg1, env, &the_cont_cons, &g.false_num);
 
+    // head == STRCONS, newer_cont is allocated
+    /////////////////////////////////////////////////////////////////////////////
+    // One-arg strcons is an error just like one-arg cons.
+    results.add_clauses_cons(
+        *strcons_hash.value(),
+        &arg1,
+        env,
+        &the_cont_cons,
+        &g.false_num,
+    );
+
     // head == BEGIN, newer_cont is allocated
     /////////////////////////////////////////////////////////////////////////////
     let cont_begin = AllocatedContPtr::pick(
@@ -1911,6 +2543,16 @@ fn reduce_cons<F: LurkField, CS: ConstraintSystem<F>>(
         &g.false_num,
     );
 
+    // head == U64, newer_cont is allocated
+    /////////////////////////////////////////////////////////////////////////////
+    results.add_clauses_cons(
+        *u64_hash.value(),
+        &arg1_or_expr,
+        env,
+        &newer_cont_if_end_is_nil,
+        &g.false_num,
+    );
+
     // head == ATOM, newer_cont is allocated
     /////////////////////////////////////////////////////////////////////////////
     results.add_clauses_cons(
@@ -1931,93 +2573,470 @@ fn reduce_cons<F: LurkField, CS: ConstraintSystem<F>>(
         &g.false_num,
     );
 
-    // head == +, newer_cont is allocated
+    // head == TYPE-OF and the type predicates, newer_cont is allocated
     /////////////////////////////////////////////////////////////////////////////
-    results.add_clauses_cons(*sum_hash.value(), &arg1, env, &newer_cont, &g.false_num);
+    for op in Op1::TYPE_OPS.iter() {
+        results.add_clauses_cons(
+            *hash_sym(op.type_op_name().unwrap()).value(),
+            &arg1_or_expr,
+            env,
+            &newer_cont_if_end_is_nil,
+            &g.false_num,
+        );
+    }
 
-    // head == -, newer_cont is allocated
+    // head == + - * /, newer_cont is allocated
     /////////////////////////////////////////////////////////////////////////////
-    results.add_clauses_cons(*diff_hash.value(), &arg1, env, &newer_cont, &g.false_num);
+    let zero = AllocatedPtr::from_parts(g.num_tag.clone(), g.false_num.clone());
+    let one = AllocatedPtr::from_parts(g.num_tag.clone(), g.true_num.clone());
 
-    // head == *, newer_cont is allocated
-    /////////////////////////////////////////////////////////////////////////////
-    results.add_clauses_cons(*product_hash.value(), &arg1, env, &newer_cont, &g.false_num);
+    let arg1_or_zero = AllocatedPtr::pick(
+        &mut cs.namespace(|| "arg1 or zero"),
+        &end_is_nil,
+        &zero,
+        &arg1,
+    )?;
 
-    // head == /, newer_cont is allocated
-    /////////////////////////////////////////////////////////////////////////////
+    let arg1_or_one = AllocatedPtr::pick(
+        &mut cs.namespace(|| "arg1 or one"),
+        &end_is_nil,
+        &one,
+        &arg1,
+    )?;
+
+    // (+) is 0 and (*) is 1, which are returned right away.
+    let newer_cont_if_rest_is_not_nil = AllocatedContPtr::pick(
+        &mut cs.namespace(|| "newer_cont if rest is not nil"),
+        &rest_is_nil,
+        cont,
+        &newer_cont,
+    )?;
+
+    let rest_is_nil_num = pick(
+        &mut cs.namespace(|| "rest_is_nil num"),
+        &rest_is_nil,
+        &g.true_num,
+        &g.false_num,
+    )?;
+
+    // (-) and (/) are errors.
+    let newer_cont_or_error = AllocatedContPtr::pick(
+        &mut cs.namespace(|| "newer_cont or error"),
+        &rest_is_nil,
+        &g.error_ptr_cont,
+        &newer_cont,
+    )?;
+
+    let expr_or_arg1_or_zero = AllocatedPtr::pick(
+        &mut cs.namespace(|| "expr or arg1 or zero"),
+        &rest_is_nil,
+        expr,
+        &arg1_or_zero,
+    )?;
+
+    let expr_or_arg1_or_one = AllocatedPtr::pick(
+        &mut cs.namespace(|| "expr or arg1 or one"),
+        &rest_is_nil,
+        expr,
+        &arg1_or_one,
+    )?;
+
+    // (+ x) and (* x) are x, once Binop has checked it is a number.
+    let zero_or_arg1 = AllocatedPtr::pick(
+        &mut cs.namespace(|| "zero or arg1"),
+        &rest_is_nil,
+        &zero,
+        &arg1,
+    )?;
+
+    let one_or_arg1 = AllocatedPtr::pick(
+        &mut cs.namespace(|| "one or arg1"),
+        &rest_is_nil,
+        &one,
+        &arg1,
+    )?;
+
+    results.add_clauses_cons(
+        *sum_hash.value(),
+        &zero_or_arg1,
+        env,
+        &newer_cont_if_rest_is_not_nil,
+        &rest_is_nil_num,
+    );
+    results.add_clauses_cons(
+        *diff_hash.value(),
+        &expr_or_arg1_or_zero,
+        env,
+        &newer_cont_or_error,
+        &g.false_num,
+    );
+    results.add_clauses_cons(
+        *product_hash.value(),
+        &one_or_arg1,
+        env,
+        &newer_cont_if_rest_is_not_nil,
+        &rest_is_nil_num,
+    );
     results.add_clauses_cons(
         *quotient_hash.value(),
-        &arg1,
+        &expr_or_arg1_or_one,
         env,
-        &newer_cont,
+        &newer_cont_or_error,
         &g.false_num,
     );
 
-    // head == =, newer_cont is allocated
+    // head == % or DIV, newer_cont is allocated
     /////////////////////////////////////////////////////////////////////////////
-    results.add_clauses_cons(
-        *numequal_hash.value(),
+    // They have no identity, so with fewer than two arguments they are errors.
+    let expr_or_arg1 = AllocatedPtr::pick(
+        &mut cs.namespace(|| "expr or arg1"),
+        &end_is_nil,
+        expr,
         &arg1,
-        env,
+    )?;
+
+    let error_or_newer_cont = AllocatedContPtr::pick(
+        &mut cs.namespace(|| "error or newer_cont"),
+        &end_is_nil,
+        &g.error_ptr_cont,
         &newer_cont,
+    )?;
+
+    results.add_clauses_cons(
+        *modulo_hash.value(),
+        &expr_or_arg1,
+        env,
+        &error_or_newer_cont,
+        &g.false_num,
+    );
+    results.add_clauses_cons(
+        *int_quotient_hash.value(),
+        &expr_or_arg1,
+        env,
+        &error_or_newer_cont,
         &g.false_num,
     );
 
-    // head == EQ, newer_cont is allocated
+    // head == = < > <= >= EQ, newer_cont is allocated
     /////////////////////////////////////////////////////////////////////////////
-    results.add_clauses_cons(*equal_hash.value(), &arg1, env, &newer_cont, &g.false_num);
+    // A relation with no arguments is T. Otherwise its first argument is evaluated, and checked by
+    // Relop even if it is the only one.
+    let t_or_arg1 = AllocatedPtr::pick(
+        &mut cs.namespace(|| "t or arg1"),
+        &rest_is_nil,
+        &g.t_ptr,
+        &arg1,
+    )?;
+    for rel_hash in [
+        numequal_hash,
+        less_hash,
+        greater_hash,
+        lessequal_hash,
+        greaterequal_hash,
+        equal_hash,
+    ] {
+        results.add_clauses_cons(
+            *rel_hash.value(),
+            &t_or_arg1,
+            env,
+            &newer_cont_if_rest_is_not_nil,
+            &rest_is_nil_num,
+        );
+    }
 
     // head == IF, newer_cont is allocated
     /////////////////////////////////////////////////////////////////////////////
     results.add_clauses_cons(*if_hash.value(), &arg1, env, &newer_cont, &g.false_num);
 
-    // head == (FN . ARGS), newer_cont is allocated (deal with CALL and CALL0)
+    // head == AND, newer_cont is allocated
     /////////////////////////////////////////////////////////////////////////////
-    let (res, continuation) = {
-        let fun_form = &head;
+    // (AND) is T, and (AND X) is X.
+    results.add_clauses_cons(
+        *and_hash.value(),
+        &t_or_arg1,
+        env,
+        &cont_begin,
+        &rest_is_nil_num,
+    );
 
-        let expanded_inner = AllocatedPtr::construct_list(
-            &mut cs.namespace(|| "expanded_inner"),
+    // head == OR, newer_cont is allocated
+    /////////////////////////////////////////////////////////////////////////////
+    // (OR) is NIL, which is arg1 when rest is nil, and (OR X) is X.
+    results.add_clauses_cons(*or_hash.value(), &arg1, env, &cont_begin, &rest_is_nil_num);
+
+    // head == COND, newer_cont is allocated
+    /////////////////////////////////////////////////////////////////////////////
+    // (COND) is NIL, which is car_arg1 when rest is nil.
+    results.add_clauses_cons(
+        *cond_hash,
+        &car_arg1,
+        env,
+        &newer_cont_if_rest_is_not_nil,
+        &rest_is_nil_num,
+    );
+
+    // head == EVAL, newer_cont is allocated
+    /////////////////////////////////////////////////////////////////////////////
+    // (EVAL EXPR ENV) evaluates (CONS EXPR ENV), and (EVAL EXPR) has a nil ENV.
+    let (eval_expr, eval_cont) = {
+        let mut cs = cs.namespace(|| "eval");
+        let expr_and_env = AllocatedPtr::construct_list(
+            &mut cs.namespace(|| "expr and env"),
             g,
             store,
-            &[fun_form, &arg1],
+            &[&cons_t, &arg1, &body1],
+        )?;
+        let eval_error = or!(cs, &rest_is_nil, &rest_body_is_nil.not())?;
+        let eval_expr = AllocatedPtr::pick(
+            &mut cs.namespace(|| "eval expr"),
+            &eval_error,
+            expr,
+            &expr_and_env,
         )?;
+        let eval_cont = AllocatedContPtr::pick(
+            &mut cs.namespace(|| "eval cont"),
+            &eval_error,
+            &g.error_ptr_cont,
+            &newer_cont,
+        )?;
+        (eval_expr, eval_cont)
+    };
+    results.add_clauses_cons(
+        *eval_hash.value(),
+        &eval_expr,
+        env,
+        &eval_cont,
+        &g.false_num,
+    );
 
-        let expanded = AllocatedPtr::construct_cons(
-            &mut cs.namespace(|| "expanded"),
+    // head == FAIL and head == ASSERT, newer_cont is allocated for ASSERT
+    /////////////////////////////////////////////////////////////////////////////
+    // Both take exactly one argument, which is evaluated first.
+    let (one_arg_or_expr, fail_cont, assert_cont) = {
+        let mut cs = cs.namespace(|| "fail and assert");
+        let not_one_arg = or!(cs, &rest_is_nil, &end_is_nil.not())?;
+        let one_arg_or_expr = AllocatedPtr::pick(
+            &mut cs.namespace(|| "one arg or expr"),
+            &not_one_arg,
+            expr,
+            &arg1,
+        )?;
+        let fail_cont = AllocatedContPtr::pick(
+            &mut cs.namespace(|| "fail cont"),
+            &not_one_arg,
+            &g.error_ptr_cont,
+            &g.fail_ptr,
+        )?;
+        let assert_cont = AllocatedContPtr::pick(
+            &mut cs.namespace(|| "assert cont"),
+            &not_one_arg,
+            &g.error_ptr_cont,
+            &newer_cont,
+        )?;
+        (one_arg_or_expr, fail_cont, assert_cont)
+    };
+    results.add_clauses_cons(*fail_hash, &one_arg_or_expr, env, &fail_cont, &g.false_num);
+    results.add_clauses_cons(
+        *assert_hash.value(),
+        &one_arg_or_expr,
+        env,
+        &assert_cont,
+        &g.false_num,
+    );
+
+    // head == APPLY, newer_cont is allocated
+    /////////////////////////////////////////////////////////////////////////////
+    // (APPLY FN ARGS) evaluates (CONS FN ARGS). When rest is nil, so is more.
+    let (apply_expr, apply_cont) = {
+        let mut cs = cs.namespace(|| "apply");
+        let fun_and_args = AllocatedPtr::construct_cons(
+            &mut cs.namespace(|| "fun and args"),
             g,
             store,
-            &expanded_inner,
-            &more,
+            &cons_t,
+            &rest,
         )?;
-
-        let more_args_is_nil =
-            more.alloc_equal(&mut cs.namespace(|| "more_args_is_nil"), &g.nil_ptr)?;
-
-        let res = AllocatedPtr::pick(
-            &mut cs.namespace(|| "pick res"),
-            &more_args_is_nil,
-            fun_form,
-            &expanded,
+        let apply_error = or!(cs, &end_is_nil, &rest_body_is_nil.not())?;
+        let apply_expr = AllocatedPtr::pick(
+            &mut cs.namespace(|| "apply expr"),
+            &apply_error,
+            expr,
+            &fun_and_args,
         )?;
-
-        let continuation = AllocatedContPtr::pick(
-            &mut cs.namespace(|| "pick continuation"),
-            &more_args_is_nil,
+        let apply_cont = AllocatedContPtr::pick(
+            &mut cs.namespace(|| "apply cont"),
+            &apply_error,
+            &g.error_ptr_cont,
             &newer_cont,
-            cont,
         )?;
-
-        (res, continuation)
+        (apply_expr, apply_cont)
     };
-
-    let defaults = [
-        res.tag(),
-        res.hash(),
-        env.tag(),
-        env.hash(),
-        continuation.tag(),
-        continuation.hash(),
+    results.add_clauses_cons(
+        *apply_hash.value(),
+        &apply_expr,
+        env,
+        &apply_cont,
+        &g.false_num,
+    );
+
+    // head == CATCH and head == THROW, newer_cont is allocated
+    /////////////////////////////////////////////////////////////////////////////
+    // Both take exactly two arguments: a tag, which is not evaluated, and an expression.
+    let (catch_throw_expr, catch_throw_cont) = {
+        let mut cs = cs.namespace(|| "catch and throw");
+        let catch_throw_error = or!(cs, &end_is_nil, &rest_body_is_nil.not())?;
+        let catch_throw_expr = AllocatedPtr::pick(
+            &mut cs.namespace(|| "catch and throw expr"),
+            &catch_throw_error,
+            expr,
+            &body1,
+        )?;
+        let catch_throw_cont = AllocatedContPtr::pick(
+            &mut cs.namespace(|| "catch and throw cont"),
+            &catch_throw_error,
+            &g.error_ptr_cont,
+            &newer_cont,
+        )?;
+        (catch_throw_expr, catch_throw_cont)
+    };
+    results.add_clauses_cons(
+        *catch_hash.value(),
+        &catch_throw_expr,
+        env,
+        &catch_throw_cont,
+        &g.false_num,
+    );
+    results.add_clauses_cons(
+        *throw_hash.value(),
+        &catch_throw_expr,
+        env,
+        &catch_throw_cont,
+        &g.false_num,
+    );
+
+    // head == WITNESS and head == READ
+    /////////////////////////////////////////////////////////////////////////////
+    // (WITNESS NAME) takes exactly one argument, which is not evaluated, and (READ) none. Their
+    // value comes from the oracle, so it is a private witness which nothing else constrains, and so
+    // is whether the oracle had a value at all: without one, the form is an error. Both are the
+    // prover's choice, so an error proved here only means the prover supplied no value.
+    let (witness_results, read_results) = {
+        let mut cs = cs.namespace(|| "witness and read");
+        let value = witness
+            .as_ref()
+            .and_then(|w| w.oracle_value)
+            .unwrap_or_else(|| store.get_nil());
+        let oracle_value =
+            AllocatedPtr::alloc_ptr(&mut cs.namespace(|| "oracle value"), store, || Ok(&value))?;
+        let oracle_missing = Boolean::Is(AllocatedBit::alloc(
+            &mut cs.namespace(|| "oracle missing"),
+            witness.as_ref().map(|w| w.oracle_value.is_none()),
+        )?);
+        let witness_malformed = or!(cs, &rest_is_nil, &end_is_nil.not())?;
+        let witness_error = or!(cs, &witness_malformed, &oracle_missing)?;
+        let read_error = or!(cs, &rest_is_nil.not(), &oracle_missing)?;
+
+        let mut results_for = |name: &str, error: &Boolean| {
+            let the_expr = AllocatedPtr::pick(
+                &mut cs.namespace(|| format!("{} expr", name)),
+                error,
+                expr,
+                &oracle_value,
+            )?;
+            let the_cont = AllocatedContPtr::pick(
+                &mut cs.namespace(|| format!("{} cont", name)),
+                error,
+                &g.error_ptr_cont,
+                cont,
+            )?;
+            let apply_cont = pick(
+                &mut cs.namespace(|| format!("{} apply_cont", name)),
+                error,
+                &g.false_num,
+                &g.true_num,
+            )?;
+            Ok::<_, SynthesisError>((the_expr, the_cont, apply_cont))
+        };
+        (
+            results_for("witness", &witness_error)?,
+            results_for("read", &read_error)?,
+        )
+    };
+    let (witness_expr, witness_cont, witness_apply_cont) = &witness_results;
+    results.add_clauses_cons(
+        *witness_hash.value(),
+        witness_expr,
+        env,
+        witness_cont,
+        witness_apply_cont,
+    );
+    let (read_expr, read_cont, read_apply_cont) = &read_results;
+    results.add_clauses_cons(
+        *read_hash.value(),
+        read_expr,
+        env,
+        read_cont,
+        read_apply_cont,
+    );
+
+    // head == (FN . ARGS), newer_cont is allocated (deal with CALL and CALL0)
+    /////////////////////////////////////////////////////////////////////////////
+    let (res, continuation) = {
+        let fun_form = &head;
+
+        // (FN ARG . MORE) becomes ((FN ARG . &MORE) . MORE).
+        let arg_and_more = AllocatedPtr::construct_cons(
+            &mut cs.namespace(|| "arg_and_more"),
+            g,
+            store,
+            &arg1,
+            &more_t,
+        )?;
+
+        let expanded_inner = AllocatedPtr::construct_cons(
+            &mut cs.namespace(|| "expanded_inner"),
+            g,
+            store,
+            fun_form,
+            &arg_and_more,
+        )?;
+
+        let expanded = AllocatedPtr::construct_cons(
+            &mut cs.namespace(|| "expanded"),
+            g,
+            store,
+            &expanded_inner,
+            &more,
+        )?;
+
+        let more_args_is_nil =
+            more.alloc_equal(&mut cs.namespace(|| "more_args_is_nil"), &g.nil_ptr)?;
+        let is_call = or!(cs, &more_args_is_nil, &more_is_more_t)?;
+
+        let res = AllocatedPtr::pick(
+            &mut cs.namespace(|| "pick res"),
+            &is_call,
+            fun_form,
+            &expanded,
+        )?;
+
+        let continuation = AllocatedContPtr::pick(
+            &mut cs.namespace(|| "pick continuation"),
+            &is_call,
+            &newer_cont,
+            cont,
+        )?;
+
+        (res, continuation)
+    };
+
+    let defaults = [
+        res.tag(),
+        res.hash(),
+        env.tag(),
+        env.hash(),
+        continuation.tag(),
+        continuation.hash(),
         &g.false_num,
     ];
 
@@ -2165,6 +3184,8 @@ fn apply_continuation<F: LurkField, CS: ConstraintSystem<F>>(
         &g.false_num,
     );
     results.add_clauses_cont(ContTag::Error, result, env, &g.error_ptr_cont, &g.false_num);
+    // The value of (FAIL VALUE) becomes the error's expression.
+    results.add_clauses_cont(ContTag::Fail, result, env, &g.error_ptr_cont, &g.false_num);
 
     let (_, continuation_components) = ContPtr::allocate_maybe_dummy_components(
         &mut cs.namespace(|| "allocate_continuation_components"),
@@ -2187,29 +3208,59 @@ fn apply_continuation<F: LurkField, CS: ConstraintSystem<F>>(
 
     // Continuation::Call0 preimage
     /////////////////////////////////////////////////////////////////////////////
-    let old_continuation_components: &[&dyn AsAllocatedHashComponents<F>; 4] = &[
-        &AllocatedPtr::by_index(0, &continuation_components),
-        &AllocatedPtr::by_index(1, &continuation_components),
-        &AllocatedPtr::by_index(2, &continuation_components),
-        &AllocatedPtr::by_index(3, &continuation_components),
+    // A variadic function's collected arguments are moved one at a time.
+    let (call0_reversed_args_is_nil, call0_collected_args, call0_components_results) = {
+        let mut cs = cs.namespace(|| "Call0 preimage");
+        let reversed_args = AllocatedPtr::by_index(1, &continuation_components);
+        let collected_args = AllocatedPtr::by_index(2, &continuation_components);
+
+        let reversed_args_is_nil =
+            reversed_args.alloc_equal(&mut cs.namespace(|| "reversed_args_is_nil"), &g.nil_ptr)?;
+        let (arg, reversed_tail) = car_cdr(
+            &mut cs.namespace(|| "car_cdr reversed_args"),
+            g,
+            &reversed_args,
+            store,
+        )?;
+        let newer_collected_args = AllocatedPtr::construct_cons(
+            &mut cs.namespace(|| "newer collected_args"),
+            g,
+            store,
+            &arg,
+            &collected_args,
+        )?;
+
+        (
+            reversed_args_is_nil,
+            collected_args,
+            (reversed_tail, newer_collected_args),
+        )
+    };
+    let (call0_reversed_tail, call0_newer_collected_args) = &call0_components_results;
+    let call0_components: &[&dyn AsAllocatedHashComponents<F>; 4] = &[
+        &continuation,
+        call0_reversed_tail,
+        call0_newer_collected_args,
+        default_num_pair,
     ];
     hash_default_results.add_hash_input_clauses(
         ContTag::Call0.as_field(),
-        &g.tail_cont_tag,
-        old_continuation_components,
+        &g.call0_cont_tag,
+        call0_components,
     );
 
     // Continuation::Call preimage
     /////////////////////////////////////////////////////////////////////////////
-    let (saved_env, continuation, function) = {
+    let (saved_env, continuation, function, reversed_args) = {
         (
             AllocatedPtr::by_index(0, &continuation_components),
             AllocatedContPtr::by_index(2, &continuation_components),
             result,
+            AllocatedPtr::by_index(3, &continuation_components),
         )
     };
     let call_components: &[&dyn AsAllocatedHashComponents<F>; 4] =
-        &[&saved_env, function, &continuation, default_num_pair];
+        &[&saved_env, function, &continuation, &reversed_args];
     hash_default_results.add_hash_input_clauses(
         ContTag::Call.as_field(),
         &g.call2_cont_tag,
@@ -2218,21 +3269,163 @@ fn apply_continuation<F: LurkField, CS: ConstraintSystem<F>>(
 
     // Continuation::Call2 preimage
     /////////////////////////////////////////////////////////////////////////////
-    let (saved_env, continuation) = {
+    // The function is called with a Tail continuation. A variadic function instead collects the
+    // argument: if the continuation is the Call or Apply holding the call's next argument, it is
+    // rebuilt with the collected ones, and otherwise they are passed to Call0.
+    let (call2_fun_parts, call2_fun_is_variadic, call2_newer_cont_tag, call2_newer_cont_components) = {
+        let mut cs = cs.namespace(|| "Call2 preimage");
+        let saved_env = AllocatedPtr::by_index(0, &continuation_components);
+        let fun = AllocatedPtr::by_index(1, &continuation_components);
+        let continuation = AllocatedContPtr::by_index(2, &continuation_components);
+        let reversed_args = AllocatedPtr::by_index(3, &continuation_components);
+
+        let (hash, arg_t, body_t, closed_env) = Ptr::allocate_maybe_fun(
+            &mut cs.namespace(|| "allocate fun"),
+            store,
+            fun.ptr(store).as_ref(),
+        )?;
+
+        let fun_is_correct = constraints::alloc_equal(
+            &mut cs.namespace(|| "fun hash is correct"),
+            fun.hash(),
+            &hash,
+        )?;
+
+        let cont_is_call2_precomp = constraints::alloc_equal(
+            &mut cs.namespace(|| "branch taken"),
+            cont.tag(),
+            &g.call2_cont_tag,
+        )?;
+
+        let cont_is_call2_and_not_dummy = and!(cs, &cont_is_call2_precomp, not_dummy)?;
+
+        enforce_implication(
+            &mut cs.namespace(|| "implies non-dummy fun"),
+            &cont_is_call2_and_not_dummy,
+            &fun_is_correct,
+        )?;
+
+        let fun_is_variadic = alloc_equal(
+            &mut cs.namespace(|| "fun_is_variadic"),
+            arg_t.tag(),
+            &g.cons_tag,
+        )?;
+        let newer_reversed_args = AllocatedPtr::construct_cons(
+            &mut cs.namespace(|| "newer reversed_args"),
+            g,
+            store,
+            result,
+            &reversed_args,
+        )?;
+
+        let continuation_witness = witness
+            .as_ref()
+            .and_then(|w| w.apply_continuation_cont.as_ref())
+            .and_then(|c| match store.fetch_cont(c) {
+                Some(Continuation::Call2 { continuation, .. }) => Some(continuation),
+                _ => None,
+            });
+        let (continuation_hash, next_components) = ContPtr::allocate_maybe_dummy_components(
+            &mut cs.namespace(|| "continuation components"),
+            continuation_witness.as_ref(),
+            store,
+        )?;
+        implies_equal!(
+            cs,
+            &cont_is_call2_and_not_dummy,
+            &continuation_hash,
+            continuation.hash()
+        );
+        let continuation_is_call = alloc_equal(
+            &mut cs.namespace(|| "continuation is call"),
+            continuation.tag(),
+            &g.call_cont_tag,
+        )?;
+        let continuation_is_apply = alloc_equal(
+            &mut cs.namespace(|| "continuation is apply"),
+            continuation.tag(),
+            &g.apply_cont_tag,
+        )?;
+        let continuation_has_args = or!(cs, &continuation_is_call, &continuation_is_apply)?;
+
+        // Tail is laid out as [saved_env, continuation, _, _], Call and Apply as [saved_env,
+        // unevaled_args, continuation, reversed_args], and Call0 as [continuation, reversed_args,
+        // collected_args, _].
+        let variadic_tag = pick(
+            &mut cs.namespace(|| "variadic newer_cont tag"),
+            &continuation_has_args,
+            continuation.tag(),
+            &g.call0_cont_tag,
+        )?;
+        let newer_cont_tag = pick(
+            &mut cs.namespace(|| "newer_cont tag"),
+            &fun_is_variadic,
+            &variadic_tag,
+            &g.tail_cont_tag,
+        )?;
+        // Each component is (WITH ARGS, CALL0, TAIL).
+        let choices = [
+            (&next_components[0], continuation.tag(), saved_env.tag()),
+            (&next_components[1], continuation.hash(), saved_env.hash()),
+            (
+                &next_components[2],
+                newer_reversed_args.tag(),
+                continuation.tag(),
+            ),
+            (
+                &next_components[3],
+                newer_reversed_args.hash(),
+                continuation.hash(),
+            ),
+            (&next_components[4], g.nil_ptr.tag(), &g.default_num),
+            (&next_components[5], g.nil_ptr.hash(), &g.default_num),
+            (newer_reversed_args.tag(), &g.default_num, &g.default_num),
+            (newer_reversed_args.hash(), &g.default_num, &g.default_num),
+        ];
+        let mut newer_cont_components = Vec::with_capacity(choices.len());
+        for (i, &(with_args, call0, tail)) in choices.iter().enumerate() {
+            let variadic = pick(
+                &mut cs.namespace(|| format!("variadic component {}", i)),
+                &continuation_has_args,
+                with_args,
+                call0,
+            )?;
+            newer_cont_components.push(pick(
+                &mut cs.namespace(|| format!("component {}", i)),
+                &fun_is_variadic,
+                &variadic,
+                tail,
+            )?);
+        }
+
         (
-            AllocatedPtr::by_index(0, &continuation_components),
-            AllocatedContPtr::by_index(2, &continuation_components),
+            (arg_t, body_t, closed_env),
+            fun_is_variadic,
+            newer_cont_tag,
+            newer_cont_components,
         )
     };
     let call2_components: &[&dyn AsAllocatedHashComponents<F>; 4] = &[
-        &saved_env,
-        &continuation,
-        default_num_pair,
-        default_num_pair,
+        &[
+            &call2_newer_cont_components[0],
+            &call2_newer_cont_components[1],
+        ],
+        &[
+            &call2_newer_cont_components[2],
+            &call2_newer_cont_components[3],
+        ],
+        &[
+            &call2_newer_cont_components[4],
+            &call2_newer_cont_components[5],
+        ],
+        &[
+            &call2_newer_cont_components[6],
+            &call2_newer_cont_components[7],
+        ],
     ];
     hash_default_results.add_hash_input_clauses(
         ContTag::Call2.as_field(),
-        &g.tail_cont_tag,
+        &call2_newer_cont_tag,
         call2_components,
     );
 
@@ -2270,7 +3463,7 @@ fn apply_continuation<F: LurkField, CS: ConstraintSystem<F>>(
 
     // Continuation::Unop preimage
     /////////////////////////////////////////////////////////////////////////////
-    let (unop_val, unop_continuation) = {
+    let (unop_val, unop_continuation, u64_fits, u64_val) = {
         let op1 = AllocatedPtr::by_index(0, &continuation_components);
         let unop_continuation = AllocatedContPtr::by_index(1, &continuation_components);
 
@@ -2290,124 +3483,199 @@ fn apply_continuation<F: LurkField, CS: ConstraintSystem<F>>(
             &g.t_ptr,
         )?;
 
-        let op1_is_open = alloc_equal(
-            &mut cs.namespace(|| "op1 is open"),
-            op1.tag(),
-            &g.op1_open_tag,
+        let hide = hide(&mut cs.namespace(|| "Unop hide"), F::zero(), result, store)?;
+        let open = open(&mut cs.namespace(|| "Unop open"), result, store)?;
+        let secret = secret(&mut cs.namespace(|| "Unop secret"), result, store)?;
+        let num = num(&mut cs.namespace(|| "Unop num"), result, store)?;
+        let comm = comm(&mut cs.namespace(|| "Unop comm"), result, store)?;
+        // A character's hash is its code, so the result of CHAR has the hash of its argument. An
+        // argument which is not a character code is an error.
+        let c = AllocatedPtr::from_parts(g.char_tag.clone(), result.hash().clone());
+
+        // A Num which does not fit in a u64 is an error, rather than being truncated.
+        let (u64_fits, u64_val) =
+            alloc_fits_in_bits(&mut cs.namespace(|| "Unop u64 fits"), result.hash(), 64)?;
+        let u64 = AllocatedPtr::from_parts(g.u64_tag.clone(), u64_val.clone());
+
+        // TYPE-OF returns the keyword naming the tag of the result.
+        let mut keywords = Vec::new();
+        for tag in [
+            Tag::Nil,
+            Tag::Cons,
+            Tag::Sym,
+            Tag::Fun,
+            Tag::Num,
+            Tag::Thunk,
+            Tag::Str,
+            Tag::Char,
+            Tag::Comm,
+            Tag::U64,
+        ] {
+            let keyword = store
+                .get_sym(tag.keyword(), true)
+                .and_then(|s| store.get_hash_sym(s))
+                .unwrap();
+            let allocated = allocate_constant(
+                &mut cs.namespace(|| format!("{} keyword", tag.keyword())),
+                *keyword.value(),
+            )?;
+            keywords.push((tag.as_field(), allocated));
+        }
+        let keyword_clauses: Vec<_> = keywords
+            .iter()
+            .map(|(key, value)| CaseClause { key: *key, value })
+            .collect();
+        let keyword_hash = case(
+            &mut cs.namespace(|| "type-of keyword"),
+            result.tag(),
+            &keyword_clauses,
+            &g.default_num,
         )?;
+        let type_of = AllocatedPtr::from_parts(g.sym_tag.clone(), keyword_hash);
 
-        let op1_is_secret = alloc_equal(
-            &mut cs.namespace(|| "op1 is secret"),
+        // A type predicate compares the tag of the result with the one it tests for.
+        let mut predicate_tags = Vec::new();
+        for op in Op1::TYPE_OPS.iter() {
+            if let Some(tag) = op.predicate_tag() {
+                let allocated =
+                    tag.allocate_constant(&mut cs.namespace(|| format!("{} predicate tag", op)))?;
+                predicate_tags.push((op.as_field(), allocated));
+            }
+        }
+        let predicate_tag_clauses: Vec<_> = predicate_tags
+            .iter()
+            .map(|(key, value)| CaseClause { key: *key, value })
+            .collect();
+        let predicate_tag = case(
+            &mut cs.namespace(|| "predicate tag"),
             op1.tag(),
-            &g.op1_secret_tag,
+            &predicate_tag_clauses,
+            &g.default_num,
         )?;
-
-        let hide = hide(&mut cs.namespace(|| "Unop hide"), F::zero(), result, store)?;
-        let open = open(
-            &mut cs.namespace(|| "Unop open"),
-            &op1_is_open,
-            result,
-            store,
+        let predicate_holds = alloc_equal(
+            &mut cs.namespace(|| "predicate holds"),
+            result.tag(),
+            &predicate_tag,
         )?;
-        let secret = secret(
-            &mut cs.namespace(|| "Unop secret"),
-            &op1_is_secret,
-            result,
-            store,
+        let predicate = AllocatedPtr::pick(
+            &mut cs.namespace(|| "predicate val"),
+            &predicate_holds,
+            &g.t_ptr,
+            &g.nil_ptr,
         )?;
-        let num = num(&mut cs.namespace(|| "Unop num"), result, store)?;
-        let comm = comm(&mut cs.namespace(|| "Unop comm"), result, store)?;
-        let c = char_op(&mut cs.namespace(|| "Unop char"), result, store)?;
+
+        let mut tag_clauses = vec![
+            CaseClause {
+                key: Op1::Car.as_field(),
+                value: allocated_car.tag(),
+            },
+            CaseClause {
+                key: Op1::Cdr.as_field(),
+                value: allocated_cdr.tag(),
+            },
+            CaseClause {
+                key: Op1::Atom.as_field(),
+                value: atom_ptr.tag(),
+            },
+            CaseClause {
+                key: Op1::Emit.as_field(),
+                value: result.tag(),
+            },
+            CaseClause {
+                key: Op1::Commit.as_field(),
+                value: hide.tag(),
+            },
+            CaseClause {
+                key: Op1::Open.as_field(),
+                value: open.tag(),
+            },
+            CaseClause {
+                key: Op1::Secret.as_field(),
+                value: secret.tag(),
+            },
+            CaseClause {
+                key: Op1::Num.as_field(),
+                value: num.tag(),
+            },
+            CaseClause {
+                key: Op1::Comm.as_field(),
+                value: comm.tag(),
+            },
+            CaseClause {
+                key: Op1::Char.as_field(),
+                value: c.tag(),
+            },
+            CaseClause {
+                key: Op1::U64.as_field(),
+                value: u64.tag(),
+            },
+        ];
+        let mut hash_clauses = vec![
+            CaseClause {
+                key: Op1::Car.as_field(),
+                value: allocated_car.hash(),
+            },
+            CaseClause {
+                key: Op1::Cdr.as_field(),
+                value: allocated_cdr.hash(),
+            },
+            CaseClause {
+                key: Op1::Atom.as_field(),
+                value: atom_ptr.hash(),
+            },
+            CaseClause {
+                key: Op1::Emit.as_field(),
+                value: result.hash(),
+            },
+            CaseClause {
+                key: Op1::Commit.as_field(),
+                value: hide.hash(),
+            },
+            CaseClause {
+                key: Op1::Open.as_field(),
+                value: open.hash(),
+            },
+            CaseClause {
+                key: Op1::Secret.as_field(),
+                value: secret.hash(),
+            },
+            CaseClause {
+                key: Op1::Num.as_field(),
+                value: num.hash(),
+            },
+            CaseClause {
+                key: Op1::Comm.as_field(),
+                value: comm.hash(),
+            },
+            CaseClause {
+                key: Op1::Char.as_field(),
+                value: c.hash(),
+            },
+            CaseClause {
+                key: Op1::U64.as_field(),
+                value: u64.hash(),
+            },
+        ];
+        for op in Op1::TYPE_OPS.iter() {
+            let val = if *op == Op1::TypeOf {
+                &type_of
+            } else {
+                &predicate
+            };
+            tag_clauses.push(CaseClause {
+                key: op.as_field(),
+                value: val.tag(),
+            });
+            hash_clauses.push(CaseClause {
+                key: op.as_field(),
+                value: val.hash(),
+            });
+        }
 
         let res = multi_case(
             &mut cs.namespace(|| "Unop case"),
             op1.tag(),
-            &[
-                &[
-                    CaseClause {
-                        key: Op1::Car.as_field(),
-                        value: allocated_car.tag(),
-                    },
-                    CaseClause {
-                        key: Op1::Cdr.as_field(),
-                        value: allocated_cdr.tag(),
-                    },
-                    CaseClause {
-                        key: Op1::Atom.as_field(),
-                        value: atom_ptr.tag(),
-                    },
-                    CaseClause {
-                        key: Op1::Emit.as_field(),
-                        value: result.tag(),
-                    },
-                    CaseClause {
-                        key: Op1::Commit.as_field(),
-                        value: hide.tag(),
-                    },
-                    CaseClause {
-                        key: Op1::Open.as_field(),
-                        value: open.tag(),
-                    },
-                    CaseClause {
-                        key: Op1::Secret.as_field(),
-                        value: secret.tag(),
-                    },
-                    CaseClause {
-                        key: Op1::Num.as_field(),
-                        value: num.tag(),
-                    },
-                    CaseClause {
-                        key: Op1::Comm.as_field(),
-                        value: comm.tag(),
-                    },
-                    CaseClause {
-                        key: Op1::Char.as_field(),
-                        value: c.tag(),
-                    },
-                ],
-                &[
-                    CaseClause {
-                        key: Op1::Car.as_field(),
-                        value: allocated_car.hash(),
-                    },
-                    CaseClause {
-                        key: Op1::Cdr.as_field(),
-                        value: allocated_cdr.hash(),
-                    },
-                    CaseClause {
-                        key: Op1::Atom.as_field(),
-                        value: atom_ptr.hash(),
-                    },
-                    CaseClause {
-                        key: Op1::Emit.as_field(),
-                        value: result.hash(),
-                    },
-                    CaseClause {
-                        key: Op1::Commit.as_field(),
-                        value: hide.hash(),
-                    },
-                    CaseClause {
-                        key: Op1::Open.as_field(),
-                        value: open.hash(),
-                    },
-                    CaseClause {
-                        key: Op1::Secret.as_field(),
-                        value: secret.hash(),
-                    },
-                    CaseClause {
-                        key: Op1::Num.as_field(),
-                        value: num.hash(),
-                    },
-                    CaseClause {
-                        key: Op1::Comm.as_field(),
-                        value: comm.hash(),
-                    },
-                    CaseClause {
-                        key: Op1::Char.as_field(),
-                        value: c.hash(),
-                    },
-                ],
-            ],
+            &[&tag_clauses, &hash_clauses],
             &[
                 &g.default_num,
                 &g.default_num,
@@ -2416,7 +3684,12 @@ fn apply_continuation<F: LurkField, CS: ConstraintSystem<F>>(
             ],
         )?;
 
-        (AllocatedPtr::by_index(0, &res), unop_continuation)
+        (
+            AllocatedPtr::by_index(0, &res),
+            unop_continuation,
+            u64_fits,
+            u64_val,
+        )
     };
 
     let emit_components: &[&dyn AsAllocatedHashComponents<F>; 4] = &[
@@ -2431,68 +3704,1132 @@ fn apply_continuation<F: LurkField, CS: ConstraintSystem<F>>(
         emit_components,
     );
 
-    // Continuation::Binop preimage
-    /////////////////////////////////////////////////////////////////////////////
-    let (op2, continuation) = {
-        (
-            &continuation_components[0],
-            AllocatedContPtr::by_index(3, &continuation_components),
-        )
-    };
-    let binop_components: &[&dyn AsAllocatedHashComponents<F>; 4] = &[
-        &[op2, &g.default_num],
-        result,
-        &continuation,
-        default_num_pair,
-    ];
-    hash_default_results.add_hash_input_clauses(
-        ContTag::Binop.as_field(),
-        &g.binop2_cont_tag,
-        binop_components,
-    );
+    // Binop, Relop, Binop2 and Relop2 all keep their unevaluated arguments at index 2.
+    let unevaled_args = AllocatedPtr::by_index(2, &continuation_components);
 
-    // Continuation::Relop preimage
-    /////////////////////////////////////////////////////////////////////////////
-    let (relop2, relop_cont) = {
-        (
-            &continuation_components[0],
-            AllocatedContPtr::by_index(3, &continuation_components),
-        )
-    };
-    let relop_components: &[&dyn AsAllocatedHashComponents<F>; 4] = &[
-        &[relop2, &g.default_num],
-        result,
-        &relop_cont,
-        default_num_pair,
-    ];
-    hash_default_results.add_hash_input_clauses(
-        ContTag::Relop.as_field(),
-        &g.relop2_cont_tag,
-        relop_components,
-    );
+    let (next_arg, more_args) = car_cdr(
+        &mut cs.namespace(|| "car_cdr unevaled_args"),
+        g,
+        &unevaled_args,
+        store,
+    )?;
 
-    let defaults = [
-        &g.default_num,
-        &g.default_num,
-        &g.default_num,
-        &g.default_num,
-        &g.default_num,
-        &g.default_num,
-        &g.false_num,
-    ];
+    let unevaled_args_is_nil =
+        unevaled_args.alloc_equal(&mut cs.namespace(|| "unevaled_args_is_nil"), &g.nil_ptr)?;
 
-    /////////////////////////// multicase (hash preimage)
-    let all_hash_input_clauses = [
-        &hash_default_results.tag_clauses[..],
-        &hash_default_results.components_clauses[0][..],
-        &hash_default_results.components_clauses[1][..],
-        &hash_default_results.components_clauses[2][..],
-        &hash_default_results.components_clauses[3][..],
-        &hash_default_results.components_clauses[4][..],
-        &hash_default_results.components_clauses[5][..],
-        &hash_default_results.components_clauses[6][..],
-        &hash_default_results.components_clauses[7][..],
-    ];
+    let more_args_is_nil =
+        more_args.alloc_equal(&mut cs.namespace(|| "more_args_is_nil"), &g.nil_ptr)?;
+
+    // The only argument of (+ x), (* x) or a relation must still be a number.
+    let result_is_num = alloc_equal(
+        &mut cs.namespace(|| "result_is_num"),
+        result.tag(),
+        &g.num_tag,
+    )?;
+    let result_is_u64 = alloc_equal(
+        &mut cs.namespace(|| "result_is_u64"),
+        result.tag(),
+        &g.u64_tag,
+    )?;
+    let result_is_number = constraints::or(
+        &mut cs.namespace(|| "result_is_number"),
+        &result_is_num,
+        &result_is_u64,
+    )?;
+
+    // Continuation::Binop2 result
+    /////////////////////////////////////////////////////////////////////////////
+    let (binop2_res, binop2_error) = {
+        let op2 = AllocatedPtr::by_index(0, &continuation_components);
+        let arg1 = AllocatedPtr::by_index(1, &continuation_components);
+
+        let arg2 = result;
+
+        let arg1_is_num = alloc_equal(&mut cs.namespace(|| "arg1_is_num"), arg1.tag(), &g.num_tag)?;
+        let arg2_is_num = alloc_equal(&mut cs.namespace(|| "arg2_is_num"), arg2.tag(), &g.num_tag)?;
+        let both_args_are_nums = Boolean::and(
+            &mut cs.namespace(|| "both_args_are_nums"),
+            &arg1_is_num,
+            &arg2_is_num,
+        )?;
+
+        let arg1_is_u64 = alloc_equal(&mut cs.namespace(|| "arg1_is_u64"), arg1.tag(), &g.u64_tag)?;
+        let arg2_is_u64 = alloc_equal(&mut cs.namespace(|| "arg2_is_u64"), arg2.tag(), &g.u64_tag)?;
+        let both_args_are_u64s = Boolean::and(
+            &mut cs.namespace(|| "both_args_are_u64s"),
+            &arg1_is_u64,
+            &arg2_is_u64,
+        )?;
+
+        // For Nums and U64s, the 'hash' is an immediate value.
+        let (a, b) = (arg1.hash(), arg2.hash());
+
+        let not_dummy = alloc_equal(
+            &mut cs.namespace(|| "Binop2 not dummy"),
+            cont.tag(),
+            &g.binop2_cont_tag,
+        )?;
+
+        let sum = constraints::add(&mut cs.namespace(|| "sum"), a, b)?;
+        let diff = constraints::sub(&mut cs.namespace(|| "difference"), a, b)?;
+        let product = constraints::mul(&mut cs.namespace(|| "product"), a, b)?;
+        let secret = match arg1.hash().get_value() {
+            Some(s) => s,
+            None => F::zero(), //dummy
+        };
+        let hide = hide(&mut cs.namespace(|| "Binop2 hide"), secret, arg2, store)?;
+
+        let op2_is_div = alloc_equal(
+            cs.namespace(|| "op2_is_div"),
+            op2.tag(),
+            &g.op2_quotient_tag,
+        )?;
+
+        let b_is_zero = &alloc_is_zero(&mut cs.namespace(|| "b_is_zero"), b)?;
+
+        let divisor = pick(
+            &mut cs.namespace(|| "maybe-dummy divisor"),
+            b_is_zero,
+            &g.true_num,
+            b,
+        )?;
+
+        let quotient = constraints::div(&mut cs.namespace(|| "quotient"), a, &divisor)?;
+
+        let op2_is_mod = alloc_equal(cs.namespace(|| "op2_is_mod"), op2.tag(), &g.op2_modulo_tag)?;
+
+        let op2_is_int_div = alloc_equal(
+            cs.namespace(|| "op2_is_int_div"),
+            op2.tag(),
+            &g.op2_int_quotient_tag,
+        )?;
+
+        let op2_is_int_div_or_mod = constraints::or(
+            &mut cs.namespace(|| "op2_is_int_div_or_mod"),
+            &op2_is_int_div,
+            &op2_is_mod,
+        )?;
+
+        let op2_is_div_or_mod = constraints::or(
+            &mut cs.namespace(|| "op2_is_div_or_mod"),
+            &op2_is_div,
+            &op2_is_int_div_or_mod,
+        )?;
+
+        // Integer division is defined for U64s, and for Nums which fit in a u64.
+        let (a_fits, _) = alloc_fits_in_bits(&mut cs.namespace(|| "a fits in u64"), a, 64)?;
+        let (b_fits, _) = alloc_fits_in_bits(&mut cs.namespace(|| "b fits in u64"), b, 64)?;
+
+        let args_fit = Boolean::and(&mut cs.namespace(|| "args fit in u64"), &a_fits, &b_fits)?;
+
+        let nums_fit = Boolean::and(
+            &mut cs.namespace(|| "nums fit in u64"),
+            &both_args_are_nums,
+            &args_fit,
+        )?;
+
+        let args_are_integers = constraints::or(
+            &mut cs.namespace(|| "args are integers"),
+            &both_args_are_u64s,
+            &nums_fit,
+        )?;
+
+        // U64 arithmetic wraps. The sum, the difference offset by 2^64, and the product of two
+        // u64s all fit in 128 bits, and the wrapped result is made of their low 64 bits.
+        let two_to_64 = F::from(u64::MAX) + F::one();
+        let offset_diff = AllocatedNum::alloc(&mut cs.namespace(|| "offset difference"), || {
+            let a = a.get_value().ok_or(SynthesisError::AssignmentMissing)?;
+            let b = b.get_value().ok_or(SynthesisError::AssignmentMissing)?;
+            Ok(a + two_to_64 - b)
+        })?;
+
+        // (a + 2^64 - b) * 1 = offset_diff
+        cs.enforce(
+            || "offset difference = a + 2^64 - b",
+            |lc| lc + a.get_variable() + (two_to_64, CS::one()) - b.get_variable(),
+            |lc| lc + CS::one(),
+            |lc| lc + offset_diff.get_variable(),
+        );
+
+        let unwrapped = case(
+            &mut cs.namespace(|| "u64 unwrapped case"),
+            op2.tag(),
+            &[
+                CaseClause {
+                    key: Op2::Sum.as_field(),
+                    value: &sum,
+                },
+                CaseClause {
+                    key: Op2::Diff.as_field(),
+                    value: &offset_diff,
+                },
+                CaseClause {
+                    key: Op2::Product.as_field(),
+                    value: &product,
+                },
+            ],
+            &g.default_num,
+        )?;
+
+        let unwrapped = pick(
+            &mut cs.namespace(|| "maybe-dummy unwrapped"),
+            &both_args_are_u64s,
+            &unwrapped,
+            &g.default_num,
+        )?;
+
+        let wrapped = alloc_low_bits(&mut cs.namespace(|| "wrapped"), &unwrapped, 64, 128)?;
+
+        let divisible = Boolean::and(
+            &mut cs.namespace(|| "divisible"),
+            &args_are_integers,
+            &b_is_zero.not(),
+        )?;
+
+        let int_dividend = pick(
+            &mut cs.namespace(|| "maybe-dummy integer dividend"),
+            &divisible,
+            a,
+            &g.default_num,
+        )?;
+
+        let int_divisor = pick(
+            &mut cs.namespace(|| "maybe-dummy integer divisor"),
+            &divisible,
+            b,
+            &g.true_num,
+        )?;
+
+        let (int_quotient, int_remainder) = alloc_div_rem(
+            &mut cs.namespace(|| "integer division"),
+            &int_dividend,
+            &int_divisor,
+            64,
+        )?;
+
+        let int_div_or_mod = pick(
+            &mut cs.namespace(|| "integer quotient or remainder"),
+            &op2_is_mod,
+            &int_remainder,
+            &int_quotient,
+        )?;
+
+        let u64_val = pick(
+            &mut cs.namespace(|| "u64 val"),
+            &op2_is_div_or_mod,
+            &int_div_or_mod,
+            &wrapped,
+        )?;
+
+        let cons =
+            AllocatedPtr::construct_cons(&mut cs.namespace(|| "cons"), g, store, &arg1, arg2)?;
+
+        let val = case(
+            &mut cs.namespace(|| "Binop2 case"),
+            op2.tag(),
+            &[
+                CaseClause {
+                    key: Op2::Sum.as_field(),
+                    value: &sum,
+                },
+                CaseClause {
+                    key: Op2::Diff.as_field(),
+                    value: &diff,
+                },
+                CaseClause {
+                    key: Op2::Product.as_field(),
+                    value: &product,
+                },
+                CaseClause {
+                    key: Op2::Quotient.as_field(),
+                    value: &quotient,
+                },
+                CaseClause {
+                    key: Op2::Cons.as_field(),
+                    value: cons.hash(),
+                },
+                CaseClause {
+                    key: Op2::Hide.as_field(),
+                    value: hide.hash(),
+                },
+                CaseClause {
+                    key: Op2::StrCons.as_field(),
+                    value: cons.hash(),
+                },
+            ],
+            &g.default_num,
+        )?;
+
+        let is_cons = alloc_equal(
+            &mut cs.namespace(|| "Op2 is Cons"),
+            op2.tag(),
+            &g.op2_cons_tag,
+        )?;
+
+        let is_hide = alloc_equal(
+            &mut cs.namespace(|| "Op2 is Hide"),
+            op2.tag(),
+            &g.op2_hide_tag,
+        )?;
+
+        let is_strcons = alloc_equal(
+            &mut cs.namespace(|| "Op2 is StrCons"),
+            op2.tag(),
+            &g.op2_strcons_tag,
+        )?;
+
+        let is_cons_or_is_strcons = constraints::or(
+            &mut cs.namespace(|| "is cons or is strcons"),
+            &is_cons,
+            &is_strcons,
+        )?;
+
+        let is_cons_or_is_hide = constraints::or(
+            &mut cs.namespace(|| "is cons or is hide"),
+            &is_cons,
+            &is_hide,
+        )?;
+
+        let is_u64_arithmetic = Boolean::and(
+            &mut cs.namespace(|| "is u64 arithmetic"),
+            &both_args_are_u64s,
+            &is_cons_or_is_hide.not(),
+        )?;
+
+        let is_num_int_division = Boolean::and(
+            &mut cs.namespace(|| "is num integer division"),
+            &both_args_are_nums,
+            &op2_is_int_div_or_mod,
+        )?;
+
+        let is_integer_result = constraints::or(
+            &mut cs.namespace(|| "is integer result"),
+            &is_u64_arithmetic,
+            &is_num_int_division,
+        )?;
+
+        let val = pick(
+            &mut cs.namespace(|| "Op2 val"),
+            &is_integer_result,
+            &u64_val,
+            &val,
+        )?;
+
+        let hide_tag_is_comm = alloc_equal(
+            &mut cs.namespace(|| "hide tag is comm"),
+            hide.tag(),
+            &g.comm_tag,
+        )?;
+
+        let hide_tag_is_dummy =
+            alloc_is_zero(&mut cs.namespace(|| "hide tag is dummy"), hide.tag())?;
+
+        let hide_tag_is_correct = constraints::or(
+            &mut cs.namespace(|| "hide tag is correct"),
+            &hide_tag_is_comm,
+            &hide_tag_is_dummy,
+        )?;
+
+        enforce_implication(
+            &mut cs.namespace(|| "is hide implies hide tag is correct"),
+            &is_hide,
+            &hide_tag_is_correct,
+        )?;
+
+        let arg1_is_char = alloc_equal(
+            &mut cs.namespace(|| "arg1_is_char"),
+            arg1.tag(),
+            &g.char_tag,
+        )?;
+        let arg2_is_str = alloc_equal(&mut cs.namespace(|| "arg2_is_str"), arg2.tag(), &g.str_tag)?;
+        let args_are_char_str = Boolean::and(
+            &mut cs.namespace(|| "args_are_char_str"),
+            &arg1_is_char,
+            &arg2_is_str,
+        )?;
+
+        let cons_tag = pick(
+            &mut cs.namespace(|| "cons_tag"),
+            &args_are_char_str,
+            &g.str_tag,
+            &g.cons_tag,
+        )?;
+
+        let num_or_u64_tag = pick(
+            &mut cs.namespace(|| "Op2 tag is num or u64"),
+            &both_args_are_u64s,
+            &g.u64_tag,
+            &g.num_tag,
+        )?;
+
+        let comm_or_num_tag = pick(
+            &mut cs.namespace(|| "Op2 tag is comm or num"),
+            &is_hide,
+            &g.comm_tag,
+            &num_or_u64_tag,
+        )?;
+
+        let res_tag = pick(
+            &mut cs.namespace(|| "Op2 result tag"),
+            &is_cons_or_is_strcons,
+            &cons_tag,
+            &comm_or_num_tag,
+        )?;
+
+        let res = AllocatedPtr::from_parts(res_tag, val);
+
+        let not_int_div_or_args_fit = constraints::or(
+            &mut cs.namespace(|| "not integer division or args fit"),
+            &op2_is_int_div_or_mod.not(),
+            &args_fit,
+        )?;
+
+        let num_arithmetic_is_valid = Boolean::and(
+            &mut cs.namespace(|| "num arithmetic is valid"),
+            &both_args_are_nums,
+            &not_int_div_or_args_fit,
+        )?;
+
+        let arithmetic_is_valid = constraints::or(
+            &mut cs.namespace(|| "arithmetic is valid"),
+            &num_arithmetic_is_valid,
+            &both_args_are_u64s,
+        )?;
+
+        // StrCons is only defined for a Char and a Str.
+        let strcons_is_valid = Boolean::and(
+            &mut cs.namespace(|| "strcons is valid"),
+            &is_strcons,
+            &args_are_char_str,
+        )?;
+
+        let is_cons_or_hide_or_valid_strcons = constraints::or(
+            &mut cs.namespace(|| "is cons or hide or valid strcons"),
+            &is_cons_or_is_hide,
+            &strcons_is_valid,
+        )?;
+
+        let valid_types = constraints::or(
+            &mut cs.namespace(|| "Op2 called with valid types"),
+            &is_cons_or_hide_or_valid_strcons,
+            &arithmetic_is_valid,
+        )?;
+
+        let real_division = Boolean::and(
+            &mut cs.namespace(|| "real_division"),
+            &not_dummy,
+            &op2_is_div_or_mod,
+        )?;
+
+        let real_div_and_b_is_zero = Boolean::and(
+            &mut cs.namespace(|| "real_div_and_b_is_zero"),
+            &real_division,
+            b_is_zero,
+        )?;
+
+        let valid_types_and_not_div_by_zero = Boolean::and(
+            &mut cs.namespace(|| "Op2 called with no errors"),
+            &valid_types,
+            &Boolean::not(&real_div_and_b_is_zero),
+        )?;
+
+        let op2_not_both_num_and_not_cons_or_hide = Boolean::and(
+            &mut cs.namespace(|| "not both num and not cons or hide"),
+            &Boolean::not(&arithmetic_is_valid),
+            &Boolean::not(&is_cons_or_hide_or_valid_strcons),
+        )?;
+
+        let any_arithmetic_error = constraints::or(
+            &mut cs.namespace(|| "some arithmetic error happened"),
+            &Boolean::not(&valid_types_and_not_div_by_zero),
+            &op2_not_both_num_and_not_cons_or_hide,
+        )?;
+
+        let strcons_error = Boolean::and(
+            &mut cs.namespace(|| "strcons error"),
+            &is_strcons,
+            &args_are_char_str.not(),
+        )?;
+
+        let any_error = constraints::or(
+            &mut cs.namespace(|| "some error happened"),
+            &any_arithmetic_error,
+            &strcons_error,
+        )?;
+
+        (res, any_error)
+    };
+
+    // Continuation::Relop2 result
+    /////////////////////////////////////////////////////////////////////////////
+    let (relop2_res, relop2_holds, relop2_no_error) = {
+        let mut cs = cs.namespace(|| "Relop2");
+        let rel2 = AllocatedPtr::by_index(0, &continuation_components);
+        let arg1 = AllocatedPtr::by_index(1, &continuation_components);
+        let arg2 = result;
+
+        let tags_equal = alloc_equal(&mut cs.namespace(|| "tags equal"), arg1.tag(), arg2.tag())?;
+
+        let vals_equal = alloc_equal(&mut cs.namespace(|| "vals equal"), arg1.hash(), arg2.hash())?;
+
+        let arg1_tag_is_num = alloc_equal(
+            &mut cs.namespace(|| "arg1 tag is num"),
+            arg1.tag(),
+            &g.num_tag,
+        )?;
+
+        let arg2_tag_is_num = alloc_equal(
+            &mut cs.namespace(|| "arg2 tag is num"),
+            arg2.tag(),
+            &g.num_tag,
+        )?;
+
+        let args_are_nums = Boolean::and(
+            &mut cs.namespace(|| "args are nums"),
+            &arg1_tag_is_num,
+            &arg2_tag_is_num,
+        )?;
+
+        let arg1_tag_is_u64 = alloc_equal(
+            &mut cs.namespace(|| "arg1 tag is u64"),
+            arg1.tag(),
+            &g.u64_tag,
+        )?;
+
+        let arg2_tag_is_u64 = alloc_equal(
+            &mut cs.namespace(|| "arg2 tag is u64"),
+            arg2.tag(),
+            &g.u64_tag,
+        )?;
+
+        let args_are_u64s = Boolean::and(
+            &mut cs.namespace(|| "args are u64s"),
+            &arg1_tag_is_u64,
+            &arg2_tag_is_u64,
+        )?;
+
+        // U64s are compared like Nums, and always fit.
+        let args_are_num = constraints::or(
+            &mut cs.namespace(|| "args are num"),
+            &args_are_nums,
+            &args_are_u64s,
+        )?;
+
+        let rel2_is_equal = alloc_equal(
+            &mut cs.namespace(|| "rel2 tag is Equal"),
+            rel2.tag(),
+            &g.rel2_equal_tag,
+        )?;
+
+        let rel2_is_less = alloc_equal(
+            &mut cs.namespace(|| "rel2 tag is Less"),
+            rel2.tag(),
+            &g.rel2_less_tag,
+        )?;
+
+        let rel2_is_greater = alloc_equal(
+            &mut cs.namespace(|| "rel2 tag is Greater"),
+            rel2.tag(),
+            &g.rel2_greater_tag,
+        )?;
+
+        let rel2_is_lessequal = alloc_equal(
+            &mut cs.namespace(|| "rel2 tag is LessEqual"),
+            rel2.tag(),
+            &g.rel2_lessequal_tag,
+        )?;
+
+        let rel2_is_greaterequal = alloc_equal(
+            &mut cs.namespace(|| "rel2 tag is GreaterEqual"),
+            rel2.tag(),
+            &g.rel2_greaterequal_tag,
+        )?;
+
+        let rel2_is_less_or_greater = constraints::or(
+            &mut cs.namespace(|| "rel2 is Less or Greater"),
+            &rel2_is_less,
+            &rel2_is_greater,
+        )?;
+
+        let rel2_is_lessequal_or_greaterequal = constraints::or(
+            &mut cs.namespace(|| "rel2 is LessEqual or GreaterEqual"),
+            &rel2_is_lessequal,
+            &rel2_is_greaterequal,
+        )?;
+
+        let rel2_is_ordering = constraints::or(
+            &mut cs.namespace(|| "rel2 is ordering"),
+            &rel2_is_less_or_greater,
+            &rel2_is_lessequal_or_greaterequal,
+        )?;
+
+        // Ordering relations compare Nums as u64s (see `Num::cmp_u64`), and it is an error to
+        // compare a Num which does not fit.
+        let (arg1_fits, arg1_u64) =
+            alloc_fits_in_bits(&mut cs.namespace(|| "arg1 fits in u64"), arg1.hash(), 64)?;
+
+        let (arg2_fits, arg2_u64) =
+            alloc_fits_in_bits(&mut cs.namespace(|| "arg2 fits in u64"), arg2.hash(), 64)?;
+
+        let args_fit = Boolean::and(
+            &mut cs.namespace(|| "args fit in u64"),
+            &arg1_fits,
+            &arg2_fits,
+        )?;
+
+        let is_less = alloc_is_less_than(
+            &mut cs.namespace(|| "arg1 < arg2"),
+            &arg1_u64,
+            &arg2_u64,
+            64,
+        )?;
+
+        let is_lessequal =
+            constraints::or(&mut cs.namespace(|| "arg1 <= arg2"), &is_less, &vals_equal)?;
+
+        let is_greater = Boolean::and(
+            &mut cs.namespace(|| "arg1 > arg2"),
+            &is_less.not(),
+            &vals_equal.not(),
+        )?;
+
+        let is_greaterequal = is_less.not();
+
+        let less_holds = Boolean::and(&mut cs.namespace(|| "Less holds"), &rel2_is_less, &is_less)?;
+
+        let greater_holds = Boolean::and(
+            &mut cs.namespace(|| "Greater holds"),
+            &rel2_is_greater,
+            &is_greater,
+        )?;
+
+        let lessequal_holds = Boolean::and(
+            &mut cs.namespace(|| "LessEqual holds"),
+            &rel2_is_lessequal,
+            &is_lessequal,
+        )?;
+
+        let greaterequal_holds = Boolean::and(
+            &mut cs.namespace(|| "GreaterEqual holds"),
+            &rel2_is_greaterequal,
+            &is_greaterequal,
+        )?;
+
+        let less_or_greater_holds = constraints::or(
+            &mut cs.namespace(|| "Less or Greater holds"),
+            &less_holds,
+            &greater_holds,
+        )?;
+
+        let lessequal_or_greaterequal_holds = constraints::or(
+            &mut cs.namespace(|| "LessEqual or GreaterEqual holds"),
+            &lessequal_holds,
+            &greaterequal_holds,
+        )?;
+
+        let ordering_holds = constraints::or(
+            &mut cs.namespace(|| "ordering holds"),
+            &less_or_greater_holds,
+            &lessequal_or_greaterequal_holds,
+        )?;
+
+        let args_equal =
+            Boolean::and(&mut cs.namespace(|| "args equal"), &tags_equal, &vals_equal)?;
+
+        let args_are_num_or_rel2_is_equal = constraints::or(
+            &mut cs.namespace(|| "args_are_num_or_rel2_is_equal"),
+            &args_are_num,
+            &rel2_is_equal,
+        )?;
+
+        let not_num_tag_without_nums = constraints::or(
+            &mut cs.namespace(|| "sub_res"),
+            &args_are_num,
+            &rel2_is_equal,
+        )?;
+
+        let equality_holds = Boolean::and(
+            &mut cs.namespace(|| "equality holds"),
+            &args_equal,
+            &not_num_tag_without_nums,
+        )?;
+
+        let equality_res = Boolean::and(
+            &mut cs.namespace(|| "equality_res"),
+            &equality_holds,
+            &rel2_is_ordering.not(),
+        )?;
+
+        let boolean_res = constraints::or(
+            &mut cs.namespace(|| "boolean_res"),
+            &equality_res,
+            &ordering_holds,
+        )?;
+
+        let not_ordering_or_args_fit = constraints::or(
+            &mut cs.namespace(|| "not ordering or args fit"),
+            &rel2_is_ordering.not(),
+            &args_fit,
+        )?;
+
+        let no_error = Boolean::and(
+            &mut cs.namespace(|| "no error"),
+            &args_are_num_or_rel2_is_equal,
+            &not_ordering_or_args_fit,
+        )?;
+
+        let res = AllocatedPtr::pick(
+            &mut cs.namespace(|| "res"),
+            &boolean_res,
+            &g.t_ptr,
+            &g.nil_ptr,
+        )?;
+
+        (res, boolean_res, no_error)
+    };
+
+    // Continuation::Binop preimage
+    /////////////////////////////////////////////////////////////////////////////
+    let (op2, continuation) = {
+        (
+            &continuation_components[0],
+            AllocatedContPtr::by_index(3, &continuation_components),
+        )
+    };
+    let binop_components: &[&dyn AsAllocatedHashComponents<F>; 4] =
+        &[&[op2, &g.default_num], result, &more_args, &continuation];
+    hash_default_results.add_hash_input_clauses(
+        ContTag::Binop.as_field(),
+        &g.binop2_cont_tag,
+        binop_components,
+    );
+
+    // Continuation::Binop2 preimage
+    /////////////////////////////////////////////////////////////////////////////
+    let (op2, binop2_cont) = {
+        (
+            &continuation_components[0],
+            AllocatedContPtr::by_index(3, &continuation_components),
+        )
+    };
+    let binop2_components: &[&dyn AsAllocatedHashComponents<F>; 4] = &[
+        &[op2, &g.default_num],
+        &binop2_res,
+        &more_args,
+        &binop2_cont,
+    ];
+    hash_default_results.add_hash_input_clauses(
+        ContTag::Binop2.as_field(),
+        &g.binop2_cont_tag,
+        binop2_components,
+    );
+
+    // Continuation::Relop preimage
+    /////////////////////////////////////////////////////////////////////////////
+    let (relop2, relop_cont) = {
+        (
+            &continuation_components[0],
+            AllocatedContPtr::by_index(3, &continuation_components),
+        )
+    };
+    let relop_components: &[&dyn AsAllocatedHashComponents<F>; 4] =
+        &[&[relop2, &g.default_num], result, &more_args, &relop_cont];
+    hash_default_results.add_hash_input_clauses(
+        ContTag::Relop.as_field(),
+        &g.relop2_cont_tag,
+        relop_components,
+    );
+
+    // Continuation::Relop2 preimage
+    /////////////////////////////////////////////////////////////////////////////
+    let (relop2, relop2_cont) = {
+        (
+            &continuation_components[0],
+            AllocatedContPtr::by_index(3, &continuation_components),
+        )
+    };
+    let relop2_components: &[&dyn AsAllocatedHashComponents<F>; 4] =
+        &[&[relop2, &g.default_num], result, &more_args, &relop2_cont];
+    hash_default_results.add_hash_input_clauses(
+        ContTag::Relop2.as_field(),
+        &g.relop2_cont_tag,
+        relop2_components,
+    );
+
+    // If, And and Or share the same layout, so their unevaluated arguments and condition are
+    // examined once.
+    /////////////////////////////////////////////////////////////////////////////
+    let if_continuation = AllocatedContPtr::by_index(1, &continuation_components);
+    let (if_arg1, if_more, condition_is_nil) = {
+        let mut cs = cs.namespace(|| "If");
+        let unevaled_args = AllocatedPtr::by_index(0, &continuation_components);
+
+        let condition = result;
+
+        // NOTE: There was a tricky bug here.
+        // When the actual continuation was Relop, and the operation is Numequal (for example),
+        // Then this appears to be an invalid but not dummy continuation, since Numequal has a Relop tag value of 1,
+        // the same as Cons.
+        //
+        // We address this by adding 2 to the tags returned by Op2 and Rel2 fr() methods, so this collision cannot happen.
+        // TODO: It might make even more sense to make all disjoint.
+        let (arg1, more) = car_cdr(
+            &mut cs.namespace(|| "unevaled_args cons"),
+            g,
+            &unevaled_args,
+            store,
+        )?;
+
+        let condition_is_nil =
+            condition.alloc_equal(&mut cs.namespace(|| "condition is nil"), &g.nil_ptr)?;
+
+        (arg1, more, condition_is_nil)
+    };
+
+    // Continuation::And and Continuation::Or preimage
+    /////////////////////////////////////////////////////////////////////////////
+    let and_or_components: &[&dyn AsAllocatedHashComponents<F>; 4] = &[
+        &if_more,
+        &if_continuation,
+        default_num_pair,
+        default_num_pair,
+    ];
+    hash_default_results.add_hash_input_clauses(
+        ContTag::And.as_field(),
+        &g.and_cont_tag,
+        and_or_components,
+    );
+    hash_default_results.add_hash_input_clauses(
+        ContTag::Or.as_field(),
+        &g.or_cont_tag,
+        and_or_components,
+    );
+
+    // Continuation::Eval preimage
+    /////////////////////////////////////////////////////////////////////////////
+    let (saved_env, eval_cont) = {
+        (
+            AllocatedPtr::by_index(0, &continuation_components),
+            AllocatedContPtr::by_index(1, &continuation_components),
+        )
+    };
+    let eval_components: &[&dyn AsAllocatedHashComponents<F>; 4] =
+        &[&saved_env, &eval_cont, default_num_pair, default_num_pair];
+    hash_default_results.add_hash_input_clauses(
+        ContTag::Eval.as_field(),
+        &g.tail_cont_tag,
+        eval_components,
+    );
+
+    // Continuation::Apply preimage
+    /////////////////////////////////////////////////////////////////////////////
+    // The function is called with the first argument by Call2, or with none by Call0.
+    let (
+        apply_function,
+        apply_args_is_nil,
+        apply_arg,
+        apply_error,
+        apply_newer_cont_tag,
+        apply_newer_cont_components,
+    ) = {
+        let mut cs = cs.namespace(|| "Apply");
+        let saved_env = AllocatedPtr::by_index(0, &continuation_components);
+        let unevaled_args = AllocatedPtr::by_index(1, &continuation_components);
+        let continuation = AllocatedContPtr::by_index(2, &continuation_components);
+        let reversed_args = AllocatedPtr::by_index(3, &continuation_components);
+
+        // At first, the result is (FN . ARGS). Afterwards, it is what the function returned for
+        // the arguments so far, and the rest are in the continuation.
+        let unevaled_args_is_nil =
+            unevaled_args.alloc_equal(&mut cs.namespace(|| "unevaled_args_is_nil"), &g.nil_ptr)?;
+        let (car_result, cdr_result) =
+            car_cdr(&mut cs.namespace(|| "car_cdr result"), g, result, store)?;
+        let function = AllocatedPtr::pick(
+            &mut cs.namespace(|| "function"),
+            &unevaled_args_is_nil,
+            &car_result,
+            result,
+        )?;
+        let args = AllocatedPtr::pick(
+            &mut cs.namespace(|| "args"),
+            &unevaled_args_is_nil,
+            &cdr_result,
+            &unevaled_args,
+        )?;
+
+        let (arg, more) = car_cdr(&mut cs.namespace(|| "car_cdr args"), g, &args, store)?;
+        let function_is_fun = alloc_equal(
+            &mut cs.namespace(|| "function_is_fun"),
+            function.tag(),
+            &g.fun_tag,
+        )?;
+        let args_is_nil = args.alloc_equal(&mut cs.namespace(|| "args_is_nil"), &g.nil_ptr)?;
+        let args_is_cons = alloc_equal(
+            &mut cs.namespace(|| "args_is_cons"),
+            args.tag(),
+            &g.cons_tag,
+        )?;
+        let more_is_nil = more.alloc_equal(&mut cs.namespace(|| "more_is_nil"), &g.nil_ptr)?;
+        let more_is_cons = alloc_equal(
+            &mut cs.namespace(|| "more_is_cons"),
+            more.tag(),
+            &g.cons_tag,
+        )?;
+
+        // When args is not a cons, more is nil.
+        let args_is_list = or!(cs, &args_is_nil, &args_is_cons)?;
+        let more_is_list = or!(cs, &more_is_nil, &more_is_cons)?;
+        let args_are_proper = and!(cs, &args_is_list, &more_is_list)?;
+        let apply_ok = and!(cs, &function_is_fun, &args_are_proper)?;
+
+        let apply_rest_components: &[&dyn AsAllocatedHashComponents<F>; 4] =
+            &[&saved_env, &more, &continuation, &g.nil_ptr];
+        let apply_rest_cont = AllocatedContPtr::construct(
+            &mut cs.namespace(|| "apply rest continuation"),
+            store,
+            &g.apply_cont_tag,
+            apply_rest_components,
+        )?;
+        let next_cont = AllocatedContPtr::pick(
+            &mut cs.namespace(|| "next cont"),
+            &more_is_nil,
+            &continuation,
+            &apply_rest_cont,
+        )?;
+
+        // Call0 is laid out as [continuation, reversed_args, collected_args, _] and Call2 as
+        // [saved_env, function, continuation, reversed_args].
+        let newer_cont_tag = pick(
+            &mut cs.namespace(|| "newer_cont tag"),
+            &args_is_nil,
+            &g.call0_cont_tag,
+            &g.call2_cont_tag,
+        )?;
+        let component0_tag = pick(
+            &mut cs.namespace(|| "component0_tag"),
+            &args_is_nil,
+            continuation.tag(),
+            saved_env.tag(),
+        )?;
+        let component0_hash = pick(
+            &mut cs.namespace(|| "component0_hash"),
+            &args_is_nil,
+            continuation.hash(),
+            saved_env.hash(),
+        )?;
+        let component1_tag = pick(
+            &mut cs.namespace(|| "component1_tag"),
+            &args_is_nil,
+            g.nil_ptr.tag(),
+            function.tag(),
+        )?;
+        let component1_hash = pick(
+            &mut cs.namespace(|| "component1_hash"),
+            &args_is_nil,
+            g.nil_ptr.hash(),
+            function.hash(),
+        )?;
+        let component2_tag = pick(
+            &mut cs.namespace(|| "component2_tag"),
+            &args_is_nil,
+            g.nil_ptr.tag(),
+            next_cont.tag(),
+        )?;
+        let component2_hash = pick(
+            &mut cs.namespace(|| "component2_hash"),
+            &args_is_nil,
+            g.nil_ptr.hash(),
+            next_cont.hash(),
+        )?;
+        let component3_tag = pick(
+            &mut cs.namespace(|| "component3_tag"),
+            &args_is_nil,
+            &g.default_num,
+            reversed_args.tag(),
+        )?;
+        let component3_hash = pick(
+            &mut cs.namespace(|| "component3_hash"),
+            &args_is_nil,
+            &g.default_num,
+            reversed_args.hash(),
+        )?;
+
+        (
+            function,
+            args_is_nil,
+            arg,
+            apply_ok.not(),
+            newer_cont_tag,
+            [
+                [component0_tag, component0_hash],
+                [component1_tag, component1_hash],
+                [component2_tag, component2_hash],
+                [component3_tag, component3_hash],
+            ],
+        )
+    };
+    let [apply_component0, apply_component1, apply_component2, apply_component3] =
+        &apply_newer_cont_components;
+    let apply_components: &[&dyn AsAllocatedHashComponents<F>; 4] = &[
+        &[&apply_component0[0], &apply_component0[1]],
+        &[&apply_component1[0], &apply_component1[1]],
+        &[&apply_component2[0], &apply_component2[1]],
+        &[&apply_component3[0], &apply_component3[1]],
+    ];
+    hash_default_results.add_hash_input_clauses(
+        ContTag::Apply.as_field(),
+        &apply_newer_cont_tag,
+        apply_components,
+    );
+
+    // Continuation::Throw preimage
+    /////////////////////////////////////////////////////////////////////////////
+    // The thrown value is either caught by the continuation it is thrown to, or thrown on to that
+    // continuation's own continuation, which is the preimage.
+    let (throw_caught, throw_error, catch_env, catch_cont, throw_tag, throw_parent) = {
+        let mut cs = cs.namespace(|| "Throw");
+        let tag = AllocatedPtr::by_index(0, &continuation_components);
+        let thrown_to = AllocatedContPtr::by_index(1, &continuation_components);
+
+        let thrown_to_witness = witness
+            .as_ref()
+            .and_then(|w| w.apply_continuation_cont.as_ref())
+            .and_then(|c| match store.fetch_cont(c) {
+                Some(Continuation::Throw { continuation, .. }) => Some(continuation),
+                _ => None,
+            });
+        let (thrown_to_hash, thrown_to_components) = ContPtr::allocate_maybe_dummy_components(
+            &mut cs.namespace(|| "thrown_to components"),
+            thrown_to_witness.as_ref(),
+            store,
+        )?;
+        let cont_is_throw = alloc_equal(
+            &mut cs.namespace(|| "cont_is_throw"),
+            cont.tag(),
+            &g.throw_cont_tag,
+        )?;
+        let cont_is_throw_and_not_dummy = and!(cs, &cont_is_throw, not_dummy)?;
+        implies_equal!(
+            cs,
+            &cont_is_throw_and_not_dummy,
+            &thrown_to_hash,
+            thrown_to.hash()
+        );
+
+        // A Catch is laid out as [saved_env, tag, continuation, _].
+        let catch_env = AllocatedPtr::by_index(0, &thrown_to_components);
+        let catch_tag = AllocatedPtr::by_index(1, &thrown_to_components);
+        let catch_cont = AllocatedContPtr::by_index(2, &thrown_to_components);
+        let thrown_to_is_catch = alloc_equal(
+            &mut cs.namespace(|| "thrown_to_is_catch"),
+            thrown_to.tag(),
+            &g.catch_cont_tag,
+        )?;
+        let tags_match = catch_tag.alloc_equal(&mut cs.namespace(|| "tags_match"), &tag)?;
+        let caught = and!(cs, &thrown_to_is_catch, &tags_match)?;
+
+        let mut parent_tag_clauses = Vec::new();
+        let mut parent_hash_clauses = Vec::new();
+        let mut has_parent_clauses = Vec::new();
+        for cont_tag in [
+            ContTag::Outermost,
+            ContTag::Call0,
+            ContTag::Call,
+            ContTag::Call2,
+            ContTag::Tail,
+            ContTag::Error,
+            ContTag::Lookup,
+            ContTag::Unop,
+            ContTag::Binop,
+            ContTag::Binop2,
+            ContTag::Relop,
+            ContTag::Relop2,
+            ContTag::If,
+            ContTag::Let,
+            ContTag::LetRec,
+            ContTag::Dummy,
+            ContTag::Terminal,
+            ContTag::Emit,
+            ContTag::And,
+            ContTag::Or,
+            ContTag::Eval,
+            ContTag::Fail,
+            ContTag::Apply,
+            ContTag::Catch,
+            ContTag::Throw,
+        ] {
+            if let Some(i) = cont_tag.continuation_index() {
+                parent_tag_clauses.push(CaseClause {
+                    key: cont_tag.as_field(),
+                    value: &thrown_to_components[2 * i],
+                });
+                parent_hash_clauses.push(CaseClause {
+                    key: cont_tag.as_field(),
+                    value: &thrown_to_components[2 * i + 1],
+                });
+                has_parent_clauses.push(CaseClause {
+                    key: cont_tag.as_field(),
+                    value: &g.true_num,
+                });
+            }
+        }
+        let parent_results = multi_case(
+            &mut cs.namespace(|| "thrown_to continuation"),
+            thrown_to.tag(),
+            &[
+                &parent_tag_clauses[..],
+                &parent_hash_clauses[..],
+                &has_parent_clauses[..],
+            ],
+            &[&g.default_num, &g.default_num, &g.false_num],
+        )?;
+        let parent = AllocatedContPtr::by_index(0, &parent_results);
+        let has_parent = alloc_equal(
+            &mut cs.namespace(|| "has_parent"),
+            &parent_results[2],
+            &g.true_num,
+        )?;
+
+        // Nothing catches a value thrown to a continuation without one.
+        let uncaught = and!(cs, &caught.not(), &has_parent.not())?;
+
+        (caught, uncaught, catch_env, catch_cont, tag, parent)
+    };
+    let throw_components: &[&dyn AsAllocatedHashComponents<F>; 4] = &[
+        &throw_tag,
+        &throw_parent,
+        default_num_pair,
+        default_num_pair,
+    ];
+    hash_default_results.add_hash_input_clauses(
+        ContTag::Throw.as_field(),
+        &g.throw_cont_tag,
+        throw_components,
+    );
+    let defaults = [
+        &g.default_num,
+        &g.default_num,
+        &g.default_num,
+        &g.default_num,
+        &g.default_num,
+        &g.default_num,
+        &g.default_num,
+        &g.default_num,
+        &g.default_num,
+    ];
+
+    /////////////////////////// multicase (hash preimage)
+    let all_hash_input_clauses = [
+        &hash_default_results.tag_clauses[..],
+        &hash_default_results.components_clauses[0][..],
+        &hash_default_results.components_clauses[1][..],
+        &hash_default_results.components_clauses[2][..],
+        &hash_default_results.components_clauses[3][..],
+        &hash_default_results.components_clauses[4][..],
+        &hash_default_results.components_clauses[5][..],
+        &hash_default_results.components_clauses[6][..],
+        &hash_default_results.components_clauses[7][..],
+    ];
 
     let components_results = multi_case(
         &mut cs.namespace(|| "hash preimage selection"),
@@ -2510,7 +4847,7 @@ fn apply_continuation<F: LurkField, CS: ConstraintSystem<F>>(
             &[&components_results[1], &components_results[2]] as &dyn AsAllocatedHashComponents<F>,
             &[&components_results[3], &components_results[4]] as &dyn AsAllocatedHashComponents<F>,
             &[&components_results[5], &components_results[6]] as &dyn AsAllocatedHashComponents<F>,
-            &[&g.default_num, &g.default_num],
+            &[&components_results[7], &components_results[8]] as &dyn AsAllocatedHashComponents<F>,
         ],
     )?;
 
@@ -2554,6 +4891,63 @@ fn apply_continuation<F: LurkField, CS: ConstraintSystem<F>>(
             env,
         )?;
 
+        // A variadic function moves one collected argument per iteration, then binds its rest
+        // parameter to all of them.
+        let arg_is_cons = alloc_equal(
+            &mut cs.namespace(|| "arg_is_cons"),
+            arg_t.tag(),
+            &g.cons_tag,
+        )?;
+        let fun_is_variadic = and!(cs, &result_is_fun, &arg_is_cons)?;
+
+        let (rest_var, _) = car_cdr(&mut cs.namespace(|| "rest var"), g, &arg_t, store)?;
+        let rest_env = extend(
+            &mut cs.namespace(|| "extend env with rest"),
+            g,
+            store,
+            &closed_env,
+            &rest_var,
+            &call0_collected_args,
+        )?;
+
+        let rest_expr = AllocatedPtr::pick(
+            &mut cs.namespace(|| "rest expr"),
+            &call0_reversed_args_is_nil,
+            &body_form,
+            result,
+        )?;
+        let rest_env = AllocatedPtr::pick(
+            &mut cs.namespace(|| "rest env"),
+            &call0_reversed_args_is_nil,
+            &rest_env,
+            env,
+        )?;
+        let rest_cont = AllocatedContPtr::pick(
+            &mut cs.namespace(|| "rest cont"),
+            &call0_reversed_args_is_nil,
+            &continuation,
+            &newer_cont,
+        )?;
+
+        let next_exp = AllocatedPtr::pick(
+            &mut cs.namespace(|| "pick variadic exp"),
+            &fun_is_variadic,
+            &rest_expr,
+            &next_exp,
+        )?;
+        let the_env = AllocatedPtr::pick(
+            &mut cs.namespace(|| "pick variadic env"),
+            &fun_is_variadic,
+            &rest_env,
+            &the_env,
+        )?;
+        let the_cont = AllocatedContPtr::pick(
+            &mut cs.namespace(|| "pick variadic cont"),
+            &fun_is_variadic,
+            &rest_cont,
+            &the_cont,
+        )?;
+
         (next_exp, the_env, the_cont)
     };
     results.add_clauses_cont(
@@ -2593,98 +4987,172 @@ fn apply_continuation<F: LurkField, CS: ConstraintSystem<F>>(
     /////////////////////////////////////////////////////////////////////////////
     let (the_expr, the_env, the_cont) = {
         let mut cs = cs.namespace(|| "Call2");
+        let saved_env = AllocatedPtr::by_index(0, &continuation_components);
         let fun = AllocatedPtr::by_index(1, &continuation_components);
         let continuation = AllocatedContPtr::by_index(2, &continuation_components);
+        let (arg_t, body_t, closed_env) = &call2_fun_parts;
 
-        {
-            let (hash, arg_t, body_t, closed_env) = Ptr::allocate_maybe_fun(
-                &mut cs.namespace(|| "allocate fun"),
-                store,
-                fun.ptr(store).as_ref(),
-            )?;
-
-            let (body_form, _) = car_cdr(&mut cs.namespace(|| "body_form"), g, &body_t, store)?;
+        let (body_form, _) = car_cdr(&mut cs.namespace(|| "body_form"), g, body_t, store)?;
 
-            let fun_is_correct = constraints::alloc_equal(
-                &mut cs.namespace(|| "fun hash is correct"),
-                fun.hash(),
-                &hash,
-            )?;
+        let newer_env = extend(
+            &mut cs.namespace(|| "extend env"),
+            g,
+            store,
+            closed_env,
+            arg_t,
+            result,
+        )?;
 
-            let cont_is_call2_precomp = constraints::alloc_equal(
-                &mut cs.namespace(|| "branch taken"),
-                cont.tag(),
-                &g.call2_cont_tag,
-            )?;
+        let continuation_is_tail = alloc_equal(
+            &mut cs.namespace(|| "continuation is tail"),
+            continuation.tag(),
+            &g.tail_cont_tag,
+        )?;
 
-            let cont_is_call2_and_not_dummy = and!(cs, &cont_is_call2_precomp, not_dummy)?;
+        let tail_cont = AllocatedContPtr::pick(
+            &mut cs.namespace(|| "the tail continuation"),
+            &continuation_is_tail,
+            &continuation,
+            &newer_cont,
+        );
 
-            enforce_implication(
-                &mut cs.namespace(|| "implies non-dummy fun"),
-                &cont_is_call2_and_not_dummy,
-                &fun_is_correct,
-            )?;
+        let result_is_fun =
+            alloc_equal(cs.namespace(|| "result_is_fun"), result.tag(), &g.fun_tag)?;
+        let args_is_dummy =
+            arg_t.alloc_equal(&mut cs.namespace(|| "args_is_dummy"), &g.dummy_arg_ptr)?;
+        let cond = or!(cs, &args_is_dummy.not(), &result_is_fun)?;
 
-            let newer_env = extend(
-                &mut cs.namespace(|| "extend env"),
-                g,
-                store,
-                &closed_env,
-                &arg_t,
-                result,
-            )?;
+        let the_cont = AllocatedContPtr::pick(
+            &mut cs.namespace(|| "the_cont"),
+            &cond,
+            &tail_cont.unwrap(),
+            &g.error_ptr_cont,
+        )?;
 
-            let continuation_is_tail = alloc_equal(
-                &mut cs.namespace(|| "continuation is tail"),
-                continuation.tag(),
-                &g.tail_cont_tag,
-            )?;
+        let the_env = AllocatedPtr::pick(&mut cs.namespace(|| "the_env"), &cond, &newer_env, env)?;
 
-            let tail_cont = AllocatedContPtr::pick(
-                &mut cs.namespace(|| "the tail continuation"),
-                &continuation_is_tail,
-                &continuation,
-                &newer_cont,
-            );
+        let the_expr =
+            AllocatedPtr::pick(&mut cs.namespace(|| "the_expr"), &cond, &body_form, result)?;
 
-            let result_is_fun =
-                alloc_equal(cs.namespace(|| "result_is_fun"), result.tag(), &g.fun_tag)?;
-            let args_is_dummy =
-                arg_t.alloc_equal(&mut cs.namespace(|| "args_is_dummy"), &g.dummy_arg_ptr)?;
-            let cond = or!(cs, &args_is_dummy.not(), &result_is_fun)?;
+        // A variadic function collecting its argument is returned to newer_cont.
+        let the_cont = AllocatedContPtr::pick(
+            &mut cs.namespace(|| "pick variadic cont"),
+            &call2_fun_is_variadic,
+            &newer_cont,
+            &the_cont,
+        )?;
+        let the_env = AllocatedPtr::pick(
+            &mut cs.namespace(|| "pick variadic env"),
+            &call2_fun_is_variadic,
+            &saved_env,
+            &the_env,
+        )?;
+        let the_expr = AllocatedPtr::pick(
+            &mut cs.namespace(|| "pick variadic expr"),
+            &call2_fun_is_variadic,
+            &fun,
+            &the_expr,
+        )?;
 
-            let the_cont = AllocatedContPtr::pick(
-                &mut cs.namespace(|| "the_cont"),
-                &cond,
-                &tail_cont.unwrap(),
-                &g.error_ptr_cont,
-            )?;
+        (the_expr, the_env, the_cont)
+    };
+    results.add_clauses_cont(ContTag::Call2, &the_expr, &the_env, &the_cont, &g.false_num);
 
-            let the_env =
-                AllocatedPtr::pick(&mut cs.namespace(|| "the_env"), &cond, &newer_env, env)?;
+    // Continuation::Apply, newer_cont is allocated
+    /////////////////////////////////////////////////////////////////////////////
+    let (the_expr, the_cont) = {
+        let mut cs = cs.namespace(|| "Apply result");
+        let quote = store
+            .get_sym("quote", true)
+            .and_then(|s| store.get_hash_sym(s))
+            .unwrap();
+        let quote_t = AllocatedPtr::alloc_constant(&mut cs.namespace(|| "quote"), quote)?;
+        // The argument is already evaluated.
+        let quoted_arg = AllocatedPtr::construct_list(
+            &mut cs.namespace(|| "quoted arg"),
+            g,
+            store,
+            &[&quote_t, &apply_arg],
+        )?;
+        let next_expr = AllocatedPtr::pick(
+            &mut cs.namespace(|| "function or quoted arg"),
+            &apply_args_is_nil,
+            &apply_function,
+            &quoted_arg,
+        )?;
+        let the_expr = AllocatedPtr::pick(
+            &mut cs.namespace(|| "the_expr"),
+            &apply_error,
+            result,
+            &next_expr,
+        )?;
+        let the_cont = AllocatedContPtr::pick(
+            &mut cs.namespace(|| "the_cont"),
+            &apply_error,
+            &g.error_ptr_cont,
+            &newer_cont,
+        )?;
+        (the_expr, the_cont)
+    };
+    results.add_clauses_cont(ContTag::Apply, &the_expr, env, &the_cont, &g.false_num);
 
-            let the_expr =
-                AllocatedPtr::pick(&mut cs.namespace(|| "the_expr"), &cond, &body_form, result)?;
+    // Continuation::Catch
+    /////////////////////////////////////////////////////////////////////////////
+    let saved_env = AllocatedPtr::by_index(0, &continuation_components);
+    let continuation = AllocatedContPtr::by_index(2, &continuation_components);
+    results.add_clauses_cont(
+        ContTag::Catch,
+        result,
+        &saved_env,
+        &continuation,
+        &g.true_num,
+    );
 
-            (the_expr, the_env, the_cont)
-        }
+    // Continuation::Throw, newer_cont is allocated
+    /////////////////////////////////////////////////////////////////////////////
+    let (the_env, the_cont, throw_make_thunk) = {
+        let mut cs = cs.namespace(|| "Throw result");
+        let thrown_on_cont = AllocatedContPtr::pick(
+            &mut cs.namespace(|| "thrown on cont"),
+            &throw_error,
+            &g.error_ptr_cont,
+            &newer_cont,
+        )?;
+        let the_env = AllocatedPtr::pick(
+            &mut cs.namespace(|| "the_env"),
+            &throw_caught,
+            &catch_env,
+            env,
+        )?;
+        let the_cont = AllocatedContPtr::pick(
+            &mut cs.namespace(|| "the_cont"),
+            &throw_caught,
+            &catch_cont,
+            &thrown_on_cont,
+        )?;
+        let make_thunk = pick(
+            &mut cs.namespace(|| "make_thunk"),
+            &throw_error,
+            &g.false_num,
+            &g.true_num,
+        )?;
+        (the_env, the_cont, make_thunk)
     };
-    results.add_clauses_cont(ContTag::Call2, &the_expr, &the_env, &the_cont, &g.false_num);
+    results.add_clauses_cont(
+        ContTag::Throw,
+        result,
+        &the_env,
+        &the_cont,
+        &throw_make_thunk,
+    );
 
     // Continuation::Binop, newer_cont is allocated
     /////////////////////////////////////////////////////////////////////////////
-    let (the_expr, the_env, the_cont) = {
+    let (the_expr, the_env, the_cont, binop_make_thunk) = {
         let mut cs = cs.namespace(|| "Binop");
         let operator = AllocatedPtr::by_index(0, &continuation_components);
         let saved_env = AllocatedPtr::by_index(1, &continuation_components);
-        let unevaled_args = AllocatedPtr::by_index(2, &continuation_components);
-
-        let (allocated_arg2, allocated_rest) = car_cdr(
-            &mut cs.namespace(|| "cons using newer continuation"),
-            g,
-            &unevaled_args,
-            store,
-        )?;
+        let continuation = AllocatedContPtr::by_index(3, &continuation_components);
 
         let op_is_begin = alloc_equal(
             cs.namespace(|| "op_is_begin"),
@@ -2692,9 +5160,6 @@ fn apply_continuation<F: LurkField, CS: ConstraintSystem<F>>(
             &g.op2_begin_tag,
         )?;
 
-        let rest_is_nil =
-            allocated_rest.alloc_equal(&mut cs.namespace(|| "rest_is_nil"), &g.nil_ptr)?;
-
         let begin = store.get_begin();
 
         let allocated_begin =
@@ -2715,407 +5180,381 @@ fn apply_continuation<F: LurkField, CS: ConstraintSystem<F>>(
             result,
         )?;
 
-        let otherwise = Boolean::not(&op_is_begin);
-
-        let otherwise_and_rest_is_nil = Boolean::and(
-            &mut cs.namespace(|| "otherwise_and_rest_is_nil"),
-            &otherwise,
-            &rest_is_nil,
-        )?;
-
-        let the_expr = AllocatedPtr::pick(
-            &mut cs.namespace(|| "the_expr"),
-            &rest_is_nil,
-            &allocated_arg2,
-            &the_expr_if_begin,
-        )?;
-
-        let the_env = AllocatedPtr::pick(
-            &mut cs.namespace(|| "the_env"),
-            &rest_is_nil,
-            &saved_env,
-            env,
-        )?;
-
-        let the_cont_otherwise = AllocatedContPtr::pick(
-            &mut cs.namespace(|| "the_cont_otherwise"),
-            &otherwise_and_rest_is_nil,
-            &newer_cont,
-            &g.error_ptr_cont,
-        )?;
-
-        let the_cont = AllocatedContPtr::pick(
-            &mut cs.namespace(|| "the_cont"),
-            &otherwise,
-            &the_cont_otherwise,
-            &continuation,
-        )?;
-
-        (the_expr, the_env, the_cont)
-    };
-    results.add_clauses_cont(ContTag::Binop, &the_expr, &the_env, &the_cont, &g.false_num);
-
-    // Continuation::Binop2
-    /////////////////////////////////////////////////////////////////////////////
-    let (the_expr, the_cont) = {
-        let op2 = AllocatedPtr::by_index(0, &continuation_components);
-        let arg1 = AllocatedPtr::by_index(1, &continuation_components);
-        let continuation = AllocatedContPtr::by_index(2, &continuation_components);
-
-        let arg2 = result;
-
-        let arg1_is_num = alloc_equal(&mut cs.namespace(|| "arg1_is_num"), arg1.tag(), &g.num_tag)?;
-        let arg2_is_num = alloc_equal(&mut cs.namespace(|| "arg2_is_num"), arg2.tag(), &g.num_tag)?;
-        let both_args_are_nums = Boolean::and(
-            &mut cs.namespace(|| "both_args_are_nums"),
-            &arg1_is_num,
-            &arg2_is_num,
-        )?;
-
-        let (a, b) = (arg1.hash(), arg2.hash()); // For Nums, the 'hash' is an immediate value.
+        let op_is_sum = alloc_equal(cs.namespace(|| "op_is_sum"), operator.tag(), &g.op2_sum_tag)?;
 
-        let not_dummy = alloc_equal(
-            &mut cs.namespace(|| "Binop2 not dummy"),
-            cont.tag(),
-            &g.binop2_cont_tag,
+        let op_is_diff = alloc_equal(
+            cs.namespace(|| "op_is_diff"),
+            operator.tag(),
+            &g.op2_diff_tag,
         )?;
 
-        let sum = constraints::add(&mut cs.namespace(|| "sum"), a, b)?;
-        let diff = constraints::sub(&mut cs.namespace(|| "difference"), a, b)?;
-        let product = constraints::mul(&mut cs.namespace(|| "product"), a, b)?;
-        let secret = match arg1.hash().get_value() {
-            Some(s) => s,
-            None => F::zero(), //dummy
-        };
-        let hide = hide(&mut cs.namespace(|| "Binop2 hide"), secret, arg2, store)?;
+        let op_is_product = alloc_equal(
+            cs.namespace(|| "op_is_product"),
+            operator.tag(),
+            &g.op2_product_tag,
+        )?;
 
-        let op2_is_div = alloc_equal(
-            cs.namespace(|| "op2_is_div"),
-            op2.tag(),
+        let op_is_quotient = alloc_equal(
+            cs.namespace(|| "op_is_quotient"),
+            operator.tag(),
             &g.op2_quotient_tag,
         )?;
 
-        let b_is_zero = &alloc_is_zero(&mut cs.namespace(|| "b_is_zero"), b)?;
+        let op_is_sum_or_diff = constraints::or(
+            &mut cs.namespace(|| "op_is_sum_or_diff"),
+            &op_is_sum,
+            &op_is_diff,
+        )?;
 
-        let divisor = pick(
-            &mut cs.namespace(|| "maybe-dummy divisor"),
-            b_is_zero,
-            &g.true_num,
-            b,
+        let op_is_product_or_quotient = constraints::or(
+            &mut cs.namespace(|| "op_is_product_or_quotient"),
+            &op_is_product,
+            &op_is_quotient,
         )?;
 
-        let quotient = constraints::div(&mut cs.namespace(|| "quotient"), a, &divisor)?;
+        let op_is_modulo = alloc_equal(
+            cs.namespace(|| "op_is_modulo"),
+            operator.tag(),
+            &g.op2_modulo_tag,
+        )?;
 
-        let cons =
-            AllocatedPtr::construct_cons(&mut cs.namespace(|| "cons"), g, store, &arg1, arg2)?;
+        let op_is_int_quotient = alloc_equal(
+            cs.namespace(|| "op_is_int_quotient"),
+            operator.tag(),
+            &g.op2_int_quotient_tag,
+        )?;
 
-        let val = case(
-            &mut cs.namespace(|| "Binop2 case"),
-            op2.tag(),
-            &[
-                CaseClause {
-                    key: Op2::Sum.as_field(),
-                    value: &sum,
-                },
-                CaseClause {
-                    key: Op2::Diff.as_field(),
-                    value: &diff,
-                },
-                CaseClause {
-                    key: Op2::Product.as_field(),
-                    value: &product,
-                },
-                CaseClause {
-                    key: Op2::Quotient.as_field(),
-                    value: &quotient,
-                },
-                CaseClause {
-                    key: Op2::Cons.as_field(),
-                    value: cons.hash(),
-                },
-                CaseClause {
-                    key: Op2::Hide.as_field(),
-                    value: hide.hash(),
-                },
-            ],
-            &g.default_num,
+        let op_is_modulo_or_int_quotient = constraints::or(
+            &mut cs.namespace(|| "op_is_modulo_or_int_quotient"),
+            &op_is_modulo,
+            &op_is_int_quotient,
         )?;
 
-        let is_cons = alloc_equal(
-            &mut cs.namespace(|| "Op2 is Cons"),
-            op2.tag(),
-            &g.op2_cons_tag,
+        let op_is_arithmetic = constraints::or(
+            &mut cs.namespace(|| "op_is_arithmetic"),
+            &op_is_sum_or_diff,
+            &op_is_product_or_quotient,
         )?;
 
-        let is_hide = alloc_equal(
-            &mut cs.namespace(|| "Op2 is Hide"),
-            op2.tag(),
-            &g.op2_hide_tag,
+        // Only + - * / % DIV take more than two arguments. Binop2 folds in the rest.
+        let op_is_variadic = constraints::or(
+            &mut cs.namespace(|| "op_is_variadic"),
+            &op_is_arithmetic,
+            &op_is_modulo_or_int_quotient,
         )?;
 
-        let hide_tag_is_comm = alloc_equal(
-            &mut cs.namespace(|| "hide tag is comm"),
-            hide.tag(),
-            &g.comm_tag,
+        let rest_is_nil_or_op_is_variadic = constraints::or(
+            &mut cs.namespace(|| "rest_is_nil_or_op_is_variadic"),
+            &more_args_is_nil,
+            &op_is_variadic,
         )?;
 
-        let hide_tag_is_dummy =
-            alloc_is_zero(&mut cs.namespace(|| "hide tag is dummy"), hide.tag())?;
+        let otherwise = Boolean::not(&op_is_begin);
 
-        let hide_tag_is_correct = constraints::or(
-            &mut cs.namespace(|| "hide tag is correct"),
-            &hide_tag_is_comm,
-            &hide_tag_is_dummy,
+        let otherwise_and_args_are_valid = Boolean::and(
+            &mut cs.namespace(|| "otherwise_and_args_are_valid"),
+            &otherwise,
+            &rest_is_nil_or_op_is_variadic,
         )?;
 
-        enforce_implication(
-            &mut cs.namespace(|| "is hide implies hide tag is correct"),
-            &is_hide,
-            &hide_tag_is_correct,
+        let arg2_is_next = constraints::or(
+            &mut cs.namespace(|| "arg2_is_next"),
+            &more_args_is_nil,
+            &otherwise_and_args_are_valid,
         )?;
 
-        let arg1_is_char = alloc_equal(
-            &mut cs.namespace(|| "arg1_is_char"),
-            arg1.tag(),
-            &g.char_tag,
-        )?;
-        let arg2_is_str = alloc_equal(&mut cs.namespace(|| "arg2_is_str"), arg2.tag(), &g.str_tag)?;
-        let args_are_char_str = Boolean::and(
-            &mut cs.namespace(|| "args_are_char_str"),
-            &arg1_is_char,
-            &arg2_is_str,
+        let the_expr = AllocatedPtr::pick(
+            &mut cs.namespace(|| "the_expr"),
+            &arg2_is_next,
+            &next_arg,
+            &the_expr_if_begin,
         )?;
 
-        let cons_tag = pick(
-            &mut cs.namespace(|| "cons_tag"),
-            &args_are_char_str,
-            &g.str_tag,
-            &g.cons_tag,
+        let the_env = AllocatedPtr::pick(
+            &mut cs.namespace(|| "the_env"),
+            &arg2_is_next,
+            &saved_env,
+            env,
         )?;
 
-        let comm_or_num_tag = pick(
-            &mut cs.namespace(|| "Op2 tag is comm or num"),
-            &is_hide,
-            &g.comm_tag,
-            &g.num_tag,
+        let the_cont_otherwise = AllocatedContPtr::pick(
+            &mut cs.namespace(|| "the_cont_otherwise"),
+            &otherwise_and_args_are_valid,
+            &newer_cont,
+            &g.error_ptr_cont,
         )?;
 
-        let res_tag = pick(
-            &mut cs.namespace(|| "Op2 result tag"),
-            &is_cons,
-            &cons_tag,
-            &comm_or_num_tag,
+        let the_cont = AllocatedContPtr::pick(
+            &mut cs.namespace(|| "the_cont"),
+            &otherwise,
+            &the_cont_otherwise,
+            &continuation,
         )?;
 
-        let res = AllocatedPtr::from_parts(res_tag, val);
+        // The only argument of (+ x) or (* x) is its value.
+        let op_is_sum_or_product = constraints::or(
+            &mut cs.namespace(|| "op_is_sum_or_product"),
+            &op_is_sum,
+            &op_is_product,
+        )?;
 
-        let is_cons_or_is_hide = constraints::or(
-            &mut cs.namespace(|| "is cons or is hide"),
-            &is_cons,
-            &is_hide,
+        let one_arg = Boolean::and(
+            &mut cs.namespace(|| "one_arg"),
+            &unevaled_args_is_nil,
+            &op_is_sum_or_product,
         )?;
 
-        let valid_types = constraints::or(
-            &mut cs.namespace(|| "Op2 called with valid types"),
-            &is_cons_or_is_hide,
-            &both_args_are_nums,
+        let the_expr = AllocatedPtr::pick(
+            &mut cs.namespace(|| "one arg expr"),
+            &one_arg,
+            result,
+            &the_expr,
         )?;
 
-        let real_division = Boolean::and(
-            &mut cs.namespace(|| "real_division"),
-            &not_dummy,
-            &op2_is_div,
+        let the_env =
+            AllocatedPtr::pick(&mut cs.namespace(|| "one arg env"), &one_arg, env, &the_env)?;
+
+        let one_arg_cont = AllocatedContPtr::pick(
+            &mut cs.namespace(|| "one arg cont or error"),
+            &result_is_number,
+            &continuation,
+            &g.error_ptr_cont,
         )?;
 
-        let real_div_and_b_is_zero = Boolean::and(
-            &mut cs.namespace(|| "real_div_and_b_is_zero"),
-            &real_division,
-            b_is_zero,
+        let the_cont = AllocatedContPtr::pick(
+            &mut cs.namespace(|| "one arg cont"),
+            &one_arg,
+            &one_arg_cont,
+            &the_cont,
         )?;
 
-        let valid_types_and_not_div_by_zero = Boolean::and(
-            &mut cs.namespace(|| "Op2 called with no errors"),
-            &valid_types,
-            &Boolean::not(&real_div_and_b_is_zero),
+        let one_arg_is_number = Boolean::and(
+            &mut cs.namespace(|| "one_arg_is_number"),
+            &one_arg,
+            &result_is_number,
         )?;
 
-        let op2_not_both_num_and_not_cons_or_hide = Boolean::and(
-            &mut cs.namespace(|| "not both num and not cons or hide"),
-            &Boolean::not(&both_args_are_nums),
-            &Boolean::not(&is_cons_or_is_hide),
+        let make_thunk = pick(
+            &mut cs.namespace(|| "make_thunk"),
+            &one_arg_is_number,
+            &g.true_num,
+            &g.false_num,
         )?;
 
-        let any_error = constraints::or(
-            &mut cs.namespace(|| "some error happened"),
-            &Boolean::not(&valid_types_and_not_div_by_zero),
-            &op2_not_both_num_and_not_cons_or_hide,
+        (the_expr, the_env, the_cont, make_thunk)
+    };
+    results.add_clauses_cont(
+        ContTag::Binop,
+        &the_expr,
+        &the_env,
+        &the_cont,
+        &binop_make_thunk,
+    );
+
+    // Continuation::Binop2, newer_cont is allocated
+    /////////////////////////////////////////////////////////////////////////////
+    let (the_expr, the_cont, binop2_make_thunk) = {
+        let mut cs = cs.namespace(|| "Binop2 next");
+        let continuation = AllocatedContPtr::by_index(3, &continuation_components);
+
+        // While arguments remain, the result is folded into a new Binop2.
+        let the_expr = AllocatedPtr::pick(
+            &mut cs.namespace(|| "result or next arg"),
+            &unevaled_args_is_nil,
+            &binop2_res,
+            &next_arg,
         )?;
 
         let the_cont = AllocatedContPtr::pick(
-            &mut cs.namespace(|| "maybe type or div by zero error"),
-            &any_error,
-            &g.error_ptr_cont,
+            &mut cs.namespace(|| "continuation or newer_cont"),
+            &unevaled_args_is_nil,
             &continuation,
+            &newer_cont,
         )?;
 
         let the_expr = AllocatedPtr::pick(
             &mut cs.namespace(|| "maybe expr error"),
-            &any_error,
+            &binop2_error,
             result,
-            &res,
+            &the_expr,
         )?;
 
-        (the_expr, the_cont)
-    };
-
-    results.add_clauses_cont(ContTag::Binop2, &the_expr, env, &the_cont, &g.true_num);
-
-    // Continuation::Relop, newer_cont is allocated
-    /////////////////////////////////////////////////////////////////////////////
-    let (the_expr, the_env, the_cont) = {
-        let mut cs = cs.namespace(|| "Relop");
-        let saved_env = AllocatedPtr::by_index(1, &continuation_components);
-        let unevaled_args = AllocatedPtr::by_index(2, &continuation_components);
-
-        let (allocated_arg2, allocated_rest) =
-            car_cdr(&mut cs.namespace(|| "cons"), g, &unevaled_args, store)?;
-
-        let rest_is_nil =
-            allocated_rest.alloc_equal(&mut cs.namespace(|| "args_is_nil"), &g.nil_ptr)?;
-
         let the_cont = AllocatedContPtr::pick(
-            &mut cs.namespace(|| "the_cont"),
-            &rest_is_nil,
-            &newer_cont,
+            &mut cs.namespace(|| "maybe type or div by zero error"),
+            &binop2_error,
             &g.error_ptr_cont,
+            &the_cont,
         )?;
 
-        let the_expr = AllocatedPtr::pick(
-            &mut cs.namespace(|| "the_expr"),
-            &rest_is_nil,
-            &allocated_arg2,
-            result,
+        let continues = Boolean::and(
+            &mut cs.namespace(|| "continues"),
+            &binop2_error.not(),
+            &unevaled_args_is_nil.not(),
         )?;
 
-        let the_env = AllocatedPtr::pick(
-            &mut cs.namespace(|| "the_env"),
-            &rest_is_nil,
-            &saved_env,
-            env,
+        let make_thunk = pick(
+            &mut cs.namespace(|| "make_thunk"),
+            &continues,
+            &g.false_num,
+            &g.true_num,
         )?;
 
-        (the_expr, the_env, the_cont)
+        (the_expr, the_cont, make_thunk)
     };
-    results.add_clauses_cont(ContTag::Relop, &the_expr, &the_env, &the_cont, &g.false_num);
+    results.add_clauses_cont(
+        ContTag::Binop2,
+        &the_expr,
+        env,
+        &the_cont,
+        &binop2_make_thunk,
+    );
 
-    // Continuation::Relop2
+    // Continuation::Relop, newer_cont is allocated
     /////////////////////////////////////////////////////////////////////////////
-    let (the_expr, the_cont) = {
-        let mut cs = cs.namespace(|| "Relop2");
-        let rel2 = AllocatedPtr::by_index(0, &continuation_components);
-        let arg1 = AllocatedPtr::by_index(1, &continuation_components);
-        let continuation = AllocatedContPtr::by_index(2, &continuation_components);
-        let arg2 = result;
-
-        let tags_equal = alloc_equal(&mut cs.namespace(|| "tags equal"), arg1.tag(), arg2.tag())?;
-
-        let vals_equal = alloc_equal(&mut cs.namespace(|| "vals equal"), arg1.hash(), arg2.hash())?;
+    let (the_expr, the_env, the_cont, relop_make_thunk) = {
+        let mut cs = cs.namespace(|| "Relop");
+        let saved_env = AllocatedPtr::by_index(1, &continuation_components);
+        let continuation = AllocatedContPtr::by_index(3, &continuation_components);
 
-        let arg1_tag_is_num = alloc_equal(
-            &mut cs.namespace(|| "arg1 tag is num"),
-            arg1.tag(),
-            &g.num_tag,
+        // A relation holds trivially for a single argument of the right type.
+        let operator = AllocatedPtr::by_index(0, &continuation_components);
+        let rel2_is_equal = alloc_equal(
+            &mut cs.namespace(|| "rel2 is Equal"),
+            operator.tag(),
+            &g.rel2_equal_tag,
         )?;
-
-        let arg2_tag_is_num = alloc_equal(
-            &mut cs.namespace(|| "arg2 tag is num"),
-            arg2.tag(),
-            &g.num_tag,
+        let holds = constraints::or(
+            &mut cs.namespace(|| "holds trivially"),
+            &rel2_is_equal,
+            &result_is_number,
         )?;
-
-        let args_are_num = Boolean::and(
-            &mut cs.namespace(|| "args are num"),
-            &arg1_tag_is_num,
-            &arg2_tag_is_num,
+        let t_or_result = AllocatedPtr::pick(
+            &mut cs.namespace(|| "t or result"),
+            &holds,
+            &g.t_ptr,
+            result,
         )?;
-
-        let rel2_is_equal = alloc_equal(
-            &mut cs.namespace(|| "rel2 tag is Equal"),
-            rel2.tag(),
-            &g.rel2_equal_tag,
+        let continuation_or_error = AllocatedContPtr::pick(
+            &mut cs.namespace(|| "continuation or error"),
+            &holds,
+            &continuation,
+            &g.error_ptr_cont,
         )?;
 
-        let args_equal =
-            Boolean::and(&mut cs.namespace(|| "args equal"), &tags_equal, &vals_equal)?;
+        let the_expr = AllocatedPtr::pick(
+            &mut cs.namespace(|| "the_expr"),
+            &unevaled_args_is_nil,
+            &t_or_result,
+            &next_arg,
+        )?;
 
-        let args_are_num_or_rel2_is_equal = constraints::or(
-            &mut cs.namespace(|| "args_are_num_or_rel2_is_equal"),
-            &args_are_num,
-            &rel2_is_equal,
+        let the_env = AllocatedPtr::pick(
+            &mut cs.namespace(|| "the_env"),
+            &unevaled_args_is_nil,
+            env,
+            &saved_env,
         )?;
 
-        let not_num_tag_without_nums = constraints::or(
-            &mut cs.namespace(|| "sub_res"),
-            &args_are_num,
-            &rel2_is_equal,
+        let the_cont = AllocatedContPtr::pick(
+            &mut cs.namespace(|| "the_cont"),
+            &unevaled_args_is_nil,
+            &continuation_or_error,
+            &newer_cont,
         )?;
 
-        let boolean_res = Boolean::and(
-            &mut cs.namespace(|| "boolean_res"),
-            &args_equal,
-            &not_num_tag_without_nums,
+        let single_arg_holds = Boolean::and(
+            &mut cs.namespace(|| "single_arg_holds"),
+            &unevaled_args_is_nil,
+            &holds,
         )?;
 
-        let res = AllocatedPtr::pick(
-            &mut cs.namespace(|| "res"),
-            &boolean_res,
-            &g.t_ptr,
-            &g.nil_ptr,
+        let make_thunk = pick(
+            &mut cs.namespace(|| "make_thunk"),
+            &single_arg_holds,
+            &g.true_num,
+            &g.false_num,
         )?;
 
+        (the_expr, the_env, the_cont, make_thunk)
+    };
+    results.add_clauses_cont(
+        ContTag::Relop,
+        &the_expr,
+        &the_env,
+        &the_cont,
+        &relop_make_thunk,
+    );
+
+    // Continuation::Relop2, newer_cont is allocated
+    /////////////////////////////////////////////////////////////////////////////
+    let (the_expr, the_cont, relop2_make_thunk) = {
+        let mut cs = cs.namespace(|| "Relop2 next");
+        let continuation = AllocatedContPtr::by_index(3, &continuation_components);
+
         let the_expr = AllocatedPtr::pick(
             &mut cs.namespace(|| "the_expr"),
-            &args_are_num_or_rel2_is_equal,
-            &res,
+            &relop2_no_error,
+            &relop2_res,
             result,
         )?;
 
         let the_cont = AllocatedContPtr::pick(
             &mut cs.namespace(|| "the_cont"),
-            &args_are_num_or_rel2_is_equal,
+            &relop2_no_error,
             &continuation,
             &g.error_ptr_cont,
         )?;
 
-        (the_expr, the_cont)
-    };
-    results.add_clauses_cont(ContTag::Relop2, &the_expr, env, &the_cont, &g.true_num);
+        // Chained relations go on to the next argument for as long as they hold.
+        let holds = Boolean::and(
+            &mut cs.namespace(|| "holds"),
+            &relop2_no_error,
+            &relop2_holds,
+        )?;
 
-    // Continuation::If
-    /////////////////////////////////////////////////////////////////////////////
-    let (res, the_cont) = {
-        let mut cs = cs.namespace(|| "If");
-        let unevaled_args = AllocatedPtr::by_index(0, &continuation_components);
-        let continuation = AllocatedContPtr::by_index(1, &continuation_components);
+        let continues = Boolean::and(
+            &mut cs.namespace(|| "continues"),
+            &holds,
+            &unevaled_args_is_nil.not(),
+        )?;
 
-        let condition = result;
+        let the_expr = AllocatedPtr::pick(
+            &mut cs.namespace(|| "next arg or the_expr"),
+            &continues,
+            &next_arg,
+            &the_expr,
+        )?;
 
-        // NOTE: There was a tricky bug here.
-        // When the actual continuation was Relop, and the operation is Numequal (for example),
-        // Then this appears to be an invalid but not dummy continuation, since Numequal has a Relop tag value of 1,
-        // the same as Cons.
-        //
-        // We address this by adding 2 to the tags returned by Op2 and Rel2 fr() methods, so this collision cannot happen.
-        // TODO: It might make even more sense to make all disjoint.
-        let (arg1, more) = car_cdr(
-            &mut cs.namespace(|| "unevaled_args cons"),
-            g,
-            &unevaled_args,
-            store,
+        let the_cont = AllocatedContPtr::pick(
+            &mut cs.namespace(|| "newer_cont or the_cont"),
+            &continues,
+            &newer_cont,
+            &the_cont,
         )?;
 
-        let condition_is_nil =
-            condition.alloc_equal(&mut cs.namespace(|| "condition is nil"), &g.nil_ptr)?;
+        let make_thunk = pick(
+            &mut cs.namespace(|| "make_thunk"),
+            &continues,
+            &g.false_num,
+            &g.true_num,
+        )?;
+
+        (the_expr, the_cont, make_thunk)
+    };
+    results.add_clauses_cont(
+        ContTag::Relop2,
+        &the_expr,
+        env,
+        &the_cont,
+        &relop2_make_thunk,
+    );
+
+    // Continuation::If
+    /////////////////////////////////////////////////////////////////////////////
+    let (res, the_cont) = {
+        let mut cs = cs.namespace(|| "If result");
+        let continuation = &if_continuation;
+        let (arg1, more) = (&if_arg1, &if_more);
 
         let (arg2, end) = car_cdr(&mut cs.namespace(|| "more cons"), g, &more, store)?;
 
@@ -3147,6 +5586,126 @@ fn apply_continuation<F: LurkField, CS: ConstraintSystem<F>>(
 
     results.add_clauses_cont(ContTag::If, &res, env, &the_cont, &g.false_num);
 
+    // Continuation::And and Continuation::Or, newer_cont is allocated
+    /////////////////////////////////////////////////////////////////////////////
+    let (and_expr, and_cont, and_make_thunk, or_expr, or_cont, or_make_thunk) = {
+        let mut cs = cs.namespace(|| "And and Or");
+
+        let more_is_nil = if_more.alloc_equal(&mut cs.namespace(|| "more is nil"), &g.nil_ptr)?;
+
+        // The last argument is evaluated in tail position.
+        let next_cont = AllocatedContPtr::pick(
+            &mut cs.namespace(|| "next cont"),
+            &more_is_nil,
+            &if_continuation,
+            &newer_cont,
+        )?;
+
+        // AND stops at the first NIL.
+        let and_expr = AllocatedPtr::pick(
+            &mut cs.namespace(|| "and expr"),
+            &condition_is_nil,
+            result,
+            &if_arg1,
+        )?;
+        let and_cont = AllocatedContPtr::pick(
+            &mut cs.namespace(|| "and cont"),
+            &condition_is_nil,
+            &if_continuation,
+            &next_cont,
+        )?;
+        let and_make_thunk = pick(
+            &mut cs.namespace(|| "and make_thunk"),
+            &condition_is_nil,
+            &g.true_num,
+            &g.false_num,
+        )?;
+
+        // OR stops at the first value which is not NIL.
+        let or_expr = AllocatedPtr::pick(
+            &mut cs.namespace(|| "or expr"),
+            &condition_is_nil,
+            &if_arg1,
+            result,
+        )?;
+        let or_cont = AllocatedContPtr::pick(
+            &mut cs.namespace(|| "or cont"),
+            &condition_is_nil,
+            &next_cont,
+            &if_continuation,
+        )?;
+        let or_make_thunk = pick(
+            &mut cs.namespace(|| "or make_thunk"),
+            &condition_is_nil,
+            &g.false_num,
+            &g.true_num,
+        )?;
+
+        (
+            and_expr,
+            and_cont,
+            and_make_thunk,
+            or_expr,
+            or_cont,
+            or_make_thunk,
+        )
+    };
+    results.add_clauses_cont(ContTag::And, &and_expr, env, &and_cont, &and_make_thunk);
+    results.add_clauses_cont(ContTag::Or, &or_expr, env, &or_cont, &or_make_thunk);
+
+    // Continuation::Eval, newer_cont is allocated
+    /////////////////////////////////////////////////////////////////////////////
+    let (eval_expr, eval_env, eval_cont) = {
+        let mut cs = cs.namespace(|| "Eval");
+        let eval_cont = AllocatedContPtr::by_index(1, &continuation_components);
+
+        let (expr, expr_env) = car_cdr(&mut cs.namespace(|| "expr and env"), g, result, store)?;
+
+        let result_is_cons =
+            alloc_equal(cs.namespace(|| "result_is_cons"), result.tag(), &g.cons_tag)?;
+
+        let continuation_is_tail = alloc_equal(
+            &mut cs.namespace(|| "continuation is tail"),
+            eval_cont.tag(),
+            &g.tail_cont_tag,
+        )?;
+
+        let tail_cont = AllocatedContPtr::pick(
+            &mut cs.namespace(|| "the tail continuation"),
+            &continuation_is_tail,
+            &eval_cont,
+            &newer_cont,
+        )?;
+
+        let the_expr = AllocatedPtr::pick(
+            &mut cs.namespace(|| "the_expr"),
+            &result_is_cons,
+            &expr,
+            result,
+        )?;
+        let the_env = AllocatedPtr::pick(
+            &mut cs.namespace(|| "the_env"),
+            &result_is_cons,
+            &expr_env,
+            env,
+        )?;
+        let the_cont = AllocatedContPtr::pick(
+            &mut cs.namespace(|| "the_cont"),
+            &result_is_cons,
+            &tail_cont,
+            &g.error_ptr_cont,
+        )?;
+
+        (the_expr, the_env, the_cont)
+    };
+    results.add_clauses_cont(
+        ContTag::Eval,
+        &eval_expr,
+        &eval_env,
+        &eval_cont,
+        &g.false_num,
+    );
+
     // Continuation::Lookup
     /////////////////////////////////////////////////////////////////////////////
     let saved_env = AllocatedPtr::by_index(0, &continuation_components);
@@ -3359,6 +5918,14 @@ fn apply_continuation<F: LurkField, CS: ConstraintSystem<F>>(
             &g.char_tag,
         )?;
 
+        let op1_is_u64 = alloc_equal(
+            &mut cs.namespace(|| "op1_is_u64"),
+            unop_op1.tag(),
+            &g.op1_u64_tag,
+        )?;
+
+        let tag_is_u64 = alloc_equal(&mut cs.namespace(|| "tag_is_u64"), result.tag(), &g.u64_tag)?;
+
         let tag_is_num_or_comm = constraints::or(
             &mut cs.namespace(|| "tag_is_num_or_comm"),
             &tag_is_num,
@@ -3383,10 +5950,34 @@ fn apply_continuation<F: LurkField, CS: ConstraintSystem<F>>(
             &Boolean::not(&tag_is_num_or_comm),
         )?;
 
+        let tag_is_num_or_comm_or_char_or_u64 = constraints::or(
+            &mut cs.namespace(|| "tag_is_num_or_comm_or_char_or_u64"),
+            &tag_is_num_or_comm_or_char,
+            &tag_is_u64,
+        )?;
+
         let num_invalid_tag_error = Boolean::and(
             &mut cs.namespace(|| "num_invalid_tag_error"),
             &op1_is_num,
-            &Boolean::not(&tag_is_num_or_comm_or_char),
+            &Boolean::not(&tag_is_num_or_comm_or_char_or_u64),
+        )?;
+
+        let tag_is_num_or_u64 = constraints::or(
+            &mut cs.namespace(|| "tag_is_num_or_u64"),
+            &tag_is_num,
+            &tag_is_u64,
+        )?;
+
+        let u64_is_valid = Boolean::and(
+            &mut cs.namespace(|| "u64_is_valid"),
+            &tag_is_num_or_u64,
+            &u64_fits,
+        )?;
+
+        let u64_error = Boolean::and(
+            &mut cs.namespace(|| "u64_error"),
+            &op1_is_u64,
+            &Boolean::not(&u64_is_valid),
         )?;
 
         let char_invalid_tag_error = Boolean::and(
@@ -3395,6 +5986,90 @@ fn apply_continuation<F: LurkField, CS: ConstraintSystem<F>>(
             &Boolean::not(&tag_is_num_or_char),
         )?;
 
+        // A Num is a character code if it fits in a u64 and is a Unicode scalar value: less than
+        // 0x110000, and not a surrogate in [0xD800, 0xE000).
+        let char_code_max =
+            allocate_constant(&mut cs.namespace(|| "char_code_max"), F::from(0x110000u64))?;
+        let surrogate_min =
+            allocate_constant(&mut cs.namespace(|| "surrogate_min"), F::from(0xD800u64))?;
+        let surrogate_max =
+            allocate_constant(&mut cs.namespace(|| "surrogate_max"), F::from(0xE000u64))?;
+
+        let below_char_code_max = alloc_is_less_than(
+            &mut cs.namespace(|| "below_char_code_max"),
+            &u64_val,
+            &char_code_max,
+            64,
+        )?;
+        let below_surrogates = alloc_is_less_than(
+            &mut cs.namespace(|| "below_surrogates"),
+            &u64_val,
+            &surrogate_min,
+            64,
+        )?;
+        let below_surrogate_max = alloc_is_less_than(
+            &mut cs.namespace(|| "below_surrogate_max"),
+            &u64_val,
+            &surrogate_max,
+            64,
+        )?;
+
+        let not_surrogate = constraints::or(
+            &mut cs.namespace(|| "not_surrogate"),
+            &below_surrogates,
+            &Boolean::not(&below_surrogate_max),
+        )?;
+
+        let is_scalar_value = Boolean::and(
+            &mut cs.namespace(|| "is_scalar_value"),
+            &below_char_code_max,
+            &not_surrogate,
+        )?;
+
+        let is_char_code = Boolean::and(
+            &mut cs.namespace(|| "is_char_code"),
+            &u64_fits,
+            &is_scalar_value,
+        )?;
+
+        let op1_is_char_and_tag_is_num = Boolean::and(
+            &mut cs.namespace(|| "op1_is_char_and_tag_is_num"),
+            &op1_is_char,
+            &tag_is_num,
+        )?;
+
+        let char_range_error = Boolean::and(
+            &mut cs.namespace(|| "char_range_error"),
+            &op1_is_char_and_tag_is_num,
+            &Boolean::not(&is_char_code),
+        )?;
+
+        let op1_is_open = alloc_equal(
+            &mut cs.namespace(|| "op1_is_open"),
+            unop_op1.tag(),
+            &g.op1_open_tag,
+        )?;
+
+        let op1_is_secret = alloc_equal(
+            &mut cs.namespace(|| "op1_is_secret"),
+            unop_op1.tag(),
+            &g.op1_secret_tag,
+        )?;
+
+        // The opening or secret of a commitment whose payload is unknown cannot be proved, and
+        // its evaluation fails instead, so these are the only errors of OPEN and SECRET.
+        let open_invalid_tag_error = Boolean::and(
+            &mut cs.namespace(|| "open_invalid_tag_error"),
+            &op1_is_open,
+            &Boolean::not(&tag_is_num_or_comm),
+        )?;
+
+        let secret_invalid_tag_error = Boolean::and(
+            &mut cs.namespace(|| "secret_invalid_tag_error"),
+            &op1_is_secret,
+            &Boolean::not(&tag_is_comm),
+        )?;
+
         // Any error? Compute the OR of individual errors
         let any_error1 = constraints::or(
             &mut cs.namespace(|| "any_error1"),
@@ -3408,12 +6083,33 @@ fn apply_continuation<F: LurkField, CS: ConstraintSystem<F>>(
             &num_invalid_tag_error,
         )?;
 
-        let any_error = constraints::or(
-            &mut cs.namespace(|| "any_error"),
+        let any_error3 = constraints::or(
+            &mut cs.namespace(|| "any_error3"),
             &any_error2,
             &char_invalid_tag_error,
         )?;
 
+        let any_error4 = constraints::or(
+            &mut cs.namespace(|| "any_error4"),
+            &any_error3,
+            &char_range_error,
+        )?;
+
+        let any_error5 = constraints::or(
+            &mut cs.namespace(|| "any_error5"),
+            &any_error4,
+            &open_invalid_tag_error,
+        )?;
+
+        let any_error6 = constraints::or(
+            &mut cs.namespace(|| "any_error6"),
+            &any_error5,
+            &secret_invalid_tag_error,
+        )?;
+
+        let any_error =
+            constraints::or(&mut cs.namespace(|| "any_error"), &any_error6, &u64_error)?;
+
         let the_expr = AllocatedPtr::pick(
             &mut cs.namespace(|| "the_expr"),
             &any_error,
@@ -3480,7 +6176,6 @@ fn hide<F: LurkField, CS: ConstraintSystem<F>>(
 
 fn open<F: LurkField, CS: ConstraintSystem<F>>(
     mut cs: CS,
-    not_dummy: &Boolean,
     maybe_commit: &AllocatedPtr<F>,
     store: &Store<F>,
 ) -> Result<AllocatedPtr<F>, SynthesisError> {
@@ -3489,29 +6184,14 @@ fn open<F: LurkField, CS: ConstraintSystem<F>>(
         None => F::zero(), // dummy value
     };
 
-    let dummy_check = || {
-        match not_dummy.get_value() {
-            Some(not_dummy) => {
-                if not_dummy {
-                    panic!("hidden value could not be opened")
-                } else {
-                    // TODO: replace by dummy pointer (bogus)
-                    store.get_nil()
-                }
-            }
-            None => {
-                // TODO: replace by dummy pointer (blank)
-                store.get_nil()
-            }
-        }
-    };
-
-    let open_ptr = match store.get_maybe_opaque(Tag::Comm, hash) {
-        Some(commit) => match store.open(commit) {
-            Some(openning) => openning,
-            None => dummy_check(),
-        },
-        None => dummy_check(),
+    // Opening a commitment whose payload is unknown fails in the evaluator, so no frame needs
+    // it, and any witness will do.
+    let open_ptr = match store
+        .get_maybe_opaque(Tag::Comm, hash)
+        .and_then(|commit| store.open(commit))
+    {
+        Some(opening) => opening,
+        None => store.get_nil(), // dummy
     };
 
     AllocatedPtr::alloc_ptr(&mut cs.namespace(|| "open"), store, || Ok(&open_ptr))
@@ -3519,7 +6199,6 @@ fn open<F: LurkField, CS: ConstraintSystem<F>>(
 
 fn secret<F: LurkField, CS: ConstraintSystem<F>>(
     mut cs: CS,
-    not_dummy: &Boolean,
     maybe_commit: &AllocatedPtr<F>,
     store: &Store<F>,
 ) -> Result<AllocatedPtr<F>, SynthesisError> {
@@ -3528,29 +6207,13 @@ fn secret<F: LurkField, CS: ConstraintSystem<F>>(
         None => F::zero(), // dummy value
     };
 
-    let dummy_check = || {
-        match not_dummy.get_value() {
-            Some(not_dummy) => {
-                if not_dummy {
-                    panic!("secret could not be extracted")
-                } else {
-                    // TODO: replace by dummy pointer (bogus)
-                    store.get_nil()
-                }
-            }
-            None => {
-                // TODO: replace by dummy pointer (blank)
-                store.get_nil()
-            }
-        }
-    };
-
-    let secret_ptr = match store.get_maybe_opaque(Tag::Comm, hash) {
-        Some(commit) => match store.secret(commit) {
-            Some(secret) => secret,
-            None => dummy_check(),
-        },
-        None => dummy_check(),
+    // As in `open`, an unknown secret cannot be evaluated.
+    let secret_ptr = match store
+        .get_maybe_opaque(Tag::Comm, hash)
+        .and_then(|commit| store.secret(commit))
+    {
+        Some(secret) => secret,
+        None => store.get_nil(), // dummy
     };
     AllocatedPtr::alloc_ptr(&mut cs.namespace(|| "secret"), store, || Ok(&secret_ptr))
 }
@@ -3561,8 +6224,10 @@ fn num<F: LurkField, CS: ConstraintSystem<F>>(
     store: &Store<F>,
 ) -> Result<AllocatedPtr<F>, SynthesisError> {
     let num_ptr = if let Some(ptr) = maybe_num.ptr(store).as_ref() {
-        let scalar_ptr = store.get_expr_hash(ptr).expect("expr hash missing");
-        match store.get_num(crate::Num::Scalar::<F>(*scalar_ptr.value())) {
+        match store
+            .get_expr_hash(ptr)
+            .and_then(|scalar_ptr| store.get_num(crate::Num::Scalar::<F>(*scalar_ptr.value())))
+        {
             Some(n) => n,
             None => store.get_nil(),
         }
@@ -3588,23 +6253,6 @@ fn comm<F: LurkField, CS: ConstraintSystem<F>>(
     AllocatedPtr::alloc_ptr(&mut cs.namespace(|| "open"), store, || Ok(&comm_ptr))
 }
 
-fn char_op<F: LurkField, CS: ConstraintSystem<F>>(
-    mut cs: CS,
-    maybe_char: &AllocatedPtr<F>,
-    store: &Store<F>,
-) -> Result<AllocatedPtr<F>, SynthesisError> {
-    let char_ptr = if let Some(ptr) = maybe_char.ptr(store).as_ref() {
-        let scalar_ptr = store.get_expr_hash(ptr).expect("expr hash missing");
-        match scalar_ptr.value().to_u32() {
-            Some(n) => store.get_char(char::from_u32(n).unwrap()),
-            None => store.get_nil(),
-        }
-    } else {
-        store.get_nil()
-    };
-    AllocatedPtr::alloc_ptr(&mut cs.namespace(|| "char_op"), store, || Ok(&char_ptr))
-}
-
 fn car_cdr<F: LurkField, CS: ConstraintSystem<F>>(
     mut cs: CS,
     g: &GlobalAllocations<F>,
@@ -3648,7 +6296,11 @@ fn car_cdr<F: LurkField, CS: ConstraintSystem<F>>(
                 .get_value()
                 .expect("maybe_cons_is_str is missing")
         {
-            store.car_cdr(ptr)
+            // An opaque or missing value is an error in the evaluator, so the dummy witness need
+            // not satisfy the constraints below.
+            store
+                .get_car_cdr(ptr)
+                .unwrap_or((store.get_nil(), store.get_nil()))
         } else {
             (store.get_nil(), store.get_nil())
         }
@@ -3831,7 +6483,7 @@ mod tests {
             cont: store.intern_cont_outermost(),
         };
 
-        let (_, witness) = input.reduce(&mut store);
+        let (_, witness) = input.reduce(&mut store).unwrap();
 
         let groth_prover = Groth16Prover::new(DEFAULT_CHUNK_FRAME_COUNT);
         let groth_params = groth_prover.groth_params().unwrap();
@@ -3871,9 +6523,9 @@ mod tests {
             assert!(delta == Delta::Equal);
 
             //println!("{}", print_cs(&cs));
-            assert_eq!(19295, cs.num_constraints());
-            assert_eq!(13, cs.num_inputs());
-            assert_eq!(19220, cs.aux().len());
+            assert_eq!(32716, cs.num_constraints());
+            assert_eq!(15, cs.num_inputs());
+            assert_eq!(32614, cs.aux().len());
 
             let public_inputs = multiframe.public_inputs();
             let mut rng = rand::thread_rng();
@@ -3954,7 +6606,7 @@ mod tests {
             cont: store.intern_cont_outermost(),
         };
 
-        let (_, witness) = input.reduce(&mut store);
+        let (_, witness) = input.reduce(&mut store).unwrap();
 
         let test_with_output = |output: IO<Fr>, expect_success: bool, store: &Store<Fr>| {
             let mut cs = TestConstraintSystem::<Fr>::new();
@@ -4031,7 +6683,7 @@ mod tests {
             cont: store.intern_cont_outermost(),
         };
 
-        let (_, witness) = input.reduce(&mut store);
+        let (_, witness) = input.reduce(&mut store).unwrap();
 
         let test_with_output = |output: IO<Fr>, expect_success: bool, store: &Store<Fr>| {
             let mut cs = TestConstraintSystem::<Fr>::new();
@@ -4108,7 +6760,7 @@ mod tests {
             cont: store.intern_cont_outermost(),
         };
 
-        let (_, witness) = input.reduce(&mut store);
+        let (_, witness) = input.reduce(&mut store).unwrap();
 
         let test_with_output = |output: IO<Fr>, expect_success: bool, store: &Store<Fr>| {
             let mut cs = TestConstraintSystem::<Fr>::new();
@@ -4186,7 +6838,7 @@ mod tests {
             cont: store.intern_cont_outermost(),
         };
 
-        let (_, witness) = input.reduce(&mut store);
+        let (_, witness) = input.reduce(&mut store).unwrap();
 
         let test_with_output = |output, expect_success, store: &mut Store<Fr>| {
             let mut cs = TestConstraintSystem::<Fr>::new();
@@ -4228,4 +6880,71 @@ mod tests {
             }
         }
     }
+
+    #[test]
+    fn witness_from_oracle() {
+        let mut store = Store::default();
+        let env = empty_sym_env(&store);
+        let expr = store.read("(witness :secret)").unwrap();
+        let input = IO {
+            expr,
+            env,
+            cont: store.intern_cont_outermost(),
+        };
+
+        let mut oracle = |store: &mut Store<Fr>, _name: Ptr<Fr>| Some(store.num(42));
+        let (_, witness) = input
+            .reduce_with_oracle(&mut store, Some(&mut oracle))
+            .unwrap();
+
+        let test_with_output = |output, witness, expect_success, store: &Store<Fr>| {
+            let mut cs = TestConstraintSystem::<Fr>::new();
+
+            let frame = Frame {
+                input,
+                output,
+                i: 0,
+                witness,
+            };
+
+            MultiFrame::<<Bls12 as Engine>::Fr, _, _>::from_frames(
+                DEFAULT_CHUNK_FRAME_COUNT,
+                &[frame],
+                store,
+            )[0]
+            .clone()
+            .synthesize(&mut cs)
+            .expect("failed to synthesize");
+
+            assert_eq!(expect_success, cs.is_satisfied());
+        };
+
+        let terminal = store.intern_cont_terminal();
+        let output = IO {
+            expr: store.num(42),
+            env,
+            cont: terminal,
+        };
+        test_with_output(output, witness, true, &store);
+
+        // The output must be the value which was read.
+        let bad_output = IO {
+            expr: store.num(999),
+            env,
+            cont: terminal,
+        };
+        test_with_output(bad_output, witness, false, &store);
+
+        // But nothing constrains the value itself: any other oracle could have supplied it.
+        let other_witness = Witness {
+            oracle_value: Some(store.num(999)),
+            ..witness
+        };
+        test_with_output(bad_output, other_witness, true, &store);
+
+        // Nor whether there was a value at all: the prover may claim the oracle had none.
+        let (error_output, no_witness) = input.reduce_with_oracle(&mut store, None).unwrap();
+        assert!(error_output.is_error());
+        test_with_output(error_output, no_witness, true, &store);
+    }
 }
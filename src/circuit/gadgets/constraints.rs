@@ -5,7 +5,7 @@ use bellperson::{
         boolean::{AllocatedBit, Boolean},
         num::AllocatedNum,
     },
-    ConstraintSystem, SynthesisError,
+    ConstraintSystem, LinearCombination, SynthesisError,
 };
 use ff::PrimeField;
 
@@ -344,6 +344,240 @@ pub fn alloc_is_zero<CS: ConstraintSystem<F>, F: PrimeField>(
     Ok(Boolean::Is(result))
 }
 
+/// Allocates the `n` least-significant bits of `x`, and enforces that they are its binary
+/// representation, least-significant bit first.
+///
+/// This is a range check: the constraints can only be satisfied if `x < 2^n`.
+pub fn alloc_bits_le<F: PrimeField, CS: ConstraintSystem<F>>(
+    mut cs: CS,
+    x: &AllocatedNum<F>,
+    n: usize,
+) -> Result<Vec<AllocatedBit>, SynthesisError> {
+    let repr = x.get_value().map(|x| x.to_repr());
+
+    let bits = (0..n)
+        .map(|i| {
+            AllocatedBit::alloc(
+                cs.namespace(|| format!("bit {}", i)),
+                repr.map(|repr| bit_le(repr.as_ref(), i)),
+            )
+        })
+        .collect::<Result<Vec<_>, _>>()?;
+
+    let packed = pack_bits(&bits);
+
+    // sum(bits[i] * 2^i) * 1 = x
+    cs.enforce(
+        || "bits sum to x",
+        |_| packed,
+        |lc| lc + CS::one(),
+        |lc| lc + x.get_variable(),
+    );
+
+    Ok(bits)
+}
+
+/// Returns a Boolean which is true if `x`, taken as an integer less than the field modulus, is
+/// less than 2^`n`. Also returns the number made of the `n` least-significant bits of `x`, which
+/// equals `x` when it fits.
+///
+/// Unlike `alloc_bits_le`, this can be satisfied for every `x`. A value which does not fit in
+/// `F::CAPACITY` bits is shown to be out of range by decomposing `-1 - x` instead. That is sound
+/// as long as 2^`n` is no greater than `modulus - 2^CAPACITY`, which holds for `n = 64` in every
+/// field we use.
+pub fn alloc_fits_in_bits<F: PrimeField, CS: ConstraintSystem<F>>(
+    mut cs: CS,
+    x: &AllocatedNum<F>,
+    n: usize,
+) -> Result<(Boolean, AllocatedNum<F>), SynthesisError> {
+    let capacity = F::CAPACITY as usize;
+    assert!(n < capacity);
+
+    let is_large = AllocatedBit::alloc(
+        cs.namespace(|| "x is large"),
+        x.get_value().map(|x| {
+            let repr = x.to_repr();
+            (capacity..F::NUM_BITS as usize).any(|i| bit_le(repr.as_ref(), i))
+        }),
+    )?;
+
+    let complement = AllocatedNum::alloc(cs.namespace(|| "complement"), || {
+        let x = x.get_value().ok_or(SynthesisError::AssignmentMissing)?;
+        Ok(-x - F::one())
+    })?;
+
+    // (x + complement + 1) * 1 = 0
+    cs.enforce(
+        || "x + complement = -1",
+        |lc| lc + x.get_variable() + complement.get_variable() + CS::one(),
+        |lc| lc + CS::one(),
+        |lc| lc,
+    );
+
+    let value = pick(
+        cs.namespace(|| "value"),
+        &Boolean::Is(is_large.clone()),
+        &complement,
+        x,
+    )?;
+
+    let bits = alloc_bits_le(cs.namespace(|| "value bits"), &value, capacity)?;
+
+    let low = alloc_packed_bits(cs.namespace(|| "low bits"), &bits[..n])?;
+    let high = alloc_packed_bits(cs.namespace(|| "high bits"), &bits[n..])?;
+    let high_is_zero = alloc_is_zero(cs.namespace(|| "high bits are zero"), &high)?;
+
+    let fits = Boolean::and(
+        cs.namespace(|| "fits"),
+        &Boolean::Is(is_large).not(),
+        &high_is_zero,
+    )?;
+
+    Ok((fits, low))
+}
+
+/// Returns a Boolean which is true if `a < b`. Both `a` and `b` must already be known to be
+/// less than 2^`n`, for example because they come from `alloc_fits_in_bits`.
+pub fn alloc_is_less_than<F: PrimeField, CS: ConstraintSystem<F>>(
+    mut cs: CS,
+    a: &AllocatedNum<F>,
+    b: &AllocatedNum<F>,
+    n: usize,
+) -> Result<Boolean, SynthesisError> {
+    let mut two_to_n = F::one();
+    for _ in 0..n {
+        two_to_n = two_to_n.double();
+    }
+
+    // shifted = a + 2^n - b lies in [1, 2^(n+1)), and bit n is set iff a >= b.
+    let shifted = AllocatedNum::alloc(cs.namespace(|| "shifted"), || {
+        let a = a.get_value().ok_or(SynthesisError::AssignmentMissing)?;
+        let b = b.get_value().ok_or(SynthesisError::AssignmentMissing)?;
+        Ok(a + two_to_n - b)
+    })?;
+
+    // (a + 2^n - b) * 1 = shifted
+    cs.enforce(
+        || "shifted = a + 2^n - b",
+        |lc| lc + a.get_variable() + (two_to_n, CS::one()) - b.get_variable(),
+        |lc| lc + CS::one(),
+        |lc| lc + shifted.get_variable(),
+    );
+
+    let bits = alloc_bits_le(cs.namespace(|| "shifted bits"), &shifted, n + 1)?;
+
+    Ok(Boolean::Is(bits[n].clone()).not())
+}
+
+/// Returns the number made of the `n` least-significant bits of `x`, which must be less than
+/// 2^`m`. This is how wrapping arithmetic on fixed-width integers is constrained.
+pub fn alloc_low_bits<F: PrimeField, CS: ConstraintSystem<F>>(
+    mut cs: CS,
+    x: &AllocatedNum<F>,
+    n: usize,
+    m: usize,
+) -> Result<AllocatedNum<F>, SynthesisError> {
+    assert!(n <= m && m < F::CAPACITY as usize);
+
+    let bits = alloc_bits_le(cs.namespace(|| "bits"), x, m)?;
+
+    alloc_packed_bits(cs.namespace(|| "low bits"), &bits[..n])
+}
+
+/// Returns the quotient and remainder of the Euclidean division of `a` by `b`. Both `a` and `b`
+/// must already be known to be less than 2^`n`, and `b` must not be zero.
+pub fn alloc_div_rem<F: PrimeField, CS: ConstraintSystem<F>>(
+    mut cs: CS,
+    a: &AllocatedNum<F>,
+    b: &AllocatedNum<F>,
+    n: usize,
+) -> Result<(AllocatedNum<F>, AllocatedNum<F>), SynthesisError> {
+    assert!(n <= 64);
+
+    let as_u64 = |x: &AllocatedNum<F>| {
+        x.get_value().map(|x| {
+            let repr = x.to_repr();
+            (0..64).rev().fold(0u64, |acc, i| {
+                (acc << 1) | u64::from(bit_le(repr.as_ref(), i))
+            })
+        })
+    };
+    let (a_val, b_val) = (as_u64(a), as_u64(b));
+
+    let q = AllocatedNum::alloc(cs.namespace(|| "quotient"), || {
+        let (a, b) = a_val.zip(b_val).ok_or(SynthesisError::AssignmentMissing)?;
+        a.checked_div(b)
+            .map(F::from)
+            .ok_or(SynthesisError::DivisionByZero)
+    })?;
+    let r = AllocatedNum::alloc(cs.namespace(|| "remainder"), || {
+        let (a, b) = a_val.zip(b_val).ok_or(SynthesisError::AssignmentMissing)?;
+        a.checked_rem(b)
+            .map(F::from)
+            .ok_or(SynthesisError::DivisionByZero)
+    })?;
+
+    // q * b = a - r
+    cs.enforce(
+        || "a = q * b + r",
+        |lc| lc + q.get_variable(),
+        |lc| lc + b.get_variable(),
+        |lc| lc + a.get_variable() - r.get_variable(),
+    );
+
+    // Both q and r are less than 2^n, so q * b + r can't wrap around the modulus.
+    alloc_bits_le(cs.namespace(|| "quotient bits"), &q, n)?;
+    alloc_bits_le(cs.namespace(|| "remainder bits"), &r, n)?;
+
+    let r_is_less = alloc_is_less_than(cs.namespace(|| "r < b"), &r, b, n)?;
+    enforce_true(cs.namespace(|| "enforce r < b"), &r_is_less)?;
+
+    Ok((q, r))
+}
+
+/// Allocates the number whose binary representation, least-significant bit first, is `bits`.
+fn alloc_packed_bits<F: PrimeField, CS: ConstraintSystem<F>>(
+    mut cs: CS,
+    bits: &[AllocatedBit],
+) -> Result<AllocatedNum<F>, SynthesisError> {
+    let num = AllocatedNum::alloc(cs.namespace(|| "num"), || {
+        bits.iter().rev().try_fold(F::zero(), |acc, bit| {
+            let acc = acc.double();
+            match bit.get_value() {
+                Some(true) => Ok(acc + F::one()),
+                Some(false) => Ok(acc),
+                None => Err(SynthesisError::AssignmentMissing),
+            }
+        })
+    })?;
+
+    let packed = pack_bits(bits);
+
+    // sum(bits[i] * 2^i) * 1 = num
+    cs.enforce(
+        || "bits sum to num",
+        |_| packed,
+        |lc| lc + CS::one(),
+        |lc| lc + num.get_variable(),
+    );
+
+    Ok(num)
+}
+
+fn pack_bits<F: PrimeField>(bits: &[AllocatedBit]) -> LinearCombination<F> {
+    let mut coeff = F::one();
+    let mut lc = LinearCombination::zero();
+    for bit in bits {
+        lc = lc + (coeff, bit.get_variable());
+        coeff = coeff.double();
+    }
+    lc
+}
+
+fn bit_le(bytes: &[u8], i: usize) -> bool {
+    (bytes[i / 8] >> (i % 8)) & 1 == 1
+}
+
 pub fn enforce_implication<CS: ConstraintSystem<F>, F: PrimeField>(
     mut cs: CS,
     a: &Boolean,
@@ -459,4 +693,85 @@ mod tests {
             assert!(cs.is_satisfied());
         }
     }
+
+    #[test]
+    fn fits_in_bits_and_less_than() {
+        let big = Fr::from(u64::MAX) + Fr::one();
+        let minus_one = Fr::zero() - Fr::one();
+        let cases = [
+            (Fr::from(3), Fr::from(5), Some(true)),
+            (Fr::from(5), Fr::from(3), Some(false)),
+            (Fr::from(5), Fr::from(5), Some(false)),
+            (Fr::zero(), Fr::from(u64::MAX), Some(true)),
+            (Fr::from(u64::MAX), Fr::zero(), Some(false)),
+            (big, Fr::from(3), None),
+            (Fr::from(3), minus_one, None),
+        ];
+
+        for (a_val, b_val, expected) in cases.iter() {
+            let mut cs = TestConstraintSystem::<Fr>::new();
+
+            let a = AllocatedNum::alloc(cs.namespace(|| "a"), || Ok(*a_val)).expect("alloc failed");
+            let b = AllocatedNum::alloc(cs.namespace(|| "b"), || Ok(*b_val)).expect("alloc failed");
+
+            let (a_fits, a_low) =
+                alloc_fits_in_bits(cs.namespace(|| "a fits"), &a, 64).expect("fits failed");
+            let (b_fits, b_low) =
+                alloc_fits_in_bits(cs.namespace(|| "b fits"), &b, 64).expect("fits failed");
+            let less_than = alloc_is_less_than(cs.namespace(|| "a < b"), &a_low, &b_low, 64)
+                .expect("less than failed");
+
+            let both_fit = a_fits.get_value().unwrap() && b_fits.get_value().unwrap();
+            assert_eq!(expected.is_some(), both_fit);
+            if let Some(expected) = expected {
+                assert_eq!(*expected, less_than.get_value().unwrap());
+            }
+            assert!(cs.is_satisfied());
+        }
+    }
+
+    #[test]
+    fn bits_le_range_check() {
+        let mut cs = TestConstraintSystem::<Fr>::new();
+        let x = AllocatedNum::alloc(cs.namespace(|| "x"), || Ok(Fr::from(255))).unwrap();
+        alloc_bits_le(cs.namespace(|| "8 bits"), &x, 8).unwrap();
+        assert!(cs.is_satisfied());
+
+        let mut cs = TestConstraintSystem::<Fr>::new();
+        let x = AllocatedNum::alloc(cs.namespace(|| "x"), || Ok(Fr::from(256))).unwrap();
+        alloc_bits_le(cs.namespace(|| "8 bits"), &x, 8).unwrap();
+        assert!(!cs.is_satisfied());
+    }
+
+    #[test]
+    fn low_bits_and_div_rem() {
+        let mut cs = TestConstraintSystem::<Fr>::new();
+        let x = AllocatedNum::alloc(
+            cs.namespace(|| "x"),
+            || Ok(Fr::from(u64::MAX) * Fr::from(3)),
+        )
+        .unwrap();
+        let low = alloc_low_bits(cs.namespace(|| "low"), &x, 64, 128).unwrap();
+        assert_eq!(Fr::from(u64::MAX - 2), low.get_value().unwrap());
+        assert!(cs.is_satisfied());
+
+        let cases = [
+            (7, 2, 3, 1),
+            (6, 3, 2, 0),
+            (2, 7, 0, 2),
+            (u64::MAX, 1, u64::MAX, 0),
+        ];
+
+        for (a_val, b_val, q_val, r_val) in cases.iter() {
+            let mut cs = TestConstraintSystem::<Fr>::new();
+            let a = AllocatedNum::alloc(cs.namespace(|| "a"), || Ok(Fr::from(*a_val))).unwrap();
+            let b = AllocatedNum::alloc(cs.namespace(|| "b"), || Ok(Fr::from(*b_val))).unwrap();
+
+            let (q, r) = alloc_div_rem(cs.namespace(|| "div rem"), &a, &b, 64).unwrap();
+
+            assert_eq!(Fr::from(*q_val), q.get_value().unwrap());
+            assert_eq!(Fr::from(*r_val), r.get_value().unwrap());
+            assert!(cs.is_satisfied());
+        }
+    }
 }
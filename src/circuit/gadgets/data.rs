@@ -6,7 +6,9 @@ use neptune::circuit2::poseidon_hash_allocated as poseidon_hash;
 
 use super::pointer::AsAllocatedHashComponents;
 use crate::field::LurkField;
-use crate::store::{ContPtr, ContTag, Expression, Op1, Op2, Pointer, Ptr, Rel2, Store, Tag, Thunk};
+use crate::store::{
+    ContPtr, ContTag, ErrorCode, Expression, Op1, Op2, Pointer, Ptr, Rel2, Store, Tag, Thunk,
+};
 use crate::store::{IntoHashComponents, ScalarPtr};
 use crate::store::{ScalarContPtr, ScalarPointer};
 
@@ -17,6 +19,7 @@ pub struct GlobalAllocations<F: LurkField> {
     pub outermost_ptr: AllocatedContPtr<F>,
     pub error_ptr_cont: AllocatedContPtr<F>,
     pub error_ptr: AllocatedPtr<F>,
+    pub fail_ptr: AllocatedContPtr<F>,
     pub dummy_ptr: AllocatedContPtr<F>,
     pub nil_ptr: AllocatedPtr<F>,
     pub t_ptr: AllocatedPtr<F>,
@@ -30,6 +33,7 @@ pub struct GlobalAllocations<F: LurkField> {
     pub str_tag: AllocatedNum<F>,
     pub num_tag: AllocatedNum<F>,
     pub comm_tag: AllocatedNum<F>,
+    pub u64_tag: AllocatedNum<F>,
     pub fun_tag: AllocatedNum<F>,
     pub let_cont_tag: AllocatedNum<F>,
     pub letrec_cont_tag: AllocatedNum<F>,
@@ -41,6 +45,13 @@ pub struct GlobalAllocations<F: LurkField> {
     pub call2_cont_tag: AllocatedNum<F>,
     pub unop_cont_tag: AllocatedNum<F>,
     pub emit_cont_tag: AllocatedNum<F>,
+    pub and_cont_tag: AllocatedNum<F>,
+    pub or_cont_tag: AllocatedNum<F>,
+    pub eval_cont_tag: AllocatedNum<F>,
+    pub fail_cont_tag: AllocatedNum<F>,
+    pub apply_cont_tag: AllocatedNum<F>,
+    pub catch_cont_tag: AllocatedNum<F>,
+    pub throw_cont_tag: AllocatedNum<F>,
     pub binop_cont_tag: AllocatedNum<F>,
     pub relop_cont_tag: AllocatedNum<F>,
     pub binop2_cont_tag: AllocatedNum<F>,
@@ -57,6 +68,7 @@ pub struct GlobalAllocations<F: LurkField> {
     pub op1_secret_tag: AllocatedNum<F>,
     pub op1_atom_tag: AllocatedNum<F>,
     pub op1_emit_tag: AllocatedNum<F>,
+    pub op1_u64_tag: AllocatedNum<F>,
     pub op2_cons_tag: AllocatedNum<F>,
     pub op2_hide_tag: AllocatedNum<F>,
     pub op2_begin_tag: AllocatedNum<F>,
@@ -64,8 +76,20 @@ pub struct GlobalAllocations<F: LurkField> {
     pub op2_diff_tag: AllocatedNum<F>,
     pub op2_product_tag: AllocatedNum<F>,
     pub op2_quotient_tag: AllocatedNum<F>,
+    pub op2_modulo_tag: AllocatedNum<F>,
+    pub op2_int_quotient_tag: AllocatedNum<F>,
+    pub op2_strcons_tag: AllocatedNum<F>,
     pub rel2_equal_tag: AllocatedNum<F>,
     pub rel2_numequal_tag: AllocatedNum<F>,
+    pub rel2_less_tag: AllocatedNum<F>,
+    pub rel2_greater_tag: AllocatedNum<F>,
+    pub rel2_lessequal_tag: AllocatedNum<F>,
+    pub rel2_greaterequal_tag: AllocatedNum<F>,
+    pub error_unbound_variable_code: AllocatedNum<F>,
+    pub error_invalid_form_code: AllocatedNum<F>,
+    pub error_invalid_call_code: AllocatedNum<F>,
+    pub error_invalid_argument_code: AllocatedNum<F>,
+    pub error_user_failure_code: AllocatedNum<F>,
 
     pub true_num: AllocatedNum<F>,
     pub false_num: AllocatedNum<F>,
@@ -97,6 +121,12 @@ impl<F: LurkField> GlobalAllocations<F> {
         let error_ptr =
             AllocatedPtr::from_parts(error_ptr_cont.tag().clone(), error_ptr_cont.hash().clone());
 
+        let fail_ptr = AllocatedContPtr::alloc_constant_cont_ptr(
+            &mut cs.namespace(|| "fail continuation"),
+            store,
+            &store.get_cont_fail(),
+        )?;
+
         let dummy_ptr = AllocatedContPtr::alloc_constant_cont_ptr(
             &mut cs.namespace(|| "dummy continuation"),
             store,
@@ -125,6 +155,7 @@ impl<F: LurkField> GlobalAllocations<F> {
         let str_tag = Tag::Str.allocate_constant(&mut cs.namespace(|| "str_tag"))?;
         let num_tag = Tag::Num.allocate_constant(&mut cs.namespace(|| "num_tag"))?;
         let comm_tag = Tag::Comm.allocate_constant(&mut cs.namespace(|| "comm_tag"))?;
+        let u64_tag = Tag::U64.allocate_constant(&mut cs.namespace(|| "u64_tag"))?;
         let fun_tag = Tag::Fun.allocate_constant(&mut cs.namespace(|| "fun_tag"))?;
 
         let outermost_cont_tag =
@@ -146,6 +177,18 @@ impl<F: LurkField> GlobalAllocations<F> {
             ContTag::Unop.allocate_constant(&mut cs.namespace(|| "unop_cont_tag"))?;
         let emit_cont_tag =
             ContTag::Emit.allocate_constant(&mut cs.namespace(|| "emit_cont_tag"))?;
+        let and_cont_tag = ContTag::And.allocate_constant(&mut cs.namespace(|| "and_cont_tag"))?;
+        let or_cont_tag = ContTag::Or.allocate_constant(&mut cs.namespace(|| "or_cont_tag"))?;
+        let eval_cont_tag =
+            ContTag::Eval.allocate_constant(&mut cs.namespace(|| "eval_cont_tag"))?;
+        let fail_cont_tag =
+            ContTag::Fail.allocate_constant(&mut cs.namespace(|| "fail_cont_tag"))?;
+        let apply_cont_tag =
+            ContTag::Apply.allocate_constant(&mut cs.namespace(|| "apply_cont_tag"))?;
+        let catch_cont_tag =
+            ContTag::Catch.allocate_constant(&mut cs.namespace(|| "catch_cont_tag"))?;
+        let throw_cont_tag =
+            ContTag::Throw.allocate_constant(&mut cs.namespace(|| "throw_cont_tag"))?;
         let binop_cont_tag =
             ContTag::Binop.allocate_constant(&mut cs.namespace(|| "binop_cont_tag"))?;
         let relop_cont_tag =
@@ -168,6 +211,7 @@ impl<F: LurkField> GlobalAllocations<F> {
             Op1::Secret.allocate_constant(&mut cs.namespace(|| "op1_secret_tag"))?;
         let op1_atom_tag = Op1::Atom.allocate_constant(&mut cs.namespace(|| "op1_atom_tag"))?;
         let op1_emit_tag = Op1::Emit.allocate_constant(&mut cs.namespace(|| "op1_emit_tag"))?;
+        let op1_u64_tag = Op1::U64.allocate_constant(&mut cs.namespace(|| "op1_u64_tag"))?;
         let op2_cons_tag = Op2::Cons.allocate_constant(&mut cs.namespace(|| "op2_cons_tag"))?;
         let op2_hide_tag = Op2::Hide.allocate_constant(&mut cs.namespace(|| "op2_hide_tag"))?;
         let op2_begin_tag = Op2::Begin.allocate_constant(&mut cs.namespace(|| "op2_begin_tag"))?;
@@ -178,6 +222,12 @@ impl<F: LurkField> GlobalAllocations<F> {
             Op2::Product.allocate_constant(&mut cs.namespace(|| "op2_product_tag"))?;
         let op2_quotient_tag =
             Op2::Quotient.allocate_constant(&mut cs.namespace(|| "op2_quotient_tag"))?;
+        let op2_modulo_tag =
+            Op2::Modulo.allocate_constant(&mut cs.namespace(|| "op2_modulo_tag"))?;
+        let op2_int_quotient_tag =
+            Op2::IntQuotient.allocate_constant(&mut cs.namespace(|| "op2_int_quotient_tag"))?;
+        let op2_strcons_tag =
+            Op2::StrCons.allocate_constant(&mut cs.namespace(|| "op2_strcons_tag"))?;
         let rel2_numequal_tag =
             AllocatedNum::alloc(&mut cs.namespace(|| "relop2_numequal_tag"), || {
                 Ok(Rel2::NumEqual.as_field())
@@ -185,6 +235,23 @@ impl<F: LurkField> GlobalAllocations<F> {
         let rel2_equal_tag = AllocatedNum::alloc(&mut cs.namespace(|| "relop2_equal_tag"), || {
             Ok(Rel2::Equal.as_field())
         })?;
+        let rel2_less_tag = Rel2::Less.allocate_constant(&mut cs.namespace(|| "rel2_less_tag"))?;
+        let rel2_greater_tag =
+            Rel2::Greater.allocate_constant(&mut cs.namespace(|| "rel2_greater_tag"))?;
+        let rel2_lessequal_tag =
+            Rel2::LessEqual.allocate_constant(&mut cs.namespace(|| "rel2_lessequal_tag"))?;
+        let rel2_greaterequal_tag =
+            Rel2::GreaterEqual.allocate_constant(&mut cs.namespace(|| "rel2_greaterequal_tag"))?;
+        let error_unbound_variable_code = ErrorCode::UnboundVariable
+            .allocate_constant(&mut cs.namespace(|| "error_unbound_variable_code"))?;
+        let error_invalid_form_code = ErrorCode::InvalidForm
+            .allocate_constant(&mut cs.namespace(|| "error_invalid_form_code"))?;
+        let error_invalid_call_code = ErrorCode::InvalidCall
+            .allocate_constant(&mut cs.namespace(|| "error_invalid_call_code"))?;
+        let error_invalid_argument_code = ErrorCode::InvalidArgument
+            .allocate_constant(&mut cs.namespace(|| "error_invalid_argument_code"))?;
+        let error_user_failure_code = ErrorCode::UserFailure
+            .allocate_constant(&mut cs.namespace(|| "error_user_failure_code"))?;
 
         let true_num = allocate_constant(&mut cs.namespace(|| "true"), F::one())?;
         let false_num = allocate_constant(&mut cs.namespace(|| "false"), F::zero())?;
@@ -195,6 +262,7 @@ impl<F: LurkField> GlobalAllocations<F> {
             outermost_ptr,
             error_ptr_cont,
             error_ptr,
+            fail_ptr,
             dummy_ptr,
             nil_ptr,
             t_ptr,
@@ -207,6 +275,7 @@ impl<F: LurkField> GlobalAllocations<F> {
             str_tag,
             num_tag,
             comm_tag,
+            u64_tag,
             fun_tag,
             outermost_cont_tag,
             lookup_cont_tag,
@@ -218,6 +287,13 @@ impl<F: LurkField> GlobalAllocations<F> {
             call2_cont_tag,
             unop_cont_tag,
             emit_cont_tag,
+            and_cont_tag,
+            or_cont_tag,
+            eval_cont_tag,
+            fail_cont_tag,
+            apply_cont_tag,
+            catch_cont_tag,
+            throw_cont_tag,
             binop_cont_tag,
             relop_cont_tag,
             binop2_cont_tag,
@@ -233,6 +309,7 @@ impl<F: LurkField> GlobalAllocations<F> {
             op1_secret_tag,
             op1_atom_tag,
             op1_emit_tag,
+            op1_u64_tag,
             op2_cons_tag,
             op2_hide_tag,
             op2_begin_tag,
@@ -240,8 +317,20 @@ impl<F: LurkField> GlobalAllocations<F> {
             op2_diff_tag,
             op2_product_tag,
             op2_quotient_tag,
+            op2_modulo_tag,
+            op2_int_quotient_tag,
+            op2_strcons_tag,
             rel2_equal_tag,
             rel2_numequal_tag,
+            rel2_less_tag,
+            rel2_greater_tag,
+            rel2_lessequal_tag,
+            rel2_greaterequal_tag,
+            error_unbound_variable_code,
+            error_invalid_form_code,
+            error_invalid_call_code,
+            error_invalid_argument_code,
+            error_user_failure_code,
             true_num,
             false_num,
             default_num,
@@ -487,6 +576,18 @@ impl Rel2 {
     }
 }
 
+impl ErrorCode {
+    pub fn allocate_constant<F: LurkField, CS: ConstraintSystem<F>>(
+        &self,
+        cs: &mut CS,
+    ) -> Result<AllocatedNum<F>, SynthesisError> {
+        allocate_constant(
+            &mut cs.namespace(|| format!("{:?} code", self)),
+            self.as_field(),
+        )
+    }
+}
+
 impl<F: LurkField> Thunk<F> {
     pub fn allocate_maybe_dummy_components<CS: ConstraintSystem<F>>(
         cs: CS,
@@ -49,3 +49,15 @@ impl<F: LurkField> ToInputs<F> for IO<F> {
         6
     }
 }
+
+/// Returns the public inputs of one end of a proof: those of `io`, followed by the running hash of
+/// the values emitted before it, as `Store::emitted_hash` computes it.
+pub fn public_inputs_with_emitted<F: LurkField>(
+    io: &IO<F>,
+    emitted_hash: F,
+    store: &Store<F>,
+) -> Vec<F> {
+    let mut inputs = io.to_inputs(store);
+    inputs.push(emitted_hash);
+    inputs
+}
@@ -1,12 +1,12 @@
 use crate::field::LurkField;
 use crate::store::{
-    ContPtr, ContTag, Continuation, Expression, Op1, Op2, Pointer, Ptr, Rel2, ScalarPointer, Store,
-    Tag, Thunk,
+    ContPtr, ContTag, Continuation, ErrorCode, Expression, Op1, Op2, Pointer, Ptr, Rel2,
+    ScalarPointer, Store, Tag, Thunk,
 };
 use crate::writer::Write;
 use log::info;
 use serde::{Deserialize, Serialize};
-use std::cmp::PartialEq;
+use std::cmp::{Ordering, PartialEq};
 use std::iter::{Iterator, Take};
 
 #[derive(Clone, Debug, PartialEq, Copy, Eq)]
@@ -80,6 +80,8 @@ impl Status {
     pub fn to_cont<F: LurkField>(&self, s: &mut Store<F>) -> Option<ContPtr<F>> {
         match self {
             Self::Terminal => Some(s.intern_cont_terminal()),
+            // The unclassified error. Use Store::intern_cont_error_with for an error continuation
+            // recording its code and offending expression.
             Self::Error => Some(s.intern_cont_error()),
             Self::Incomplete => None,
         }
@@ -124,7 +126,19 @@ impl<F: LurkField, W: Copy> Frame<IO<F>, W> {
 }
 
 pub trait Evaluable<F: LurkField, W> {
-    fn reduce(&self, store: &mut Store<F>) -> (Self, W)
+    fn reduce(&self, store: &mut Store<F>) -> Result<(Self, W), ReductionError>
+    where
+        Self: Sized,
+    {
+        self.reduce_with_oracle(store, None)
+    }
+
+    /// Like `reduce`, but reads the values of `witness` and `read` forms from `oracle`.
+    fn reduce_with_oracle(
+        &self,
+        store: &mut Store<F>,
+        oracle: Option<&mut dyn Oracle<F>>,
+    ) -> Result<(Self, W), ReductionError>
     where
         Self: Sized;
 
@@ -137,9 +151,13 @@ pub trait Evaluable<F: LurkField, W> {
 }
 
 impl<F: LurkField> Evaluable<F, Witness<F>> for IO<F> {
-    fn reduce(&self, store: &mut Store<F>) -> (Self, Witness<F>) {
-        let (expr, env, cont, witness) = reduce(self.expr, self.env, self.cont, store);
-        (Self { expr, env, cont }, witness)
+    fn reduce_with_oracle(
+        &self,
+        store: &mut Store<F>,
+        oracle: Option<&mut dyn Oracle<F>>,
+    ) -> Result<(Self, Witness<F>), ReductionError> {
+        let (expr, env, cont, witness) = reduce(self.expr, self.env, self.cont, store, oracle)?;
+        Ok((Self { expr, env, cont }, witness))
     }
 
     fn status(&self) -> Status {
@@ -196,95 +214,144 @@ impl<F: LurkField> IO<F> {
 }
 
 impl<F: LurkField, T: Evaluable<F, Witness<F>> + Clone + PartialEq + Copy> Frame<T, Witness<F>> {
-    pub(crate) fn next(&self, store: &mut Store<F>) -> Self {
+    pub(crate) fn next(
+        &self,
+        store: &mut Store<F>,
+        oracle: Option<&mut dyn Oracle<F>>,
+    ) -> Result<Self, ReductionError> {
         let input = self.output;
-        let (output, witness) = input.reduce(store);
+        let (output, witness) = input.reduce_with_oracle(store, oracle)?;
 
         // FIXME: Why isn't this method found?
         // self.log(store);
         self.output.log(store, self.i + 1);
-        Self {
+        Ok(Self {
             input,
             output,
             i: self.i + 1,
             witness,
-        }
+        })
     }
 }
 
 impl<F: LurkField, T: Evaluable<F, Witness<F>> + Clone + PartialEq + Copy> Frame<T, Witness<F>> {
-    fn from_initial_input(input: T, store: &mut Store<F>) -> Self {
+    fn from_initial_input(
+        input: T,
+        store: &mut Store<F>,
+        oracle: Option<&mut dyn Oracle<F>>,
+    ) -> Result<Self, ReductionError> {
         input.log(store, 0);
-        let (output, witness) = input.reduce(store);
+        let (output, witness) = input.reduce_with_oracle(store, oracle)?;
 
-        Self {
+        Ok(Self {
             input,
             output,
             i: 0,
             witness,
-        }
+        })
     }
 }
 
 pub struct FrameIt<'a, W: Copy, F: LurkField> {
-    first: bool,
-    frame: Frame<IO<F>, W>,
+    initial_input: IO<F>,
+    // The last frame, or `None` before the initial input is reduced.
+    frame: Option<Frame<IO<F>, W>>,
+    // Whether a reduction has failed, after which there are no more frames.
+    failed: bool,
     store: &'a mut Store<F>,
+    oracle: Option<&'a mut dyn Oracle<F>>,
+    observer: Option<&'a mut dyn EmitObserver<F>>,
 }
 
 impl<'a, 'b, F: LurkField> FrameIt<'a, Witness<F>, F> {
-    fn new(initial_input: IO<F>, store: &'a mut Store<F>) -> Self {
-        let frame = Frame::from_initial_input(initial_input, store);
+    fn new(
+        initial_input: IO<F>,
+        store: &'a mut Store<F>,
+        oracle: Option<&'a mut dyn Oracle<F>>,
+        observer: Option<&'a mut dyn EmitObserver<F>>,
+    ) -> Self {
         Self {
-            first: true,
-            frame,
+            initial_input,
+            frame: None,
+            failed: false,
             store,
+            oracle,
+            observer,
+        }
+    }
+
+    // Tells the observer about the value emitted by `frame`, if any, and returns it.
+    fn observe(&mut self, frame: &Frame<IO<F>, Witness<F>>) -> Option<Ptr<F>> {
+        let emitted = frame.output.maybe_emitted_expression(self.store)?;
+        if let Some(observer) = &mut self.observer {
+            observer.emitted(self.store, emitted, frame.i);
+        }
+        Some(emitted)
+    }
+
+    // Reduces the initial input, or the output of the last frame, and returns the new frame with
+    // the value it emitted, if any. Returns `None` after a complete frame or a failed reduction.
+    #[allow(clippy::type_complexity)]
+    fn step(
+        &mut self,
+    ) -> Option<Result<(Frame<IO<F>, Witness<F>>, Option<Ptr<F>>), ReductionError>> {
+        if self.failed {
+            return None;
+        }
+        let oracle = reborrow_oracle(&mut self.oracle);
+        let next = match &self.frame {
+            None => Frame::from_initial_input(self.initial_input, self.store, oracle),
+            Some(frame) if frame.is_complete() => return None,
+            Some(frame) => frame.next(self.store, oracle),
+        };
+        match next {
+            Ok(frame) => {
+                // The initial frame is not observed.
+                let emitted = if self.frame.is_some() {
+                    self.observe(&frame)
+                } else {
+                    None
+                };
+                self.frame = Some(frame.clone());
+                Some(Ok((frame, emitted)))
+            }
+            Err(e) => {
+                self.failed = true;
+                Some(Err(e))
+            }
         }
     }
 
     /// Like `.iter().take(n).last()`, but skips intermediary stages, to optimize
-    /// for evaluation.
+    /// for evaluation. Also returns the values emitted, and why the output of the last frame
+    /// could not be reduced, if it could not. There is no last frame if the initial input could
+    /// not be reduced.
+    #[allow(clippy::type_complexity)]
     fn next_n(
         mut self,
         n: usize,
-    ) -> Option<(
-        Frame<IO<F>, Witness<F>>,
-        Frame<IO<F>, Witness<F>>,
+    ) -> (
+        Option<Frame<IO<F>, Witness<F>>>,
         Vec<Ptr<F>>,
-    )> {
-        let mut previous_frame = self.frame.clone();
+        Option<ReductionError>,
+    ) {
         let mut emitted: Vec<Ptr<F>> = Vec::new();
         for _ in 0..n {
-            if self.frame.is_complete() {
-                break;
-            }
-            let new_frame = self.frame.next(self.store);
-
-            if let Some(expr) = new_frame.output.maybe_emitted_expression(self.store) {
-                emitted.push(expr);
+            match self.step() {
+                Some(Ok((_, Some(expr)))) => emitted.push(expr),
+                Some(Ok((_, None))) => (),
+                Some(Err(e)) => return (self.frame, emitted, Some(e)),
+                None => break,
             }
-            previous_frame = std::mem::replace(&mut self.frame, new_frame);
         }
-        Some((self.frame, previous_frame, emitted))
+        (self.frame, emitted, None)
     }
 }
 
 impl<'a, 'b, F: LurkField> Iterator for FrameIt<'a, Witness<F>, F> {
-    type Item = Frame<IO<F>, Witness<F>>;
+    type Item = Result<Frame<IO<F>, Witness<F>>, ReductionError>;
     fn next(&mut self) -> Option<<Self as Iterator>::Item> {
-        // skip first iteration, as one evaluation happens on construction
-        if self.first {
-            self.first = false;
-            return Some(self.frame.clone());
-        }
-
-        if self.frame.is_complete() {
-            return None;
-        }
-
-        self.frame = self.frame.next(self.store);
-
-        Some(self.frame.clone())
+        Some(self.step()?.map(|(frame, _)| frame))
     }
 }
 
@@ -298,6 +365,60 @@ pub struct Witness<F: LurkField> {
 
     pub(crate) extended_closure: Option<Ptr<F>>,
     pub(crate) apply_continuation_cont: Option<ContPtr<F>>,
+    // The value read from the oracle by a WITNESS or READ form, if it had one.
+    pub(crate) oracle_value: Option<Ptr<F>>,
+}
+
+/// A host-side source of private values, which `(witness NAME)` and `(read)` forms evaluate to.
+///
+/// The values are chosen by whoever evaluates an expression, not by the expression itself, and are
+/// private witnesses of a proof of that evaluation: the only thing a verifier learns about them is
+/// what the result reveals. Returning `None` means there is no value for `name`, and evaluating
+/// the form is an error.
+pub trait Oracle<F: LurkField> {
+    /// Returns the value named by `name`, which is the unevaluated argument of a `witness` form,
+    /// or `nil` for a `read` form.
+    fn read(&mut self, store: &mut Store<F>, name: Ptr<F>) -> Option<Ptr<F>>;
+}
+
+impl<F: LurkField, T: FnMut(&mut Store<F>, Ptr<F>) -> Option<Ptr<F>>> Oracle<F> for T {
+    fn read(&mut self, store: &mut Store<F>, name: Ptr<F>) -> Option<Ptr<F>> {
+        self(store, name)
+    }
+}
+
+/// A host-side observer of the values emitted during evaluation, such as one streaming them while
+/// a long computation runs.
+pub trait EmitObserver<F: LurkField> {
+    /// Called with each emitted value, as soon as it is emitted, and the iteration which emitted
+    /// it. This is the index of the frame whose output is the value's `Emit` thunk.
+    fn emitted(&mut self, store: &Store<F>, value: Ptr<F>, iteration: usize);
+}
+
+impl<F: LurkField, T: FnMut(&Store<F>, Ptr<F>, usize)> EmitObserver<F> for T {
+    fn emitted(&mut self, store: &Store<F>, value: Ptr<F>, iteration: usize) {
+        self(store, value, iteration)
+    }
+}
+
+// Reborrows an optional oracle for one reduction.
+fn reborrow_oracle<'b, F: LurkField>(
+    oracle: &'b mut Option<&mut dyn Oracle<F>>,
+) -> Option<&'b mut dyn Oracle<F>> {
+    match oracle {
+        Some(oracle) => Some(&mut **oracle),
+        None => None,
+    }
+}
+
+// Reborrows an optional observer for one evaluation.
+fn reborrow_observer<'b, F: LurkField>(
+    observer: &'b mut Option<&mut dyn EmitObserver<F>>,
+) -> Option<&'b mut dyn EmitObserver<F>> {
+    match observer {
+        Some(observer) => Some(&mut **observer),
+        None => None,
+    }
 }
 
 fn reduce<F: LurkField>(
@@ -305,11 +426,38 @@ fn reduce<F: LurkField>(
     env: Ptr<F>,
     cont: ContPtr<F>,
     store: &mut Store<F>,
-) -> (Ptr<F>, Ptr<F>, ContPtr<F>, Witness<F>) {
-    let (ctrl, witness) = reduce_with_witness(expr, env, cont, store);
+    oracle: Option<&mut dyn Oracle<F>>,
+) -> Result<(Ptr<F>, Ptr<F>, ContPtr<F>, Witness<F>), ReductionError> {
+    let (ctrl, witness) = reduce_with_witness(expr, env, cont, store, oracle)?;
     let (new_expr, new_env, new_cont) = ctrl.into_results();
 
-    (new_expr, new_env, new_cont, witness)
+    Ok((new_expr, new_env, new_cont, witness))
+}
+
+/// Why an expression or value cannot be reduced. Reduction never panics on these. Most become
+/// error continuations, like any other malformed form, but reducing opaque or missing data fails
+/// with its error: the circuit has no witness for their contents, so it cannot prove even that
+/// such a reduction is an error.
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+pub enum ReductionError {
+    /// A list was expected, e.g. in a form or an environment.
+    NotAList,
+    /// The value is opaque, so its contents are unknown.
+    Opaque,
+    /// An environment is neither a simple nor a recursive one.
+    MalformedEnv,
+    /// The value is missing from the store.
+    Missing,
+    /// A dummy continuation, which only ever follows a thunk, is applied to a value.
+    DummyContinuation,
+}
+
+impl ReductionError {
+    /// Whether the circuit can prove that reduction ends in an error continuation, rather than
+    /// failing with this error.
+    pub fn is_provable(&self) -> bool {
+        !matches!(self, Self::Opaque | Self::Missing)
+    }
 }
 
 #[derive(Debug, Clone)]
@@ -352,20 +500,68 @@ fn reduce_with_witness<F: LurkField>(
     env: Ptr<F>,
     cont: ContPtr<F>,
     store: &mut Store<F>,
-) -> (Control<F>, Witness<F>) {
+    oracle: Option<&mut dyn Oracle<F>>,
+) -> Result<(Control<F>, Witness<F>), ReductionError> {
     let mut extended_closure = None;
-    let control = if cont.tag() == ContTag::Terminal {
+    let mut oracle_value = None;
+    // Input which cannot be reduced, such as a malformed environment, is an error like any other
+    // malformed form, unless the circuit cannot prove that it is.
+    let control = match reduce_control(
+        expr,
+        env,
+        cont,
+        store,
+        oracle,
+        &mut extended_closure,
+        &mut oracle_value,
+    ) {
+        Ok(control) => control,
+        Err(e) if e.is_provable() => Control::Return(expr, env, store.intern_cont_error()),
+        Err(e) => return Err(e),
+    };
+
+    let (new_expr, new_env, new_cont) = control.as_results();
+
+    let mut witness = Witness {
+        prethunk_output_expr: *new_expr,
+        prethunk_output_env: *new_env,
+        prethunk_output_cont: *new_cont,
+
+        extended_closure,
+        apply_continuation_cont: None,
+        oracle_value,
+    };
+
+    let control = apply_continuation(control, store, &mut witness)?;
+    let ctrl = make_thunk(control, store, &mut witness);
+    let ctrl = attribute_error(ctrl, expr, store, &witness);
+
+    Ok((ctrl, witness))
+}
+
+// Reduces EXPR in ENV with CONT, recording in EXTENDED_CLOSURE and ORACLE_VALUE what the witness
+// needs. Fails if EXPR cannot be reduced, rather than panicking.
+fn reduce_control<F: LurkField>(
+    expr: Ptr<F>,
+    env: Ptr<F>,
+    cont: ContPtr<F>,
+    store: &mut Store<F>,
+    oracle: Option<&mut dyn Oracle<F>>,
+    extended_closure: &mut Option<Ptr<F>>,
+    oracle_value: &mut Option<Ptr<F>>,
+) -> Result<Control<F>, ReductionError> {
+    let control = if matches!(cont.tag(), ContTag::Terminal | ContTag::Error) {
         Control::Return(expr, env, cont)
     } else {
         match expr.tag() {
-            Tag::Thunk => match store.fetch(&expr).unwrap() {
+            Tag::Thunk => match store.fetch(&expr).ok_or(ReductionError::Missing)? {
                 Expression::Thunk(thunk) => {
                     Control::ApplyContinuation(thunk.value, env, thunk.continuation)
                 }
-                _ => unreachable!(),
+                _ => return Err(ReductionError::Opaque),
             },
             // Self-evaluating
-            Tag::Nil | Tag::Num | Tag::Fun | Tag::Char | Tag::Str | Tag::Comm => {
+            Tag::Nil | Tag::Num | Tag::Fun | Tag::Char | Tag::Str | Tag::Comm | Tag::U64 => {
                 Control::ApplyContinuation(expr, env, cont)
             }
             Tag::Sym => {
@@ -383,7 +579,7 @@ fn reduce_with_witness<F: LurkField>(
                         //     //assert!(!env.is_nil(), "Unbound variable: {:?}", expr);
                         Control::Return(expr, env, store.intern_cont_error())
                     } else {
-                        let (binding, smaller_env) = store.car_cdr(&env);
+                        let (binding, smaller_env) = store.car_cdr_or_nil(&env)?;
                         if binding.is_nil() {
                             // If binding is NIL, it's empty. There is no match. Return an error due to unbound variable.
 
@@ -396,7 +592,8 @@ fn reduce_with_witness<F: LurkField>(
 
                             // CIRCUIT: binding_not_nil
                             //          otherwise_and_binding_not_nil
-                            let (var_or_rec_binding, val_or_more_rec_env) = store.car_cdr(&binding);
+                            let (var_or_rec_binding, val_or_more_rec_env) =
+                                store.car_cdr_or_nil(&binding)?;
                             match var_or_rec_binding.tag() {
                                 Tag::Sym => {
                                     // We are in a simple env (not a recursive env),
@@ -453,7 +650,7 @@ fn reduce_with_witness<F: LurkField>(
                                     let rec_env = binding;
                                     let smaller_rec_env = val_or_more_rec_env;
 
-                                    let (v2, val2) = store.car_cdr(&var_or_rec_binding);
+                                    let (v2, val2) = store.car_cdr_or_nil(&var_or_rec_binding)?;
                                     if v2 == expr {
                                         // CIRCUIT: v2_is_expr
                                         //          v2_is_expr_real
@@ -463,16 +660,16 @@ fn reduce_with_witness<F: LurkField>(
                                             match val2.tag() {
                                                 Tag::Fun => {
                                                     // TODO: This is a misnomer. It's actually the closure *to be extended*.
-                                                    extended_closure = Some(val2);
+                                                    *extended_closure = Some(val2);
                                                     // CIRCUIT: val2_is_fun
 
                                                     // We just found a closure in a recursive env.
                                                     // We need to extend its environment to include that recursive env.
 
-                                                    extend_closure(&val2, &rec_env, store)
+                                                    extend_closure(&val2, &rec_env, store)?
                                                 }
                                                 _ => {
-                                                    extended_closure = None;
+                                                    *extended_closure = None;
                                                     val2
                                                 }
                                             }
@@ -506,7 +703,7 @@ fn reduce_with_witness<F: LurkField>(
                                         }
                                     }
                                 }
-                                _ => panic!("Bad form."),
+                                _ => Control::Return(expr, env, store.intern_cont_error()),
                             }
                         }
                     }
@@ -514,23 +711,35 @@ fn reduce_with_witness<F: LurkField>(
             }
             Tag::Cons => {
                 // This should not fail, since expr is a Cons.
-                let (head, rest) = store.car_cdr(&expr);
+                let (head, rest) = store.car_cdr_or_nil(&expr)?;
                 let lambda = store.sym("lambda");
                 let quote = store.sym("quote");
                 let dummy_arg = store.sym("_");
 
                 if head == lambda {
-                    let (args, body) = store.car_cdr(&rest);
-                    let (arg, _rest) = if args.is_nil() {
+                    let (args, body) = store.car_cdr_or_nil(&rest)?;
+                    let (arg, cdr_args) = if args.is_nil() {
                         // (LAMBDA () STUFF)
                         // becomes (LAMBDA (DUMMY) STUFF)
                         (dummy_arg, store.nil())
+                    } else if args.tag() == Tag::Sym {
+                        // (LAMBDA REST STUFF)
+                        // is variadic, and so is the innermost function of (LAMBDA (A . REST) STUFF).
+                        // Its argument is the list (REST).
+                        (store.list(&[args]), store.nil())
                     } else {
-                        store.car_cdr(&args)
+                        store.car_cdr_or_nil(&args)?
                     };
-                    let cdr_args = store.cdr(&args);
                     let inner_body = if cdr_args.is_nil() {
-                        body
+                        if store.car_cdr_or_nil(&body)?.1.is_nil() {
+                            body
+                        } else {
+                            // (LAMBDA (A) STUFF MORE-STUFF)
+                            // becomes (LAMBDA (A) (BEGIN STUFF MORE-STUFF))
+                            let begin = store.sym("begin");
+                            let implicit_begin = store.cons(begin, body);
+                            store.list(&[implicit_begin])
+                        }
                     } else {
                         // (LAMBDA (A B) STUFF)
                         // becomes (LAMBDA (A) (LAMBDA (B) STUFF))
@@ -542,24 +751,30 @@ fn reduce_with_witness<F: LurkField>(
 
                     Control::ApplyContinuation(function, env, cont)
                 } else if head == quote {
-                    let (quoted, end) = store.car_cdr(&rest);
+                    let (quoted, end) = store.car_cdr_or_nil(&rest)?;
                     if !end.is_nil() {
                         Control::Return(expr, env, store.intern_cont_error())
                     } else {
                         Control::ApplyContinuation(quoted, env, cont)
                     }
                 } else if head == store.sym("let") {
-                    let (bindings, body) = store.car_cdr(&rest);
-                    let (body1, rest_body) = store.car_cdr(&body);
-                    // Only a single body form allowed for now.
-                    if !rest_body.is_nil() || body.is_nil() {
+                    let (bindings, body) = store.car_cdr_or_nil(&rest)?;
+                    let (body1, rest_body) = store.car_cdr_or_nil(&body)?;
+                    // Several body forms are evaluated in order, as if wrapped in BEGIN.
+                    let body1 = if rest_body.is_nil() {
+                        body1
+                    } else {
+                        let begin = store.sym("begin");
+                        store.cons(begin, body)
+                    };
+                    if body.is_nil() {
                         Control::Return(expr, env, store.intern_cont_error())
                     } else if bindings.is_nil() {
                         Control::Return(body1, env, cont)
                     } else {
-                        let (binding1, rest_bindings) = store.car_cdr(&bindings);
-                        let (var, vals) = store.car_cdr(&binding1);
-                        let (val, end) = store.car_cdr(&vals);
+                        let (binding1, rest_bindings) = store.car_cdr_or_nil(&bindings)?;
+                        let (var, vals) = store.car_cdr_or_nil(&binding1)?;
+                        let (val, end) = store.car_cdr_or_nil(&vals)?;
                         if !end.is_nil() {
                             Control::Return(expr, env, store.intern_cont_error())
                         } else {
@@ -577,17 +792,23 @@ fn reduce_with_witness<F: LurkField>(
                         }
                     }
                 } else if head == store.sym("letrec") {
-                    let (bindings, body) = store.car_cdr(&rest);
-                    let (body1, rest_body) = store.car_cdr(&body);
-                    // Only a single body form allowed for now.
-                    if !rest_body.is_nil() || body.is_nil() {
+                    let (bindings, body) = store.car_cdr_or_nil(&rest)?;
+                    let (body1, rest_body) = store.car_cdr_or_nil(&body)?;
+                    // Several body forms are evaluated in order, as if wrapped in BEGIN.
+                    let body1 = if rest_body.is_nil() {
+                        body1
+                    } else {
+                        let begin = store.sym("begin");
+                        store.cons(begin, body)
+                    };
+                    if body.is_nil() {
                         Control::Return(expr, env, store.intern_cont_error())
                     } else if bindings.is_nil() {
                         Control::Return(body1, env, cont)
                     } else {
-                        let (binding1, rest_bindings) = store.car_cdr(&bindings);
-                        let (var, vals) = store.car_cdr(&binding1);
-                        let (val, end) = store.car_cdr(&vals);
+                        let (binding1, rest_bindings) = store.car_cdr_or_nil(&bindings)?;
+                        let (var, vals) = store.car_cdr_or_nil(&binding1)?;
+                        let (val, end) = store.car_cdr_or_nil(&vals)?;
                         if !end.is_nil() {
                             Control::Return(expr, env, store.intern_cont_error())
                         } else {
@@ -605,7 +826,7 @@ fn reduce_with_witness<F: LurkField>(
                         }
                     }
                 } else if head == store.sym("cons") {
-                    let (arg1, more) = store.car_cdr(&rest);
+                    let (arg1, more) = store.car_cdr_or_nil(&rest)?;
                     if more.is_nil() {
                         Control::Return(arg1, env, store.intern_cont_error())
                     } else {
@@ -615,8 +836,19 @@ fn reduce_with_witness<F: LurkField>(
                             store.intern_cont_binop(Op2::Cons, env, more, cont),
                         )
                     }
+                } else if head == store.sym("strcons") {
+                    let (arg1, more) = store.car_cdr_or_nil(&rest)?;
+                    if more.is_nil() {
+                        Control::Return(arg1, env, store.intern_cont_error())
+                    } else {
+                        Control::Return(
+                            arg1,
+                            env,
+                            store.intern_cont_binop(Op2::StrCons, env, more, cont),
+                        )
+                    }
                 } else if head == store.sym("hide") {
-                    let (arg1, more) = store.car_cdr(&rest);
+                    let (arg1, more) = store.car_cdr_or_nil(&rest)?;
                     if more.is_nil() {
                         Control::Return(arg1, env, store.intern_cont_error())
                     } else {
@@ -627,7 +859,7 @@ fn reduce_with_witness<F: LurkField>(
                         )
                     }
                 } else if head == store.sym("begin") {
-                    let (arg1, more) = store.car_cdr(&rest);
+                    let (arg1, more) = store.car_cdr_or_nil(&rest)?;
                     if more.is_nil() {
                         Control::Return(arg1, env, cont)
                     } else {
@@ -638,173 +870,367 @@ fn reduce_with_witness<F: LurkField>(
                         )
                     }
                 } else if head == store.sym("car") {
-                    let (arg1, end) = match store.car_cdr_mut(&rest) {
-                        Ok((car, cdr)) => (car, cdr),
-                        Err(e) => panic!("{}", e),
-                    };
+                    let (arg1, end) = store.car_cdr_or_nil(&rest)?;
                     if !end.is_nil() {
                         Control::Return(expr, env, store.intern_cont_error())
                     } else {
                         Control::Return(arg1, env, store.intern_cont_unop(Op1::Car, cont))
                     }
                 } else if head == store.sym("cdr") {
-                    let (arg1, end) = match store.car_cdr_mut(&rest) {
-                        Ok((car, cdr)) => (car, cdr),
-                        Err(e) => panic!("{}", e),
-                    };
+                    let (arg1, end) = store.car_cdr_or_nil(&rest)?;
                     if !end.is_nil() {
                         Control::Return(expr, env, store.intern_cont_error())
                     } else {
                         Control::Return(arg1, env, store.intern_cont_unop(Op1::Cdr, cont))
                     }
                 } else if head == store.sym("commit") {
-                    let (arg1, end) = match store.car_cdr_mut(&rest) {
-                        Ok((car, cdr)) => (car, cdr),
-                        Err(e) => panic!("{}", e),
-                    };
+                    let (arg1, end) = store.car_cdr_or_nil(&rest)?;
                     if !end.is_nil() {
                         Control::Return(expr, env, store.intern_cont_error())
                     } else {
                         Control::Return(arg1, env, store.intern_cont_unop(Op1::Commit, cont))
                     }
                 } else if head == store.sym("num") {
-                    let (arg1, end) = match store.car_cdr_mut(&rest) {
-                        Ok((car, cdr)) => (car, cdr),
-                        Err(e) => panic!("{}", e),
-                    };
+                    let (arg1, end) = store.car_cdr_or_nil(&rest)?;
                     if !end.is_nil() {
                         Control::Return(expr, env, store.intern_cont_error())
                     } else {
                         Control::Return(arg1, env, store.intern_cont_unop(Op1::Num, cont))
                     }
                 } else if head == store.sym("comm") {
-                    let (arg1, end) = match store.car_cdr_mut(&rest) {
-                        Ok((car, cdr)) => (car, cdr),
-                        Err(e) => panic!("{}", e),
-                    };
+                    let (arg1, end) = store.car_cdr_or_nil(&rest)?;
                     if !end.is_nil() {
                         Control::Return(expr, env, store.intern_cont_error())
                     } else {
                         Control::Return(arg1, env, store.intern_cont_unop(Op1::Comm, cont))
                     }
                 } else if head == store.sym("char") {
-                    let (arg1, end) = match store.car_cdr_mut(&rest) {
-                        Ok((car, cdr)) => (car, cdr),
-                        Err(e) => panic!("{}", e),
-                    };
+                    let (arg1, end) = store.car_cdr_or_nil(&rest)?;
                     if !end.is_nil() {
                         Control::Return(expr, env, store.intern_cont_error())
                     } else {
                         Control::Return(arg1, env, store.intern_cont_unop(Op1::Char, cont))
                     }
+                } else if head == store.sym("u64") {
+                    let (arg1, end) = store.car_cdr_or_nil(&rest)?;
+                    if !end.is_nil() {
+                        Control::Return(expr, env, store.intern_cont_error())
+                    } else {
+                        Control::Return(arg1, env, store.intern_cont_unop(Op1::U64, cont))
+                    }
                 } else if head == store.sym("open") {
-                    let (arg1, end) = match store.car_cdr_mut(&rest) {
-                        Ok((car, cdr)) => (car, cdr),
-                        Err(e) => panic!("{}", e),
-                    };
+                    let (arg1, end) = store.car_cdr_or_nil(&rest)?;
                     if !end.is_nil() {
                         Control::Return(expr, env, store.intern_cont_error())
                     } else {
                         Control::Return(arg1, env, store.intern_cont_unop(Op1::Open, cont))
                     }
                 } else if head == store.sym("secret") {
-                    let (arg1, end) = match store.car_cdr_mut(&rest) {
-                        Ok((car, cdr)) => (car, cdr),
-                        Err(e) => panic!("{}", e),
-                    };
+                    let (arg1, end) = store.car_cdr_or_nil(&rest)?;
                     if !end.is_nil() {
                         Control::Return(expr, env, store.intern_cont_error())
                     } else {
                         Control::Return(arg1, env, store.intern_cont_unop(Op1::Secret, cont))
                     }
                 } else if head == store.sym("atom") {
-                    let (arg1, end) = store.car_cdr(&rest);
+                    let (arg1, end) = store.car_cdr_or_nil(&rest)?;
                     if !end.is_nil() {
                         Control::Return(expr, env, store.intern_cont_error())
                     } else {
                         Control::Return(arg1, env, store.intern_cont_unop(Op1::Atom, cont))
                     }
                 } else if head == store.sym("emit") {
-                    let (arg1, end) = store.car_cdr(&rest);
+                    let (arg1, end) = store.car_cdr_or_nil(&rest)?;
                     if !end.is_nil() {
                         Control::Return(expr, env, store.intern_cont_error())
                     } else {
                         Control::Return(arg1, env, store.intern_cont_unop(Op1::Emit, cont))
                     }
+                } else if let Some(op1) = Op1::TYPE_OPS
+                    .iter()
+                    .copied()
+                    .find(|op| head == store.sym(op.type_op_name().unwrap()))
+                {
+                    // (TYPE-OF X), (NUM? X), ...
+                    let (arg1, end) = store.car_cdr_or_nil(&rest)?;
+                    if !end.is_nil() {
+                        Control::Return(expr, env, store.intern_cont_error())
+                    } else {
+                        Control::Return(arg1, env, store.intern_cont_unop(op1, cont))
+                    }
                 } else if head == store.sym("+") {
-                    let (arg1, more) = store.car_cdr(&rest);
-                    Control::Return(
-                        arg1,
-                        env,
-                        store.intern_cont_binop(Op2::Sum, env, more, cont),
-                    )
+                    if rest.is_nil() {
+                        Control::ApplyContinuation(store.num(0), env, cont)
+                    } else {
+                        // (+ x) is x, once Binop has checked it is a number.
+                        let (arg1, more) = store.car_cdr_or_nil(&rest)?;
+                        Control::Return(
+                            arg1,
+                            env,
+                            store.intern_cont_binop(Op2::Sum, env, more, cont),
+                        )
+                    }
                 } else if head == store.sym("-") {
-                    let (arg1, more) = store.car_cdr(&rest);
-                    Control::Return(
-                        arg1,
-                        env,
-                        store.intern_cont_binop(Op2::Diff, env, more, cont),
-                    )
+                    if rest.is_nil() {
+                        Control::Return(expr, env, store.intern_cont_error())
+                    } else {
+                        let (arg1, more) = store.car_cdr_or_nil(&rest)?;
+                        if more.is_nil() {
+                            // (- x) is (- 0 x).
+                            Control::Return(
+                                store.num(0),
+                                env,
+                                store.intern_cont_binop(Op2::Diff, env, rest, cont),
+                            )
+                        } else {
+                            Control::Return(
+                                arg1,
+                                env,
+                                store.intern_cont_binop(Op2::Diff, env, more, cont),
+                            )
+                        }
+                    }
                 } else if head == store.sym("*") {
-                    let (arg1, more) = store.car_cdr(&rest);
-                    Control::Return(
-                        arg1,
-                        env,
-                        store.intern_cont_binop(Op2::Product, env, more, cont),
-                    )
+                    if rest.is_nil() {
+                        Control::ApplyContinuation(store.num(1), env, cont)
+                    } else {
+                        // (* x) is x, once Binop has checked it is a number.
+                        let (arg1, more) = store.car_cdr_or_nil(&rest)?;
+                        Control::Return(
+                            arg1,
+                            env,
+                            store.intern_cont_binop(Op2::Product, env, more, cont),
+                        )
+                    }
                 } else if head == store.sym("/") {
-                    let (arg1, more) = store.car_cdr(&rest);
-                    Control::Return(
-                        arg1,
-                        env,
-                        store.intern_cont_binop(Op2::Quotient, env, more, cont),
-                    )
+                    if rest.is_nil() {
+                        Control::Return(expr, env, store.intern_cont_error())
+                    } else {
+                        let (arg1, more) = store.car_cdr_or_nil(&rest)?;
+                        if more.is_nil() {
+                            // (/ x) is (/ 1 x).
+                            Control::Return(
+                                store.num(1),
+                                env,
+                                store.intern_cont_binop(Op2::Quotient, env, rest, cont),
+                            )
+                        } else {
+                            Control::Return(
+                                arg1,
+                                env,
+                                store.intern_cont_binop(Op2::Quotient, env, more, cont),
+                            )
+                        }
+                    }
+                } else if head == store.sym("%") {
+                    // Unlike - and /, % and DIV have no identity, so they need at least two
+                    // arguments. More are folded in from the left.
+                    let (arg1, more) = store.car_cdr_or_nil(&rest)?;
+                    if more.is_nil() {
+                        Control::Return(expr, env, store.intern_cont_error())
+                    } else {
+                        Control::Return(
+                            arg1,
+                            env,
+                            store.intern_cont_binop(Op2::Modulo, env, more, cont),
+                        )
+                    }
+                } else if head == store.sym("div") {
+                    let (arg1, more) = store.car_cdr_or_nil(&rest)?;
+                    if more.is_nil() {
+                        Control::Return(expr, env, store.intern_cont_error())
+                    } else {
+                        Control::Return(
+                            arg1,
+                            env,
+                            store.intern_cont_binop(Op2::IntQuotient, env, more, cont),
+                        )
+                    }
                 } else if head == store.sym("=") {
-                    let (arg1, more) = store.car_cdr(&rest);
-                    Control::Return(
-                        arg1,
-                        env,
-                        store.intern_cont_relop(Rel2::NumEqual, env, more, cont),
-                    )
+                    reduce_relop(Rel2::NumEqual, rest, env, cont, store)?
+                } else if head == store.sym("<") {
+                    reduce_relop(Rel2::Less, rest, env, cont, store)?
+                } else if head == store.sym(">") {
+                    reduce_relop(Rel2::Greater, rest, env, cont, store)?
+                } else if head == store.sym("<=") {
+                    reduce_relop(Rel2::LessEqual, rest, env, cont, store)?
+                } else if head == store.sym(">=") {
+                    reduce_relop(Rel2::GreaterEqual, rest, env, cont, store)?
                 } else if head == store.sym("eq") {
-                    let (arg1, more) = store.car_cdr(&rest);
-                    Control::Return(
-                        arg1,
-                        env,
-                        store.intern_cont_relop(Rel2::Equal, env, more, cont),
-                    )
+                    reduce_relop(Rel2::Equal, rest, env, cont, store)?
                 } else if head == store.sym("if") {
-                    let (condition, more) = store.car_cdr(&rest);
+                    let (condition, more) = store.car_cdr_or_nil(&rest)?;
                     Control::Return(condition, env, store.intern_cont_if(more, cont))
+                } else if head == store.sym("and") {
+                    let (arg1, more) = store.car_cdr_or_nil(&rest)?;
+                    if rest.is_nil() {
+                        Control::ApplyContinuation(store.t(), env, cont)
+                    } else if more.is_nil() {
+                        Control::Return(arg1, env, cont)
+                    } else {
+                        Control::Return(arg1, env, store.intern_cont_and(more, cont))
+                    }
+                } else if head == store.sym("or") {
+                    let (arg1, more) = store.car_cdr_or_nil(&rest)?;
+                    if rest.is_nil() {
+                        Control::ApplyContinuation(store.nil(), env, cont)
+                    } else if more.is_nil() {
+                        Control::Return(arg1, env, cont)
+                    } else {
+                        Control::Return(arg1, env, store.intern_cont_or(more, cont))
+                    }
+                } else if head == store.sym("cond") {
+                    if rest.is_nil() {
+                        Control::ApplyContinuation(store.nil(), env, cont)
+                    } else {
+                        // (COND (C . BODY) . CLAUSES)
+                        // becomes (IF C (BEGIN . BODY) (COND . CLAUSES)),
+                        // and (COND (C) . CLAUSES), whose value is C's,
+                        // becomes (OR C (COND . CLAUSES)).
+                        let (clause, clauses) = store.car_cdr_or_nil(&rest)?;
+                        let (condition, body) = store.car_cdr_or_nil(&clause)?;
+                        let cond = store.sym("cond");
+                        let otherwise = store.cons(cond, clauses);
+                        if body.is_nil() {
+                            let more = store.list(&[otherwise]);
+                            Control::Return(condition, env, store.intern_cont_or(more, cont))
+                        } else {
+                            let begin = store.sym("begin");
+                            let then = store.cons(begin, body);
+                            let more = store.list(&[then, otherwise]);
+                            Control::Return(condition, env, store.intern_cont_if(more, cont))
+                        }
+                    }
                 } else if head == store.sym("current-env") {
                     if !rest.is_nil() {
-                        Control::Return(env, env, store.intern_cont_error())
+                        Control::Return(expr, env, store.intern_cont_error())
                     } else {
                         Control::ApplyContinuation(env, env, cont)
                     }
+                } else if head == store.sym("eval") {
+                    // (EVAL EXPR ENV)
+                    // evaluates (CONS EXPR ENV), then evaluates its car in its cdr.
+                    // (EVAL EXPR) evaluates EXPR in the empty environment.
+                    let (arg1, more) = store.car_cdr_or_nil(&rest)?;
+                    let (env_form, end) = store.car_cdr_or_nil(&more)?;
+                    if rest.is_nil() || !end.is_nil() {
+                        Control::Return(expr, env, store.intern_cont_error())
+                    } else {
+                        let cons = store.sym("cons");
+                        let expr_and_env = store.list(&[cons, arg1, env_form]);
+                        Control::Return(expr_and_env, env, store.intern_cont_eval(env, cont))
+                    }
+                } else if head == store.sym("fail") {
+                    // (FAIL VALUE) evaluates VALUE, then fails with it.
+                    let (arg1, end) = store.car_cdr_or_nil(&rest)?;
+                    if rest.is_nil() || !end.is_nil() {
+                        Control::Return(expr, env, store.intern_cont_error())
+                    } else {
+                        Control::Return(arg1, env, store.intern_cont_fail())
+                    }
+                } else if head == store.sym("assert") {
+                    // (ASSERT C) becomes (IF C T (FAIL (QUOTE C)))
+                    let (condition, end) = store.car_cdr_or_nil(&rest)?;
+                    if rest.is_nil() || !end.is_nil() {
+                        Control::Return(expr, env, store.intern_cont_error())
+                    } else {
+                        let quote = store.sym("quote");
+                        let quoted = store.list(&[quote, condition]);
+                        let fail = store.sym("fail");
+                        let failure = store.list(&[fail, quoted]);
+                        let t = store.t();
+                        let more = store.list(&[t, failure]);
+                        Control::Return(condition, env, store.intern_cont_if(more, cont))
+                    }
+                } else if head == store.sym("apply") {
+                    // (APPLY FN ARGS) evaluates (CONS FN ARGS), then calls the function with
+                    // each argument in turn, as (FN . ARGS) would.
+                    let (_, more) = store.car_cdr_or_nil(&rest)?;
+                    let (_, end) = store.car_cdr_or_nil(&more)?;
+                    if rest.is_nil() || more.is_nil() || !end.is_nil() {
+                        Control::Return(expr, env, store.intern_cont_error())
+                    } else {
+                        let cons = store.sym("cons");
+                        let fun_and_args = store.cons(cons, rest);
+                        let nil = store.nil();
+                        let cont = call_boundary(env, cont, store);
+                        Control::Return(
+                            fun_and_args,
+                            env,
+                            store.intern_cont_apply(nil, env, cont, nil),
+                        )
+                    }
+                } else if head == store.sym("catch") {
+                    // (CATCH TAG BODY) evaluates BODY, unless it throws a value to TAG.
+                    let (tag, more) = store.car_cdr_or_nil(&rest)?;
+                    let (body, end) = store.car_cdr_or_nil(&more)?;
+                    if rest.is_nil() || more.is_nil() || !end.is_nil() {
+                        Control::Return(expr, env, store.intern_cont_error())
+                    } else {
+                        Control::Return(body, env, store.intern_cont_catch(tag, env, cont))
+                    }
+                } else if head == store.sym("throw") {
+                    // (THROW TAG VALUE) returns the value of VALUE from the innermost enclosing
+                    // (CATCH TAG BODY).
+                    let (tag, more) = store.car_cdr_or_nil(&rest)?;
+                    let (value, end) = store.car_cdr_or_nil(&more)?;
+                    if rest.is_nil() || more.is_nil() || !end.is_nil() {
+                        Control::Return(expr, env, store.intern_cont_error())
+                    } else {
+                        Control::Return(value, env, store.intern_cont_throw(tag, cont))
+                    }
+                } else if head == store.sym("witness") || head == store.sym("read") {
+                    // (WITNESS NAME) and (READ) evaluate to the value the oracle supplies for NAME,
+                    // which is not evaluated, or for nil.
+                    let (name, end) = store.car_cdr_or_nil(&rest)?;
+                    let well_formed = if head == store.sym("read") {
+                        rest.is_nil()
+                    } else {
+                        !rest.is_nil() && end.is_nil()
+                    };
+                    *oracle_value = match oracle {
+                        Some(oracle) if well_formed => oracle.read(store, name),
+                        _ => None,
+                    };
+                    match *oracle_value {
+                        Some(value) => Control::ApplyContinuation(value, env, cont),
+                        None => Control::Return(expr, env, store.intern_cont_error()),
+                    }
                 } else {
                     // (fn . args)
                     let fun_form = head;
                     let args = rest;
+                    let nil = store.nil();
                     if args.is_nil() {
-                        Control::Return(fun_form, env, store.intern_cont_call0(cont))
+                        Control::Return(fun_form, env, store.intern_cont_call0(cont, nil, nil))
                     } else {
-                        let (arg, more_args) = store.car_cdr(&args);
-                        match more_args.tag() {
+                        let (arg, more_args) = store.car_cdr_or_nil(&args)?;
+                        let more = store.sym("&more");
+                        if more_args.is_nil() {
                             // (fn arg)
                             // Interpreting as call.
-                            Tag::Nil => Control::Return(
+                            let cont = call_boundary(env, cont, store);
+                            Control::Return(
                                 fun_form,
                                 env,
-                                store.intern_cont_call(arg, env, cont),
-                            ),
-                            _ => {
-                                // Interpreting as multi-arg call.
-                                // (fn arg . more_args) => ((fn arg) . more_args)
-                                let expanded_inner = store.list(&[fun_form, arg]);
-                                let expanded = store.cons(expanded_inner, more_args);
-                                Control::Return(expanded, env, cont)
-                            }
+                                store.intern_cont_call(arg, env, cont, nil),
+                            )
+                        } else if more_args == more {
+                            // (fn arg . &more)
+                            // Interpreting as call with the first argument of a multi-arg call,
+                            // whose next argument is in cont.
+                            Control::Return(
+                                fun_form,
+                                env,
+                                store.intern_cont_call(arg, env, cont, nil),
+                            )
+                        } else {
+                            // Interpreting as multi-arg call.
+                            // (fn arg . more_args) => ((fn arg . &more) . more_args)
+                            let arg_and_more = store.cons(arg, more);
+                            let expanded_inner = store.cons(fun_form, arg_and_more);
+                            let expanded = store.cons(expanded_inner, more_args);
+                            Control::Return(expanded, env, cont)
                         }
                     }
                 }
@@ -812,52 +1238,114 @@ fn reduce_with_witness<F: LurkField>(
         }
     };
 
-    let (new_expr, new_env, new_cont) = control.as_results();
+    Ok(control)
+}
 
-    let mut witness = Witness {
-        prethunk_output_expr: *new_expr,
-        prethunk_output_env: *new_env,
-        prethunk_output_cont: *new_cont,
+// Replaces the unclassified error with one recording why reduction failed and the offending expression.
+// Failing to reduce a symbol means it is unbound; failing to reduce anything else means a malformed form.
+// Failing to apply a call or APPLY continuation is a bad call. Applying FAIL, or a THROW which
+// nothing catches, is a user failure. Failing to apply any other continuation is a bad argument.
+fn attribute_error<F: LurkField>(
+    control: Control<F>,
+    expr: Ptr<F>,
+    store: &mut Store<F>,
+    witness: &Witness<F>,
+) -> Control<F> {
+    let error = store.get_cont_error();
+    let (result, env, cont) = control.into_results();
 
-        extended_closure,
-        apply_continuation_cont: None,
-    };
+    if cont != error {
+        return Control::Return(result, env, cont);
+    }
 
-    let control = apply_continuation(control, store, &mut witness);
-    let ctrl = make_thunk(control, store, &mut witness);
+    // CIRCUIT: error_in_reduce
+    let code = if witness.prethunk_output_cont == error {
+        if expr.tag() == Tag::Sym {
+            ErrorCode::UnboundVariable
+        } else {
+            ErrorCode::InvalidForm
+        }
+    } else {
+        match witness.prethunk_output_cont.tag() {
+            ContTag::Call0 | ContTag::Call | ContTag::Call2 | ContTag::Apply => {
+                ErrorCode::InvalidCall
+            }
+            ContTag::Fail | ContTag::Throw => ErrorCode::UserFailure,
+            _ => ErrorCode::InvalidArgument,
+        }
+    };
 
-    (ctrl, witness)
+    Control::Return(result, env, store.intern_cont_error_with(code, result))
 }
 
 fn apply_continuation<F: LurkField>(
     control: Control<F>,
     store: &mut Store<F>,
     witness: &mut Witness<F>,
-) -> Control<F> {
+) -> Result<Control<F>, ReductionError> {
     if !control.is_apply_continuation() {
-        return control;
+        return Ok(control);
     }
 
     let (result, env, cont) = control.as_results();
     witness.apply_continuation_cont = Some(*cont);
 
+    // A value which cannot be used is an error like any other bad argument, unless the circuit
+    // cannot prove that it is, as for an opaque one.
+    match apply_continuation_control(result, env, cont, store) {
+        Err(e) if e.is_provable() => Ok(Control::Return(*result, *env, store.intern_cont_error())),
+        control => control,
+    }
+}
+
+// Applies CONT to RESULT in ENV. Fails if RESULT cannot be used, rather than panicking.
+fn apply_continuation_control<F: LurkField>(
+    result: &Ptr<F>,
+    env: &Ptr<F>,
+    cont: &ContPtr<F>,
+    store: &mut Store<F>,
+) -> Result<Control<F>, ReductionError> {
     let control = match cont.tag() {
         ContTag::Terminal | ContTag::Error => Control::Return(*result, *env, *cont),
-        ContTag::Dummy => unreachable!("Dummy Continuation should never be applied."),
+        // Only an input which was not output by a reduction, such as one being resumed, can apply
+        // it.
+        ContTag::Dummy => return Err(ReductionError::DummyContinuation),
         ContTag::Outermost => Control::Return(*result, *env, store.intern_cont_terminal()),
-        ContTag::Emit => match store.fetch_cont(cont).unwrap() {
+        ContTag::Emit => match store.fetch_cont(cont).ok_or(ReductionError::Missing)? {
             // Although Emit has no effect within the computation, it has an externally-visible side effect of
             // manifesting an explicit Thunk in the expr register of the execution trace.
             Continuation::Emit { continuation } => Control::MakeThunk(*result, *env, continuation),
             _ => unreachable!(),
         },
-        ContTag::Call0 => match store.fetch_cont(cont).unwrap() {
-            Continuation::Call0 { continuation } => match result.tag() {
-                Tag::Fun => match store.fetch(result).unwrap() {
+        ContTag::Call0 => match store.fetch_cont(cont).ok_or(ReductionError::Missing)? {
+            Continuation::Call0 {
+                continuation,
+                reversed_args,
+                collected_args,
+            } => match result.tag() {
+                Tag::Fun => match store.fetch(result).ok_or(ReductionError::Missing)? {
                     Expression::Fun(arg, body, closed_env) => {
-                        let body_form = store.car(&body);
+                        let body_form = store.car_cdr_or_nil(&body)?.0;
                         if arg == store.sym("_") {
                             Control::Return(body_form, closed_env, continuation)
+                        } else if arg.is_cons() {
+                            // A variadic function is called once its call has no more arguments.
+                            // The arguments were collected in reverse, so first move them one at a
+                            // time, then bind the rest parameter to them.
+                            if reversed_args.is_nil() {
+                                let var = store.car_cdr_or_nil(&arg)?.0;
+                                let newer_env = extend(closed_env, var, collected_args, store);
+                                Control::Return(body_form, newer_env, continuation)
+                            } else {
+                                let (val, reversed_args) = store.car_cdr_or_nil(&reversed_args)?;
+                                let collected_args = store.cons(val, collected_args);
+                                let newer_cont = store.intern_cont_call0(
+                                    continuation,
+                                    reversed_args,
+                                    collected_args,
+                                );
+                                Control::Return(*result, *env, newer_cont)
+                            }
                         } else {
                             // Applying zero args to a non-zero arg function leaves it unchanged.
                             // This is arguably consistent with auto-currying.
@@ -865,7 +1353,7 @@ fn apply_continuation<F: LurkField>(
                             Control::Return(*result, *env, continuation)
                         }
                     }
-                    _ => unreachable!(),
+                    _ => return Err(ReductionError::Opaque),
                 }, // Bad function
                 _ => Control::Return(*result, *env, store.intern_cont_error()),
             },
@@ -874,15 +1362,17 @@ fn apply_continuation<F: LurkField>(
 
         ContTag::Call => match result.tag() {
             // (arg, saved_env, continuation)
-            Tag::Fun => match store.fetch_cont(cont).unwrap() {
+            Tag::Fun => match store.fetch_cont(cont).ok_or(ReductionError::Missing)? {
                 Continuation::Call {
                     unevaled_arg,
                     saved_env,
                     continuation,
+                    reversed_args,
                 } => {
                     let function = result;
                     let next_expr = unevaled_arg;
-                    let newer_cont = store.intern_cont_call2(*function, saved_env, continuation);
+                    let newer_cont =
+                        store.intern_cont_call2(*function, saved_env, continuation, reversed_args);
                     Control::Return(next_expr, *env, newer_cont)
                 }
                 _ => unreachable!(),
@@ -892,23 +1382,63 @@ fn apply_continuation<F: LurkField>(
                 Control::Return(*result, *env, store.intern_cont_error())
             }
         },
-        ContTag::Call2 => match store.fetch_cont(cont).unwrap() {
+        ContTag::Call2 => match store.fetch_cont(cont).ok_or(ReductionError::Missing)? {
             Continuation::Call2 {
                 function,
                 saved_env,
                 continuation,
+                reversed_args,
             } => match function.tag() {
-                Tag::Fun => match store.fetch(&function).unwrap() {
+                Tag::Fun => match store.fetch(&function).ok_or(ReductionError::Missing)? {
                     Expression::Fun(arg, body, closed_env) => {
                         if arg == store.sym("_") {
-                            return Control::Return(*result, *env, store.intern_cont_error());
+                            return Ok(Control::Return(*result, *env, store.intern_cont_error()));
+                        }
+                        if arg.is_cons() {
+                            // A variadic function collects every argument of its call. While the
+                            // call has more of them, the continuation is the Call or Apply which
+                            // holds the next one, and carries the collected ones to the next Call2.
+                            // Otherwise, the function is called with Call0.
+                            let reversed_args = store.cons(*result, reversed_args);
+                            let next_cont = match store
+                                .fetch_cont(&continuation)
+                                .ok_or(ReductionError::Missing)?
+                            {
+                                Continuation::Call {
+                                    unevaled_arg,
+                                    saved_env,
+                                    continuation,
+                                    ..
+                                } => store.intern_cont_call(
+                                    unevaled_arg,
+                                    saved_env,
+                                    continuation,
+                                    reversed_args,
+                                ),
+                                Continuation::Apply {
+                                    unevaled_args,
+                                    saved_env,
+                                    continuation,
+                                    ..
+                                } => store.intern_cont_apply(
+                                    unevaled_args,
+                                    saved_env,
+                                    continuation,
+                                    reversed_args,
+                                ),
+                                _ => {
+                                    let nil = store.nil();
+                                    store.intern_cont_call0(continuation, reversed_args, nil)
+                                }
+                            };
+                            return Ok(Control::Return(function, saved_env, next_cont));
                         }
-                        let body_form = store.car(&body);
+                        let body_form = store.car_cdr_or_nil(&body)?.0;
                         let newer_env = extend(closed_env, arg, *result, store);
                         let cont = make_tail_continuation(saved_env, continuation, store);
                         Control::Return(body_form, newer_env, cont)
                     }
-                    _ => unreachable!(),
+                    _ => return Err(ReductionError::Opaque),
                 },
                 _ => {
                     // Call2 continuation contains a non-function
@@ -917,7 +1447,7 @@ fn apply_continuation<F: LurkField>(
             },
             _ => unreachable!(),
         },
-        ContTag::Let => match store.fetch_cont(cont).unwrap() {
+        ContTag::Let => match store.fetch_cont(cont).ok_or(ReductionError::Missing)? {
             Continuation::Let {
                 var,
                 body,
@@ -931,21 +1461,21 @@ fn apply_continuation<F: LurkField>(
             }
             _ => unreachable!(),
         },
-        ContTag::LetRec => match store.fetch_cont(cont).unwrap() {
+        ContTag::LetRec => match store.fetch_cont(cont).ok_or(ReductionError::Missing)? {
             Continuation::LetRec {
                 var,
                 body,
                 saved_env,
                 continuation,
             } => {
-                let extended_env = extend_rec(*env, var, *result, store);
+                let extended_env = extend_rec(*env, var, *result, store)?;
                 let c = make_tail_continuation(saved_env, continuation, store);
 
                 Control::Return(body, extended_env, c)
             }
             _ => unreachable!(),
         },
-        ContTag::Unop => match store.fetch_cont(cont).unwrap() {
+        ContTag::Unop => match store.fetch_cont(cont).ok_or(ReductionError::Missing)? {
             Continuation::Unop {
                 operator,
                 continuation,
@@ -953,12 +1483,17 @@ fn apply_continuation<F: LurkField>(
                 let val = match operator {
                     Op1::Car => match store.car_cdr_mut(result) {
                         Ok((car, _)) => car,
-                        Err(_) => return Control::Return(*result, *env, store.intern_cont_error()),
-                        //Err(_) => store.nil(),
+                        Err(ReductionError::NotAList) => {
+                            return Ok(Control::Return(*result, *env, store.intern_cont_error()))
+                        }
+                        Err(e) => return Err(e),
                     },
                     Op1::Cdr => match store.car_cdr_mut(result) {
                         Ok((_, cdr)) => cdr,
-                        Err(_) => return Control::Return(*result, *env, store.intern_cont_error()),
+                        Err(ReductionError::NotAList) => {
+                            return Ok(Control::Return(*result, *env, store.intern_cont_error()))
+                        }
+                        Err(e) => return Err(e),
                     },
                     Op1::Atom => match result.tag() {
                         Tag::Cons => store.nil(),
@@ -966,58 +1501,110 @@ fn apply_continuation<F: LurkField>(
                     },
                     Op1::Emit => {
                         println!("{}", result.fmt_to_string(store));
-                        return Control::MakeThunk(
+                        return Ok(Control::MakeThunk(
                             *result,
                             *env,
                             store.intern_cont_emit(continuation),
-                        );
+                        ));
                     }
-                    Op1::Open => store
-                        .open_mut(*result)
-                        .expect("hidden value could not be opened"),
-                    Op1::Secret => store
-                        .secret_mut(*result)
-                        .expect("secret could not be extracted"),
+                    // A commitment whose payload is unknown cannot be opened.
+                    Op1::Open => match result.tag() {
+                        Tag::Num | Tag::Comm => {
+                            store.open_mut(*result).ok_or(ReductionError::Opaque)?
+                        }
+                        _ => return Ok(Control::Return(*result, *env, store.intern_cont_error())),
+                    },
+                    Op1::Secret => match result.tag() {
+                        Tag::Comm => store.secret_mut(*result).ok_or(ReductionError::Opaque)?,
+                        _ => return Ok(Control::Return(*result, *env, store.intern_cont_error())),
+                    },
                     Op1::Commit => store.hide(F::zero(), *result),
                     Op1::Num => match result.tag() {
-                        Tag::Num | Tag::Comm | Tag::Char => {
+                        Tag::Num | Tag::Comm | Tag::Char | Tag::U64 => {
                             let scalar_ptr =
-                                store.get_expr_hash(result).expect("expr hash missing");
+                                store.get_expr_hash(result).ok_or(ReductionError::Missing)?;
                             store.intern_num(crate::Num::Scalar::<F>(*scalar_ptr.value()))
                         }
-                        _ => return Control::Return(*result, *env, store.intern_cont_error()),
+                        _ => return Ok(Control::Return(*result, *env, store.intern_cont_error())),
                     },
                     Op1::Comm => match result.tag() {
                         Tag::Num | Tag::Comm => {
                             let scalar_ptr =
-                                store.get_expr_hash(result).expect("expr hash missing");
+                                store.get_expr_hash(result).ok_or(ReductionError::Missing)?;
                             store.intern_maybe_opaque_comm(*scalar_ptr.value())
                         }
-                        _ => return Control::Return(*result, *env, store.intern_cont_error()),
+                        _ => return Ok(Control::Return(*result, *env, store.intern_cont_error())),
                     },
                     Op1::Char => match result.tag() {
                         Tag::Num | Tag::Char => {
                             let scalar_ptr =
-                                store.get_expr_hash(result).expect("expr hash missing");
-                            store.get_char(
-                                char::from_u32(scalar_ptr.value().to_u32().unwrap()).unwrap(),
-                            )
+                                store.get_expr_hash(result).ok_or(ReductionError::Missing)?;
+                            // A Num which is not a valid character code is an error.
+                            match scalar_ptr.value().to_u32().and_then(char::from_u32) {
+                                Some(c) => store.get_char(c),
+                                None => {
+                                    return Ok(Control::Return(
+                                        *result,
+                                        *env,
+                                        store.intern_cont_error(),
+                                    ))
+                                }
+                            }
                         }
-                        _ => return Control::Return(*result, *env, store.intern_cont_error()),
+                        _ => return Ok(Control::Return(*result, *env, store.intern_cont_error())),
+                    },
+                    // A Num which does not fit in a u64 is an error, rather than being truncated.
+                    Op1::U64 => match store.fetch(result).ok_or(ReductionError::Missing)? {
+                        Expression::Num(n) => match n.to_u64() {
+                            Some(n) => store.intern_u64(n),
+                            None => {
+                                return Ok(Control::Return(
+                                    *result,
+                                    *env,
+                                    store.intern_cont_error(),
+                                ))
+                            }
+                        },
+                        Expression::U64(_) => *result,
+                        _ => return Ok(Control::Return(*result, *env, store.intern_cont_error())),
                     },
+                    Op1::TypeOf => store.sym(result.tag().keyword()),
+                    Op1::IsNil
+                    | Op1::IsCons
+                    | Op1::IsSym
+                    | Op1::IsFun
+                    | Op1::IsNum
+                    | Op1::IsStr
+                    | Op1::IsChar
+                    | Op1::IsComm
+                    | Op1::IsU64 => {
+                        if operator.predicate_tag() == Some(result.tag()) {
+                            store.t()
+                        } else {
+                            store.nil()
+                        }
+                    }
                 };
                 Control::MakeThunk(val, *env, continuation)
             }
             _ => unreachable!(),
         },
-        ContTag::Binop => match store.fetch_cont(cont).unwrap() {
+        ContTag::Binop => match store.fetch_cont(cont).ok_or(ReductionError::Missing)? {
             Continuation::Binop {
                 operator,
                 saved_env,
                 unevaled_args,
                 continuation,
             } => {
-                let (arg2, rest) = store.car_cdr(&unevaled_args);
+                if unevaled_args.is_nil() && matches!(operator, Op2::Sum | Op2::Product) {
+                    // The only argument of (+ x) or (* x) is its value.
+                    return Ok(if matches!(result.tag(), Tag::Num | Tag::U64) {
+                        Control::MakeThunk(*result, *env, continuation)
+                    } else {
+                        Control::Return(*result, *env, store.intern_cont_error())
+                    });
+                }
+                let (arg2, rest) = store.car_cdr_or_nil(&unevaled_args)?;
                 if operator == Op2::Begin {
                     if rest.is_nil() {
                         Control::Return(arg2, saved_env, continuation)
@@ -1026,28 +1613,40 @@ fn apply_continuation<F: LurkField>(
                         let begin_again = store.cons(begin, unevaled_args);
                         Control::Return(begin_again, saved_env, continuation)
                     }
-                } else if !rest.is_nil() {
+                } else if !rest.is_nil()
+                    && !matches!(
+                        operator,
+                        Op2::Sum
+                            | Op2::Diff
+                            | Op2::Product
+                            | Op2::Quotient
+                            | Op2::Modulo
+                            | Op2::IntQuotient
+                    )
+                {
                     Control::Return(*result, *env, store.intern_cont_error())
                 } else {
+                    // Remaining arguments are folded in from the left by Binop2.
                     Control::Return(
                         arg2,
                         saved_env,
-                        store.intern_cont_binop2(operator, *result, continuation),
+                        store.intern_cont_binop2(operator, *result, rest, continuation),
                     )
                 }
             }
             _ => unreachable!(),
         },
-        ContTag::Binop2 => match store.fetch_cont(cont).unwrap() {
+        ContTag::Binop2 => match store.fetch_cont(cont).ok_or(ReductionError::Missing)? {
             Continuation::Binop2 {
                 operator,
                 evaled_arg,
+                unevaled_args,
                 continuation,
             } => {
                 let arg2 = result;
                 let result = match (
-                    store.fetch(&evaled_arg).unwrap(),
-                    store.fetch(arg2).unwrap(),
+                    store.fetch(&evaled_arg).ok_or(ReductionError::Missing)?,
+                    store.fetch(arg2).ok_or(ReductionError::Missing)?,
                 ) {
                     (Expression::Num(a), Expression::Num(b)) => match operator {
                         Op2::Sum => {
@@ -1069,62 +1668,127 @@ fn apply_continuation<F: LurkField>(
                             let mut tmp = a;
                             let b_is_zero: bool = b.is_zero();
                             if b_is_zero {
-                                return Control::Return(*result, *env, store.intern_cont_error());
+                                return Ok(Control::Return(
+                                    *result,
+                                    *env,
+                                    store.intern_cont_error(),
+                                ));
                             }
                             tmp /= b;
                             store.intern_num(tmp)
                         }
                         Op2::Cons => store.cons(evaled_arg, *arg2),
                         Op2::Hide => store.hide(a.into_scalar(), *arg2),
+                        // Integer division is only defined for Nums which fit in a u64.
+                        Op2::IntQuotient | Op2::Modulo => match (a.to_u64(), b.to_u64()) {
+                            (Some(a), Some(b)) if b != 0 => {
+                                if operator == Op2::Modulo {
+                                    store.intern_num(a % b)
+                                } else {
+                                    store.intern_num(a / b)
+                                }
+                            }
+                            _ => {
+                                return Ok(Control::Return(
+                                    *result,
+                                    *env,
+                                    store.intern_cont_error(),
+                                ));
+                            }
+                        },
+                        Op2::StrCons => {
+                            return Ok(Control::Return(*result, *env, store.intern_cont_error()));
+                        }
+                        Op2::Begin => unreachable!(),
+                    },
+                    (Expression::U64(a), Expression::U64(b)) => match operator {
+                        Op2::Sum => store.intern_u64(a.wrapping_add(b)),
+                        Op2::Diff => store.intern_u64(a.wrapping_sub(b)),
+                        Op2::Product => store.intern_u64(a.wrapping_mul(b)),
+                        Op2::Quotient | Op2::IntQuotient | Op2::Modulo if b == 0 => {
+                            return Ok(Control::Return(*result, *env, store.intern_cont_error()));
+                        }
+                        Op2::Quotient | Op2::IntQuotient => store.intern_u64(a / b),
+                        Op2::Modulo => store.intern_u64(a % b),
+                        Op2::Cons => store.cons(evaled_arg, *arg2),
+                        Op2::Hide => store.hide(F::from(a), *arg2),
+                        Op2::StrCons => {
+                            return Ok(Control::Return(*result, *env, store.intern_cont_error()));
+                        }
                         Op2::Begin => unreachable!(),
                     },
                     (Expression::Num(a), _) => match operator {
                         Op2::Cons => store.cons(evaled_arg, *arg2),
                         Op2::Hide => store.hide(a.into_scalar(), *arg2),
                         _ => {
-                            return Control::Return(*result, *env, store.intern_cont_error());
+                            return Ok(Control::Return(*result, *env, store.intern_cont_error()));
+                        }
+                    },
+                    (Expression::U64(a), _) => match operator {
+                        Op2::Cons => store.cons(evaled_arg, *arg2),
+                        Op2::Hide => store.hide(F::from(a), *arg2),
+                        _ => {
+                            return Ok(Control::Return(*result, *env, store.intern_cont_error()));
                         }
                     },
+                    (Expression::Char(_), Expression::Str(_)) if operator == Op2::StrCons => {
+                        store.cons(evaled_arg, *arg2)
+                    }
                     _ => match operator {
                         Op2::Cons => store.cons(evaled_arg, *arg2),
                         _ => {
-                            return Control::Return(*result, *env, store.intern_cont_error());
+                            return Ok(Control::Return(*result, *env, store.intern_cont_error()));
                         }
                     },
                 };
-                Control::MakeThunk(result, *env, continuation)
+                if unevaled_args.is_nil() {
+                    Control::MakeThunk(result, *env, continuation)
+                } else {
+                    let (arg, more) = store.car_cdr_or_nil(&unevaled_args)?;
+                    Control::Return(
+                        arg,
+                        *env,
+                        store.intern_cont_binop2(operator, result, more, continuation),
+                    )
+                }
             }
             _ => unreachable!(),
         },
-        ContTag::Relop => match store.fetch_cont(cont).unwrap() {
+        ContTag::Relop => match store.fetch_cont(cont).ok_or(ReductionError::Missing)? {
             Continuation::Relop {
                 operator,
                 saved_env,
                 unevaled_args,
                 continuation,
             } => {
-                let (arg2, rest) = store.car_cdr(&unevaled_args);
-                if !rest.is_nil() {
-                    Control::Return(*result, *env, store.intern_cont_error())
+                if unevaled_args.is_nil() {
+                    // A relation holds trivially for a single argument of the right type.
+                    if operator == Rel2::Equal || matches!(result.tag(), Tag::Num | Tag::U64) {
+                        Control::MakeThunk(store.t(), *env, continuation)
+                    } else {
+                        Control::Return(*result, *env, store.intern_cont_error())
+                    }
                 } else {
+                    let (arg2, rest) = store.car_cdr_or_nil(&unevaled_args)?;
                     Control::Return(
                         arg2,
                         saved_env,
-                        store.intern_cont_relop2(operator, *result, continuation),
+                        store.intern_cont_relop2(operator, *result, rest, continuation),
                     )
                 }
             }
             _ => unreachable!(),
         },
-        ContTag::Relop2 => match store.fetch_cont(cont).unwrap() {
+        ContTag::Relop2 => match store.fetch_cont(cont).ok_or(ReductionError::Missing)? {
             Continuation::Relop2 {
                 operator,
                 evaled_arg,
+                unevaled_args,
                 continuation,
             } => {
                 let arg2 = result;
                 let result = match (evaled_arg.tag(), arg2.tag()) {
-                    (Tag::Num, Tag::Num) => match operator {
+                    (Tag::Num, Tag::Num) | (Tag::U64, Tag::U64) => match operator {
                         Rel2::NumEqual | Rel2::Equal => {
                             if store.ptr_eq(&evaled_arg, arg2) {
                                 store.t() // TODO: maybe explicit boolean.
@@ -1132,11 +1796,41 @@ fn apply_continuation<F: LurkField>(
                                 store.nil()
                             }
                         }
+                        Rel2::Less | Rel2::Greater | Rel2::LessEqual | Rel2::GreaterEqual => {
+                            let ordering = match (
+                                store.fetch(&evaled_arg).ok_or(ReductionError::Missing)?,
+                                store.fetch(arg2).ok_or(ReductionError::Missing)?,
+                            ) {
+                                (Expression::Num(a), Expression::Num(b)) => a.cmp_u64(&b),
+                                (Expression::U64(a), Expression::U64(b)) => Some(a.cmp(&b)),
+                                _ => unreachable!(),
+                            };
+                            // Numbers are ordered as u64s. Comparing a number which does not
+                            // fit in a u64 is an error. See `Num::cmp_u64`.
+                            let holds = match ordering {
+                                Some(ordering) => match operator {
+                                    Rel2::Less => ordering == Ordering::Less,
+                                    Rel2::Greater => ordering == Ordering::Greater,
+                                    Rel2::LessEqual => ordering != Ordering::Greater,
+                                    Rel2::GreaterEqual => ordering != Ordering::Less,
+                                    _ => unreachable!(),
+                                },
+                                None => {
+                                    return Ok(Control::Return(
+                                        *result,
+                                        *env,
+                                        store.intern_cont_error(),
+                                    ));
+                                }
+                            };
+                            if holds {
+                                store.t()
+                            } else {
+                                store.nil()
+                            }
+                        }
                     },
                     (_, _) => match operator {
-                        Rel2::NumEqual => {
-                            return Control::Return(*result, *env, store.intern_cont_error());
-                        }
                         Rel2::Equal => {
                             if store.ptr_eq(&evaled_arg, arg2) {
                                 store.t()
@@ -1144,19 +1838,32 @@ fn apply_continuation<F: LurkField>(
                                 store.nil()
                             }
                         }
+                        _ => {
+                            return Ok(Control::Return(*result, *env, store.intern_cont_error()));
+                        }
                     },
                 };
-                Control::MakeThunk(result, *env, continuation)
+                // Chained relations stop at the first one which does not hold.
+                if unevaled_args.is_nil() || result.is_nil() {
+                    Control::MakeThunk(result, *env, continuation)
+                } else {
+                    let (arg, more) = store.car_cdr_or_nil(&unevaled_args)?;
+                    Control::Return(
+                        arg,
+                        *env,
+                        store.intern_cont_relop2(operator, *arg2, more, continuation),
+                    )
+                }
             }
             _ => unreachable!(),
         },
-        ContTag::If => match store.fetch_cont(cont).unwrap() {
+        ContTag::If => match store.fetch_cont(cont).ok_or(ReductionError::Missing)? {
             Continuation::If {
                 unevaled_args,
                 continuation,
             } => {
                 let condition = result;
-                let (arg1, more) = store.car_cdr(&unevaled_args);
+                let (arg1, more) = store.car_cdr_or_nil(&unevaled_args)?;
                 // NOTE: as formulated here, IF operates on any condition. Every
                 // value but NIL is considered true.
                 //
@@ -1185,7 +1892,7 @@ fn apply_continuation<F: LurkField>(
                 // value being checked against is not zero, so that value should
                 // first be subtracted from the value being checked.
 
-                let (arg2, end) = store.car_cdr(&more);
+                let (arg2, end) = store.car_cdr_or_nil(&more)?;
                 if !end.is_nil() {
                     Control::Return(arg1, *env, store.intern_cont_error())
                 } else if condition.is_nil() {
@@ -1196,14 +1903,151 @@ fn apply_continuation<F: LurkField>(
             }
             _ => unreachable!(),
         },
-        ContTag::Lookup => match store.fetch_cont(cont).unwrap() {
+        ContTag::And => match store.fetch_cont(cont).ok_or(ReductionError::Missing)? {
+            Continuation::And {
+                unevaled_args,
+                continuation,
+            } => {
+                if result.is_nil() {
+                    Control::MakeThunk(*result, *env, continuation)
+                } else {
+                    let (arg, more) = store.car_cdr_or_nil(&unevaled_args)?;
+                    if more.is_nil() {
+                        Control::Return(arg, *env, continuation)
+                    } else {
+                        Control::Return(arg, *env, store.intern_cont_and(more, continuation))
+                    }
+                }
+            }
+            _ => unreachable!(),
+        },
+        ContTag::Or => match store.fetch_cont(cont).ok_or(ReductionError::Missing)? {
+            Continuation::Or {
+                unevaled_args,
+                continuation,
+            } => {
+                if !result.is_nil() {
+                    Control::MakeThunk(*result, *env, continuation)
+                } else {
+                    let (arg, more) = store.car_cdr_or_nil(&unevaled_args)?;
+                    if more.is_nil() {
+                        Control::Return(arg, *env, continuation)
+                    } else {
+                        Control::Return(arg, *env, store.intern_cont_or(more, continuation))
+                    }
+                }
+            }
+            _ => unreachable!(),
+        },
+        ContTag::Lookup => match store.fetch_cont(cont).ok_or(ReductionError::Missing)? {
             Continuation::Lookup {
                 saved_env,
                 continuation,
             } => Control::MakeThunk(*result, saved_env, continuation),
             _ => unreachable!(),
         },
-        ContTag::Tail => match store.fetch_cont(cont).unwrap() {
+        ContTag::Eval => match store.fetch_cont(cont).ok_or(ReductionError::Missing)? {
+            Continuation::Eval {
+                saved_env,
+                continuation,
+            } => match result.tag() {
+                Tag::Cons => {
+                    let (expr, env) = store.car_cdr_or_nil(result)?;
+                    let c = make_tail_continuation(saved_env, continuation, store);
+
+                    Control::Return(expr, env, c)
+                }
+                _ => Control::Return(*result, *env, store.intern_cont_error()),
+            },
+            _ => unreachable!(),
+        },
+        ContTag::Apply => match store.fetch_cont(cont).ok_or(ReductionError::Missing)? {
+            Continuation::Apply {
+                unevaled_args,
+                saved_env,
+                continuation,
+                reversed_args,
+            } => {
+                // At first, the result is (FN . ARGS). Afterwards, it is what the function
+                // returned for the arguments so far, and the rest are in the continuation.
+                let (function, args) = if unevaled_args.is_nil() {
+                    store.car_cdr_or_nil(result)?
+                } else {
+                    (*result, unevaled_args)
+                };
+                if function.tag() != Tag::Fun {
+                    return Ok(Control::Return(*result, *env, store.intern_cont_error()));
+                }
+                match args.tag() {
+                    Tag::Nil => {
+                        let nil = store.nil();
+                        let newer_cont = store.intern_cont_call0(continuation, nil, nil);
+                        Control::Return(function, *env, newer_cont)
+                    }
+                    Tag::Cons => {
+                        let (arg, more) = store.car_cdr_or_nil(&args)?;
+                        let next_cont = match more.tag() {
+                            Tag::Nil => continuation,
+                            Tag::Cons => {
+                                let nil = store.nil();
+                                store.intern_cont_apply(more, saved_env, continuation, nil)
+                            }
+                            _ => {
+                                return Ok(Control::Return(
+                                    *result,
+                                    *env,
+                                    store.intern_cont_error(),
+                                ))
+                            }
+                        };
+                        // The argument is already evaluated.
+                        let quote = store.sym("quote");
+                        let quoted_arg = store.list(&[quote, arg]);
+                        let newer_cont =
+                            store.intern_cont_call2(function, saved_env, next_cont, reversed_args);
+                        Control::Return(quoted_arg, *env, newer_cont)
+                    }
+                    _ => Control::Return(*result, *env, store.intern_cont_error()),
+                }
+            }
+            _ => unreachable!(),
+        },
+        ContTag::Catch => match store.fetch_cont(cont).ok_or(ReductionError::Missing)? {
+            Continuation::Catch {
+                saved_env,
+                continuation,
+                ..
+            } => Control::MakeThunk(*result, saved_env, continuation),
+            _ => unreachable!(),
+        },
+        // A thrown value unwinds the continuations, one at a time, until one catches its tag.
+        ContTag::Throw => match store.fetch_cont(cont).ok_or(ReductionError::Missing)? {
+            Continuation::Throw { tag, continuation } => {
+                match store
+                    .fetch_cont(&continuation)
+                    .ok_or(ReductionError::Missing)?
+                {
+                    Continuation::Catch {
+                        tag: catch_tag,
+                        saved_env,
+                        continuation,
+                    } if store.ptr_eq(&tag, &catch_tag) => {
+                        Control::MakeThunk(*result, saved_env, continuation)
+                    }
+                    unwound => match unwound.continuation() {
+                        Some(parent) => {
+                            Control::MakeThunk(*result, *env, store.intern_cont_throw(tag, parent))
+                        }
+                        // Nothing catches the tag: the value becomes the error's expression.
+                        None => Control::Return(*result, *env, store.intern_cont_error()),
+                    },
+                }
+            }
+            _ => unreachable!(),
+        },
+        // The value of (FAIL VALUE) becomes the error's expression.
+        ContTag::Fail => Control::Return(*result, *env, store.intern_cont_error()),
+        ContTag::Tail => match store.fetch_cont(cont).ok_or(ReductionError::Missing)? {
             Continuation::Tail {
                 saved_env,
                 continuation,
@@ -1218,7 +2062,7 @@ fn apply_continuation<F: LurkField>(
         unreachable!();
     }
 
-    control
+    Ok(control)
 }
 
 // Returns (Expression::Thunk, Expression::Env, Continuation)
@@ -1264,6 +2108,16 @@ fn make_thunk<F: LurkField>(
     }
 }
 
+// A variadic function collects the arguments of its own call, which are held by the Call
+// continuations of a multi-arg call's (FN ARG . &MORE) forms. The Call continuation of any other
+// call is wrapped in a Tail, so a variadic function called in it does not collect its argument.
+fn call_boundary<F: LurkField>(env: Ptr<F>, cont: ContPtr<F>, store: &mut Store<F>) -> ContPtr<F> {
+    match cont.tag() {
+        ContTag::Call => make_tail_continuation(env, cont, store),
+        _ => cont,
+    }
+}
+
 fn make_tail_continuation<F: LurkField>(
     env: Ptr<F>,
     continuation: ContPtr<F>,
@@ -1283,9 +2137,12 @@ fn make_tail_continuation<F: LurkField>(
 pub struct Evaluator<'a, F: LurkField> {
     expr: Ptr<F>,
     env: Ptr<F>,
+    cont: ContPtr<F>,
     store: &'a mut Store<F>,
     limit: usize,
     terminal_frame: Option<Frame<IO<F>, Witness<F>>>,
+    oracle: Option<&'a mut dyn Oracle<F>>,
+    observer: Option<&'a mut dyn EmitObserver<F>>,
 }
 
 impl<'a, F: LurkField> Evaluator<'a, F>
@@ -1293,50 +2150,119 @@ where
     IO<F>: Copy,
 {
     pub fn new(expr: Ptr<F>, env: Ptr<F>, store: &'a mut Store<F>, limit: usize) -> Self {
+        let input = match store.expand(expr) {
+            Ok(expr) => {
+                let cont = store.intern_cont_outermost();
+                IO { expr, env, cont }
+            }
+            // A form which cannot be expanded is as invalid as one which cannot be reduced, so
+            // evaluation starts from its error.
+            Err(form) => {
+                let cont = store.intern_cont_error_with(ErrorCode::InvalidForm, form);
+                IO {
+                    expr: form,
+                    env,
+                    cont,
+                }
+            }
+        };
+        Self::resume(input, store, limit)
+    }
+
+    /// Resumes evaluation from `input`, such as the output of an evaluation which reached its
+    /// limit, for at most `limit` more iterations. Unlike `new`, this does not expand the forms
+    /// in `input.expr`, which has been expanded before evaluation started. An input whose dummy
+    /// continuation does not follow a thunk evaluates to an error.
+    pub fn resume(input: IO<F>, store: &'a mut Store<F>, limit: usize) -> Self {
         Evaluator {
-            expr,
-            env,
+            expr: input.expr,
+            env: input.env,
+            cont: input.cont,
             store,
             limit,
             terminal_frame: None,
+            oracle: None,
+            observer: None,
         }
     }
 
+    /// Reads the values of `witness` and `read` forms from `oracle`. Without an oracle, evaluating
+    /// them is an error.
+    pub fn with_oracle(mut self, oracle: &'a mut dyn Oracle<F>) -> Self {
+        self.oracle = Some(oracle);
+        self
+    }
+
+    /// Calls `observer` with every value emitted during evaluation, as soon as it is emitted.
+    pub fn with_emit_observer(mut self, observer: &'a mut dyn EmitObserver<F>) -> Self {
+        self.observer = Some(observer);
+        self
+    }
+
+    /// Evaluates for at most `limit` iterations. Evaluation which must take apart or call opaque
+    /// data stops at the input which cannot be reduced, as it does at the limit. Use `try_eval`
+    /// to tell the two apart.
     pub fn eval(&mut self) -> (IO<F>, usize, Vec<Ptr<F>>) {
-        let initial_input = self.initial();
-        let frame_iterator = FrameIt::new(initial_input, self.store);
+        let (output, iterations, emitted, _) = self.eval_until_stuck();
+        (output, iterations, emitted)
+    }
 
-        // Initial input performs one reduction, so we need limit - 1 more.
-        if let Some((ultimate_frame, _penultimate_frame, emitted)) =
-            frame_iterator.next_n(self.limit - 1)
-        {
-            let output = ultimate_frame.output;
+    /// Like `eval`, but fails if an input cannot be reduced. No proof of such an evaluation can
+    /// be made, not even of an error.
+    pub fn try_eval(&mut self) -> Result<(IO<F>, usize, Vec<Ptr<F>>), ReductionError> {
+        match self.eval_until_stuck() {
+            (_, _, _, Some(e)) => Err(e),
+            (output, iterations, emitted, None) => Ok((output, iterations, emitted)),
+        }
+    }
 
-            let was_terminal = ultimate_frame.is_complete();
-            let i = ultimate_frame.i;
-            if was_terminal {
-                self.terminal_frame = Some(ultimate_frame);
-            }
-            let iterations = if was_terminal { i } else { i + 1 };
-            // NOTE: We compute a terminal frame but don't include it in the iteration count.
-            (output, iterations, emitted)
-        } else {
-            panic!("xxx")
+    // Evaluates until the limit, a complete frame or an input which cannot be reduced, and
+    // returns why it could not be.
+    fn eval_until_stuck(&mut self) -> (IO<F>, usize, Vec<Ptr<F>>, Option<ReductionError>) {
+        let initial_input = self.initial();
+        let frame_iterator = FrameIt::new(
+            initial_input,
+            self.store,
+            reborrow_oracle(&mut self.oracle),
+            reborrow_observer(&mut self.observer),
+        );
+
+        // The initial input is always reduced, even with no limit.
+        let (ultimate_frame, emitted, error) = frame_iterator.next_n(self.limit.max(1));
+        let ultimate_frame = match ultimate_frame {
+            Some(frame) => frame,
+            None => return (initial_input, 0, emitted, error),
+        };
+        let output = ultimate_frame.output;
+
+        let was_terminal = ultimate_frame.is_complete();
+        let i = ultimate_frame.i;
+        if was_terminal {
+            self.terminal_frame = Some(ultimate_frame);
         }
+        let iterations = if was_terminal { i } else { i + 1 };
+        // NOTE: We compute a terminal frame but don't include it in the iteration count.
+        (output, iterations, emitted, error)
     }
 
     pub fn initial(&mut self) -> IO<F> {
         IO {
             expr: self.expr,
             env: self.env,
-            cont: self.store.intern_cont_outermost(),
+            cont: self.cont,
         }
     }
 
     pub fn iter(&mut self) -> Take<FrameIt<'_, Witness<F>, F>> {
         let initial_input = self.initial();
 
-        FrameIt::new(initial_input, self.store).take(self.limit)
+        FrameIt::new(
+            initial_input,
+            self.store,
+            reborrow_oracle(&mut self.oracle),
+            reborrow_observer(&mut self.observer),
+        )
+        .take(self.limit)
     }
 
     pub fn generate_frames<Fp: Fn(usize) -> bool>(
@@ -1345,9 +2271,26 @@ where
         store: &'a mut Store<F>,
         limit: usize,
         needs_frame_padding: Fp,
-    ) -> Vec<Frame<IO<F>, Witness<F>>> {
-        let mut evaluator = Self::new(expr, env, store, limit);
-        let mut frames: Vec<Frame<IO<F>, Witness<F>>> = evaluator.iter().collect::<Vec<_>>();
+    ) -> Result<Vec<Frame<IO<F>, Witness<F>>>, ReductionError> {
+        Self::generate_frames_with_oracle(expr, env, store, limit, needs_frame_padding, None)
+    }
+
+    /// Like `generate_frames`, but reads the values of `witness` and `read` forms from `oracle`.
+    /// Fails if an input cannot be reduced, as `try_eval` does.
+    pub fn generate_frames_with_oracle<Fp: Fn(usize) -> bool>(
+        expr: Ptr<F>,
+        env: Ptr<F>,
+        store: &'a mut Store<F>,
+        limit: usize,
+        needs_frame_padding: Fp,
+        oracle: Option<&mut dyn Oracle<F>>,
+    ) -> Result<Vec<Frame<IO<F>, Witness<F>>>, ReductionError> {
+        let mut evaluator = Evaluator::new(expr, env, store, limit);
+        if let Some(oracle) = oracle {
+            evaluator = evaluator.with_oracle(oracle);
+        }
+        let mut frames: Vec<Frame<IO<F>, Witness<F>>> =
+            evaluator.iter().collect::<Result<_, _>>()?;
         assert!(!frames.is_empty());
 
         // TODO: We previously had an optimization here. If the limit was not reached, the final frame should be an
@@ -1364,7 +2307,7 @@ where
             }
         }
 
-        frames
+        Ok(frames)
     }
 }
 
@@ -1372,43 +2315,67 @@ pub fn empty_sym_env<F: LurkField>(store: &Store<F>) -> Ptr<F> {
     store.get_nil()
 }
 
+// A relation with no arguments holds. Otherwise its first argument is evaluated, and checked by
+// Relop even if it is the only one.
+fn reduce_relop<F: LurkField>(
+    operator: Rel2,
+    rest: Ptr<F>,
+    env: Ptr<F>,
+    cont: ContPtr<F>,
+    store: &mut Store<F>,
+) -> Result<Control<F>, ReductionError> {
+    if rest.is_nil() {
+        return Ok(Control::ApplyContinuation(store.t(), env, cont));
+    }
+    let (arg1, more) = store.car_cdr_or_nil(&rest)?;
+    Ok(Control::Return(
+        arg1,
+        env,
+        store.intern_cont_relop(operator, env, more, cont),
+    ))
+}
+
 fn extend<F: LurkField>(env: Ptr<F>, var: Ptr<F>, val: Ptr<F>, store: &mut Store<F>) -> Ptr<F> {
     let cons = store.cons(var, val);
     store.cons(cons, env)
 }
 
-fn extend_rec<F: LurkField>(env: Ptr<F>, var: Ptr<F>, val: Ptr<F>, store: &mut Store<F>) -> Ptr<F> {
-    let (binding_or_env, rest) = store.car_cdr(&env);
-    let (var_or_binding, _val_or_more_bindings) = store.car_cdr(&binding_or_env);
+fn extend_rec<F: LurkField>(
+    env: Ptr<F>,
+    var: Ptr<F>,
+    val: Ptr<F>,
+    store: &mut Store<F>,
+) -> Result<Ptr<F>, ReductionError> {
+    let (binding_or_env, rest) = store.car_cdr_or_nil(&env)?;
+    let (var_or_binding, _val_or_more_bindings) = store.car_cdr_or_nil(&binding_or_env)?;
     match var_or_binding.tag() {
         // It's a var, so we are extending a simple env with a recursive env.
         Tag::Sym | Tag::Nil => {
             let cons = store.cons(var, val);
             let list = store.list(&[cons]);
-            store.cons(list, env)
+            Ok(store.cons(list, env))
         }
         // It's a binding, so we are extending a recursive env.
         Tag::Cons => {
             let cons = store.cons(var, val);
             let cons2 = store.cons(cons, binding_or_env);
-            store.cons(cons2, rest)
-        }
-        _ => {
-            panic!("Bad input form.")
+            Ok(store.cons(cons2, rest))
         }
+        _ => Err(ReductionError::MalformedEnv),
     }
 }
 
-fn extend_closure<F: LurkField>(fun: &Ptr<F>, rec_env: &Ptr<F>, store: &mut Store<F>) -> Ptr<F> {
-    match fun.tag() {
-        Tag::Fun => match store.fetch(fun).unwrap() {
-            Expression::Fun(arg, body, closed_env) => {
-                let extended = store.cons(*rec_env, closed_env);
-                store.intern_fun(arg, body, extended)
-            }
-            _ => unreachable!(),
-        },
-        _ => panic!("extend_closure received non-Fun: {:?}", fun),
+fn extend_closure<F: LurkField>(
+    fun: &Ptr<F>,
+    rec_env: &Ptr<F>,
+    store: &mut Store<F>,
+) -> Result<Ptr<F>, ReductionError> {
+    match store.fetch(fun).ok_or(ReductionError::Missing)? {
+        Expression::Fun(arg, body, closed_env) => {
+            let extended = store.cons(*rec_env, closed_env);
+            Ok(store.intern_fun(arg, body, extended))
+        }
+        _ => Err(ReductionError::Opaque),
     }
 }
 
@@ -1750,10 +2717,161 @@ mod test {
         let s = &mut Store::<Fr>::default();
         let expr = "(/ 21 0)";
 
-        let error = s.get_cont_error();
+        let zero = s.num(0);
+        let error = s.intern_cont_error_with(ErrorCode::InvalidArgument, zero);
         test_aux(s, expr, None, None, Some(error), None, 3);
     }
 
+    #[test]
+    fn evaluate_variadic_arithmetic() {
+        let s = &mut Store::<Fr>::default();
+        let t = s.t();
+        let terminal = s.get_cont_terminal();
+
+        let six = s.num(6);
+        test_aux(s, "(+ 1 2 3)", Some(six), None, Some(terminal), None, 4);
+        let four = s.num(4);
+        test_aux(s, "(- 10 1 2 3)", Some(four), None, Some(terminal), None, 5);
+        let twenty_four = s.num(24);
+        test_aux(
+            s,
+            "(* 2 3 4)",
+            Some(twenty_four),
+            None,
+            Some(terminal),
+            None,
+            4,
+        );
+        let two = s.num(2);
+        test_aux(s, "(/ 24 3 4)", Some(two), None, Some(terminal), None, 4);
+        let zero = s.num(0);
+        test_aux(s, "(+)", Some(zero), None, Some(terminal), None, 1);
+        let one = s.num(1);
+        test_aux(s, "(*)", Some(one), None, Some(terminal), None, 1);
+        let five = s.num(5);
+        test_aux(s, "(+ 5)", Some(five), None, Some(terminal), None, 2);
+        test_aux(s, "(* 5)", Some(five), None, Some(terminal), None, 2);
+        let five_u64 = s.uint64(5);
+        test_aux(s, "(+ 5u64)", Some(five_u64), None, Some(terminal), None, 2);
+        test_aux(s, "(* 5u64)", Some(five_u64), None, Some(terminal), None, 2);
+        test_aux(
+            s,
+            "(= (- 5) (- 0 5))",
+            Some(t),
+            None,
+            Some(terminal),
+            None,
+            9,
+        );
+        let one = s.num(1);
+        test_aux(s, "(* 2 (/ 2))", Some(one), None, Some(terminal), None, 6);
+        let ten = s.uint64(10);
+        test_aux(
+            s,
+            "(+ 1u64 2u64 3u64 4u64)",
+            Some(ten),
+            None,
+            Some(terminal),
+            None,
+            5,
+        );
+    }
+
+    #[test]
+    fn evaluate_variadic_arithmetic_error() {
+        let s = &mut Store::<Fr>::default();
+        let zero = s.num(0);
+        let one = s.num(1);
+        let a = s.sym("a");
+        let nil = s.nil();
+
+        for form in ["(-)", "(/)"].iter() {
+            let bad_form = s.read(form).unwrap();
+            let error = s.intern_cont_error_with(ErrorCode::InvalidForm, bad_form);
+            test_aux(s, form, None, None, Some(error), None, 1);
+        }
+        let error = s.intern_cont_error_with(ErrorCode::InvalidArgument, a);
+        test_aux(s, "(+ 1 2 'a)", None, None, Some(error), None, 4);
+        let error = s.intern_cont_error_with(ErrorCode::InvalidArgument, zero);
+        test_aux(s, "(/ 6 2 0)", None, None, Some(error), None, 4);
+        // Only arithmetic operators take more than two arguments.
+        let error = s.intern_cont_error_with(ErrorCode::InvalidArgument, one);
+        test_aux(s, "(cons 1 2 3)", None, None, Some(error), None, 2);
+        // A single argument is still checked to be a number.
+        let error = s.intern_cont_error_with(ErrorCode::InvalidArgument, a);
+        test_aux(s, "(+ 'a)", None, None, Some(error), None, 2);
+        let error = s.intern_cont_error_with(ErrorCode::InvalidArgument, nil);
+        test_aux(s, "(* nil)", None, None, Some(error), None, 2);
+    }
+
+    #[test]
+    fn evaluate_int_quotient_and_modulo() {
+        let s = &mut Store::<Fr>::default();
+        let terminal = s.get_cont_terminal();
+
+        let three = s.num(3);
+        test_aux(s, "(div 7 2)", Some(three), None, Some(terminal), None, 3);
+        let one = s.num(1);
+        test_aux(s, "(% 7 2)", Some(one), None, Some(terminal), None, 3);
+        let zero = s.num(0);
+        test_aux(s, "(div 2 7)", Some(zero), None, Some(terminal), None, 3);
+        let two = s.num(2);
+        test_aux(s, "(% 2 7)", Some(two), None, Some(terminal), None, 3);
+        let three = s.uint64(3);
+        test_aux(
+            s,
+            "(div 7u64 2u64)",
+            Some(three),
+            None,
+            Some(terminal),
+            None,
+            3,
+        );
+
+        // More arguments are folded in from the left, like those of /.
+        let three = s.num(3);
+        test_aux(
+            s,
+            "(div 20 3 2)",
+            Some(three),
+            None,
+            Some(terminal),
+            None,
+            4,
+        );
+        let one = s.num(1);
+        test_aux(s, "(% 7 4 2)", Some(one), None, Some(terminal), None, 4);
+    }
+
+    #[test]
+    fn evaluate_int_quotient_and_modulo_error() {
+        use crate::Num;
+
+        let s = &mut Store::<Fr>::default();
+        let zero = s.num(0);
+        let two = s.num(2);
+        let minus_one = s.num(Num::Scalar(Fr::from(0) - Fr::from(1)));
+        let nil = s.nil();
+
+        // Having no identity, they take at least two arguments.
+        for form in ["(div)", "(div 1)", "(%)", "(% 1)"].iter() {
+            let bad_form = s.read(form).unwrap();
+            let error = s.intern_cont_error_with(ErrorCode::InvalidForm, bad_form);
+            test_aux(s, form, None, None, Some(error), None, 1);
+        }
+
+        let error = s.intern_cont_error_with(ErrorCode::InvalidArgument, zero);
+        test_aux(s, "(div 7 0)", None, None, Some(error), None, 3);
+        test_aux(s, "(% 7 0)", None, None, Some(error), None, 3);
+        let error = s.intern_cont_error_with(ErrorCode::InvalidArgument, nil);
+        test_aux(s, "(% 7 nil)", None, None, Some(error), None, 3);
+        // Numbers which do not fit in a u64 have no integer division.
+        let error = s.intern_cont_error_with(ErrorCode::InvalidArgument, two);
+        test_aux(s, "(div (- 0 1) 2)", None, None, Some(error), None, 6);
+        let error = s.intern_cont_error_with(ErrorCode::InvalidArgument, minus_one);
+        test_aux(s, "(% 7 (- 0 1))", None, None, Some(error), None, 6);
+    }
+
     #[test]
     fn evaluate_num_equal() {
         let s = &mut Store::<Fr>::default();
@@ -1781,29 +2899,197 @@ mod test {
     }
 
     #[test]
-    fn evaluate_adder1() {
+    fn evaluate_num_ordering() {
         let s = &mut Store::<Fr>::default();
-        let expr = "(((lambda (x) (lambda (y) (+ x y))) 2) 3)";
-
-        let expected = s.num(5);
+        let t = s.t();
+        let nil = s.nil();
         let terminal = s.get_cont_terminal();
-        test_aux(s, expr, Some(expected), None, Some(terminal), None, 13);
+
+        test_aux(s, "(< 5 6)", Some(t), None, Some(terminal), None, 3);
+        test_aux(s, "(< 6 5)", Some(nil), None, Some(terminal), None, 3);
+        test_aux(s, "(< 5 5)", Some(nil), None, Some(terminal), None, 3);
+        test_aux(s, "(> 6 5)", Some(t), None, Some(terminal), None, 3);
+        test_aux(s, "(> 5 5)", Some(nil), None, Some(terminal), None, 3);
+        test_aux(s, "(<= 5 5)", Some(t), None, Some(terminal), None, 3);
+        test_aux(s, "(<= 6 5)", Some(nil), None, Some(terminal), None, 3);
+        test_aux(s, "(>= 5 5)", Some(t), None, Some(terminal), None, 3);
+        test_aux(s, "(>= 5 6)", Some(nil), None, Some(terminal), None, 3);
+        test_aux(
+            s,
+            "(< 18446744073709551615 1)",
+            Some(nil),
+            None,
+            Some(terminal),
+            None,
+            3,
+        );
     }
 
-    // Enable this when we have LET.
     #[test]
-    fn evaluate_adder2() {
+    fn evaluate_num_ordering_error() {
+        use crate::Num;
+
         let s = &mut Store::<Fr>::default();
-        let expr = "(let ((make-adder (lambda (x) (lambda (y) (+ x y)))))
-                   ((make-adder 2) 3))";
+        let five = s.num(5);
+        let too_big = s.num(Num::Scalar(Fr::from(u64::MAX) + Fr::from(1)));
+        let nil = s.nil();
 
-        let expected = s.num(5);
-        let terminal = s.get_cont_terminal();
-        test_aux(s, expr, Some(expected), None, Some(terminal), None, 15);
+        let error = s.intern_cont_error_with(ErrorCode::InvalidArgument, nil);
+        test_aux(s, "(< 5 nil)", None, None, Some(error), None, 3);
+        let error = s.intern_cont_error_with(ErrorCode::InvalidArgument, five);
+        test_aux(s, "(>= 'a 5)", None, None, Some(error), None, 3);
+        // Numbers which do not fit in a u64 have no ordering.
+        test_aux(s, "(< (- 0 1) 5)", None, None, Some(error), None, 6);
+        let error = s.intern_cont_error_with(ErrorCode::InvalidArgument, too_big);
+        test_aux(
+            s,
+            "(> 5 (+ 18446744073709551615 1))",
+            None,
+            None,
+            Some(error),
+            None,
+            6,
+        );
     }
 
     #[test]
-    fn evaluate_let_simple() {
+    fn evaluate_chained_relations() {
+        let s = &mut Store::<Fr>::default();
+        let t = s.t();
+        let nil = s.nil();
+        let terminal = s.get_cont_terminal();
+
+        test_aux(s, "(= 1 1 1)", Some(t), None, Some(terminal), None, 4);
+        test_aux(s, "(= 1 1 2)", Some(nil), None, Some(terminal), None, 4);
+        test_aux(s, "(eq 'a 'a 'a)", Some(t), None, Some(terminal), None, 4);
+        test_aux(s, "(< 1 2 3)", Some(t), None, Some(terminal), None, 4);
+        test_aux(s, "(<= 1 3 2)", Some(nil), None, Some(terminal), None, 4);
+        // Remaining arguments are not evaluated once a relation fails.
+        test_aux(
+            s,
+            "(= 1 2 (car 3))",
+            Some(nil),
+            None,
+            Some(terminal),
+            None,
+            3,
+        );
+        test_aux(s, "(=)", Some(t), None, Some(terminal), None, 1);
+        test_aux(s, "(< 5)", Some(t), None, Some(terminal), None, 2);
+        test_aux(s, "(eq 5)", Some(t), None, Some(terminal), None, 2);
+        test_aux(s, "(eq 'a)", Some(t), None, Some(terminal), None, 2);
+        // A single argument is still evaluated, and must be a number unless the relation is EQ.
+        let a = s.sym("a");
+        let error = s.intern_cont_error_with(ErrorCode::InvalidArgument, a);
+        test_aux(s, "(= 'a)", None, None, Some(error), None, 2);
+    }
+
+    #[test]
+    fn evaluate_u64() {
+        let s = &mut Store::<Fr>::default();
+        let t = s.t();
+        let nil = s.nil();
+        let terminal = s.get_cont_terminal();
+
+        let three = s.uint64(3);
+        test_aux(
+            s,
+            "(+ 1u64 2u64)",
+            Some(three),
+            None,
+            Some(terminal),
+            None,
+            3,
+        );
+        let max = s.uint64(u64::MAX);
+        test_aux(s, "(- 0u64 1u64)", Some(max), None, Some(terminal), None, 3);
+        let max_minus_one = s.uint64(u64::MAX - 1);
+        test_aux(
+            s,
+            "(* 18446744073709551615u64 2u64)",
+            Some(max_minus_one),
+            None,
+            Some(terminal),
+            None,
+            3,
+        );
+        test_aux(
+            s,
+            "(/ 7u64 2u64)",
+            Some(three),
+            None,
+            Some(terminal),
+            None,
+            3,
+        );
+        let one = s.uint64(1);
+        test_aux(s, "(% 7u64 2u64)", Some(one), None, Some(terminal), None, 3);
+
+        let five = s.uint64(5);
+        test_aux(s, "(u64 5)", Some(five), None, Some(terminal), None, 2);
+        test_aux(s, "(u64 5u64)", Some(five), None, Some(terminal), None, 2);
+        let five_num = s.num(5);
+        test_aux(
+            s,
+            "(num 5u64)",
+            Some(five_num),
+            None,
+            Some(terminal),
+            None,
+            2,
+        );
+
+        test_aux(s, "(= 5u64 5u64)", Some(t), None, Some(terminal), None, 3);
+        test_aux(s, "(< 5u64 6u64)", Some(t), None, Some(terminal), None, 3);
+        test_aux(s, "(eq 5u64 5)", Some(nil), None, Some(terminal), None, 3);
+    }
+
+    #[test]
+    fn evaluate_u64_error() {
+        use crate::Num;
+
+        let s = &mut Store::<Fr>::default();
+        let zero = s.uint64(0);
+        let one = s.num(1);
+        let minus_one = s.num(Num::Scalar(Fr::from(0) - Fr::from(1)));
+        let a = s.sym("a");
+
+        let error = s.intern_cont_error_with(ErrorCode::InvalidArgument, zero);
+        test_aux(s, "(/ 1u64 0u64)", None, None, Some(error), None, 3);
+        test_aux(s, "(% 1u64 0u64)", None, None, Some(error), None, 3);
+        let error = s.intern_cont_error_with(ErrorCode::InvalidArgument, one);
+        test_aux(s, "(+ 1u64 1)", None, None, Some(error), None, 3);
+        test_aux(s, "(= 1u64 1)", None, None, Some(error), None, 3);
+        let error = s.intern_cont_error_with(ErrorCode::InvalidArgument, minus_one);
+        test_aux(s, "(u64 (- 0 1))", None, None, Some(error), None, 5);
+        let error = s.intern_cont_error_with(ErrorCode::InvalidArgument, a);
+        test_aux(s, "(u64 'a)", None, None, Some(error), None, 2);
+    }
+
+    #[test]
+    fn evaluate_adder1() {
+        let s = &mut Store::<Fr>::default();
+        let expr = "(((lambda (x) (lambda (y) (+ x y))) 2) 3)";
+
+        let expected = s.num(5);
+        let terminal = s.get_cont_terminal();
+        test_aux(s, expr, Some(expected), None, Some(terminal), None, 13);
+    }
+
+    // Enable this when we have LET.
+    #[test]
+    fn evaluate_adder2() {
+        let s = &mut Store::<Fr>::default();
+        let expr = "(let ((make-adder (lambda (x) (lambda (y) (+ x y)))))
+                   ((make-adder 2) 3))";
+
+        let expected = s.num(5);
+        let terminal = s.get_cont_terminal();
+        test_aux(s, expr, Some(expected), None, Some(terminal), None, 15);
+    }
+
+    #[test]
+    fn evaluate_let_simple() {
         let s = &mut Store::<Fr>::default();
         let expr = "(let ((a 1)) a)";
 
@@ -1839,7 +3125,8 @@ mod test {
         let s = &mut Store::<Fr>::default();
         let expr = "(let)";
 
-        let error = s.get_cont_error();
+        let form = s.read(expr).unwrap();
+        let error = s.intern_cont_error_with(ErrorCode::InvalidForm, form);
         test_aux(s, expr, None, None, Some(error), None, 1);
     }
 
@@ -1848,16 +3135,180 @@ mod test {
         let s = &mut Store::<Fr>::default();
         let expr = "(let ((a 1)))";
 
-        let error = s.get_cont_error();
+        let form = s.read(expr).unwrap();
+        let error = s.intern_cont_error_with(ErrorCode::InvalidForm, form);
         test_aux(s, expr, None, None, Some(error), None, 1);
     }
 
+    #[test]
+    fn evaluate_and() {
+        let s = &mut Store::<Fr>::default();
+        let t = s.t();
+        let nil = s.nil();
+        let two = s.num(2);
+        let terminal = s.get_cont_terminal();
+
+        test_aux(s, "(and)", Some(t), None, Some(terminal), None, 1);
+        test_aux(s, "(and 2)", Some(two), None, Some(terminal), None, 2);
+        test_aux(s, "(and 1 2)", Some(two), None, Some(terminal), None, 3);
+        test_aux(s, "(and 1 nil 2)", Some(nil), None, Some(terminal), None, 3);
+        // Evaluation stops at the first NIL.
+        test_aux(
+            s,
+            "(and nil (/ 1 0))",
+            Some(nil),
+            None,
+            Some(terminal),
+            None,
+            2,
+        );
+    }
+
+    #[test]
+    fn evaluate_or() {
+        let s = &mut Store::<Fr>::default();
+        let nil = s.nil();
+        let one = s.num(1);
+        let two = s.num(2);
+        let terminal = s.get_cont_terminal();
+
+        test_aux(s, "(or)", Some(nil), None, Some(terminal), None, 1);
+        test_aux(s, "(or nil 2)", Some(two), None, Some(terminal), None, 3);
+        test_aux(s, "(or nil nil)", Some(nil), None, Some(terminal), None, 3);
+        // Evaluation stops at the first value which is not NIL.
+        test_aux(
+            s,
+            "(or 1 (/ 1 0))",
+            Some(one),
+            None,
+            Some(terminal),
+            None,
+            2,
+        );
+        test_aux(
+            s,
+            "(or nil (emit 1) (emit 2))",
+            Some(one),
+            None,
+            Some(terminal),
+            Some(vec![one]),
+            6,
+        );
+    }
+
+    #[test]
+    fn evaluate_cond() {
+        let s = &mut Store::<Fr>::default();
+        let nil = s.nil();
+        let two = s.num(2);
+        let three = s.num(3);
+        let terminal = s.get_cont_terminal();
+
+        test_aux(s, "(cond)", Some(nil), None, Some(terminal), None, 1);
+        test_aux(
+            s,
+            "(cond (nil 1))",
+            Some(nil),
+            None,
+            Some(terminal),
+            None,
+            3,
+        );
+        test_aux(
+            s,
+            "(cond (nil 1) (t 2))",
+            Some(two),
+            None,
+            Some(terminal),
+            None,
+            6,
+        );
+        test_aux(
+            s,
+            "(cond (nil 1) (t 2 3))",
+            Some(three),
+            None,
+            Some(terminal),
+            None,
+            7,
+        );
+        test_aux(
+            s,
+            "(cond ((= 1 2) 1) (t 2))",
+            Some(two),
+            None,
+            Some(terminal),
+            None,
+            9,
+        );
+        // A clause without a body returns the value of its test.
+        let five = s.num(5);
+        test_aux(
+            s,
+            "(cond (nil) (5))",
+            Some(five),
+            None,
+            Some(terminal),
+            None,
+            4,
+        );
+        test_aux(
+            s,
+            "(cond ((+ 1 2)) (t 4))",
+            Some(three),
+            None,
+            Some(terminal),
+            None,
+            5,
+        );
+    }
+
+    #[test]
+    fn evaluate_let_implicit_begin() {
+        let s = &mut Store::<Fr>::default();
+        let expr = "(let ((a 1)) (emit a) (+ a 1))";
+
+        let expected = s.num(2);
+        let emitted = vec![s.num(1)];
+        let terminal = s.get_cont_terminal();
+        test_aux(
+            s,
+            expr,
+            Some(expected),
+            None,
+            Some(terminal),
+            Some(emitted),
+            10,
+        );
+    }
+
+    #[test]
+    fn evaluate_letrec_implicit_begin() {
+        let s = &mut Store::<Fr>::default();
+        let expr = "(letrec ((a 1)) a 2)";
+
+        let expected = s.num(2);
+        let terminal = s.get_cont_terminal();
+        test_aux(s, expr, Some(expected), None, Some(terminal), None, 5);
+    }
+
+    #[test]
+    fn evaluate_lambda_implicit_begin() {
+        let s = &mut Store::<Fr>::default();
+        let expr = "((lambda (x) x 2) 1)";
+
+        let expected = s.num(2);
+        let terminal = s.get_cont_terminal();
+        test_aux(s, expr, Some(expected), None, Some(terminal), None, 6);
+    }
+
     #[test]
     fn evaluate_letrec_empty_error() {
         let s = &mut Store::<Fr>::default();
         let expr = "(letrec)";
 
-        let error = s.get_cont_error();
+        let form = s.read(expr).unwrap();
+        let error = s.intern_cont_error_with(ErrorCode::InvalidForm, form);
         test_aux(s, expr, None, None, Some(error), None, 1);
     }
 
@@ -1866,7 +3317,8 @@ mod test {
         let s = &mut Store::<Fr>::default();
         let expr = "(letrec ((a 1)))";
 
-        let error = s.get_cont_error();
+        let form = s.read(expr).unwrap();
+        let error = s.intern_cont_error_with(ErrorCode::InvalidForm, form);
         test_aux(s, expr, None, None, Some(error), None, 1);
     }
 
@@ -1923,10 +3375,11 @@ mod test {
                             (false (lambda (a)
                                      (lambda (b)
                                       b)))
+                            ;; NOTE: We cannot bind COND because it is built-in.
                             (iff (lambda (a)
                                    (lambda (b)
-                                     (lambda (cond)
-                                       ((cond a) b))))))
+                                     (lambda (c)
+                                       ((c a) b))))))
                        (((iff 5) 6) true))";
 
             let expected = s.num(5);
@@ -1941,10 +3394,11 @@ mod test {
                             (false (lambda (a)
                                   (lambda (b)
                                    b)))
+                            ;; NOTE: We cannot bind COND because it is built-in.
                             (iff (lambda (a)
                                    (lambda (b)
-                                     (lambda (cond)
-                                       ((cond a) b))))))
+                                     (lambda (c)
+                                       ((c a) b))))))
                        (((iff 5) 6) false))";
 
             let expected = s.num(6);
@@ -1953,6 +3407,33 @@ mod test {
         }
     }
 
+    #[test]
+    fn evaluate_bound_cond_is_still_special() {
+        // COND is a special form wherever it heads a call, even where it is bound as a
+        // variable, so this program from before COND existed no longer calls C.
+        let s = &mut Store::<Fr>::default();
+        let expr = s
+            .read(
+                "(let ((true (lambda (a)
+                               (lambda (b)
+                                 a)))
+                       (iff (lambda (a)
+                              (lambda (b)
+                                (lambda (cond)
+                                  ((cond a) b))))))
+                  (((iff 5) 6) true))",
+            )
+            .unwrap();
+        let env = empty_sym_env(s);
+        let (output, _iterations, _emitted) = Evaluator::new(expr, env, s, 1000).eval();
+
+        // (COND A) is (COND (NIL)), whose value is NIL, and NIL is not a function.
+        let nil = s.nil();
+        let error = s.intern_cont_error_with(ErrorCode::InvalidCall, nil);
+        assert_eq!(nil, output.expr);
+        assert_eq!(error, output.cont);
+    }
+
     #[test]
     fn evaluate_if() {
         {
@@ -2042,165 +3523,1090 @@ mod test {
                                         base-inner))))
                     ((exp 5) 3))";
 
-        let expected = s.num(125);
-        let terminal = s.get_cont_terminal();
-        test_aux(s, expr, Some(expected), None, Some(terminal), None, 75);
+        let expected = s.num(125);
+        let terminal = s.get_cont_terminal();
+        test_aux(s, expr, Some(expected), None, Some(terminal), None, 75);
+    }
+
+    #[test]
+    fn evaluate_tail_recursion() {
+        let s = &mut Store::<Fr>::default();
+        let expr = "(letrec ((exp (lambda (base)
+                                  (lambda (exponent-remaining)
+                                    (lambda (acc)
+                                      (if (= 0 exponent-remaining)
+                                          acc
+                                          (((exp base) (- exponent-remaining 1)) (* acc base))))))))
+                          (((exp 5) 3) 1))";
+
+        let expected = s.num(125);
+        let terminal = s.get_cont_terminal();
+        test_aux(s, expr, Some(expected), None, Some(terminal), None, 129);
+    }
+
+    #[test]
+    fn evaluate_tail_recursion_somewhat_optimized() {
+        let s = &mut Store::<Fr>::default();
+        let expr =
+                "(letrec ((exp (lambda (base)
+                             (letrec ((base-inner
+                                        (lambda (exponent-remaining)
+                                          (lambda (acc)
+                                            (if (= 0 exponent-remaining)
+                                              acc
+                                             ((base-inner (- exponent-remaining 1)) (* acc base)))))))
+                                      base-inner))))
+                   (((exp 5) 3) 1))";
+
+        let expected = s.num(125);
+        let terminal = s.get_cont_terminal();
+        test_aux(s, expr, Some(expected), None, Some(terminal), None, 110);
+    }
+
+    #[test]
+    fn evaluate_multiple_letrec_bindings() {
+        let s = &mut Store::<Fr>::default();
+        let expr = "(letrec ((double (lambda (x) (* 2 x)))
+                           (square (lambda (x) (* x x))))
+                   (+ (square 3) (double 2)))";
+
+        let expected = s.num(13);
+        let terminal = s.get_cont_terminal();
+        test_aux(s, expr, Some(expected), None, Some(terminal), None, 22);
+    }
+
+    #[test]
+    fn evaluate_multiple_letrec_bindings_referencing() {
+        let s = &mut Store::<Fr>::default();
+        let expr = "(letrec ((double (lambda (x) (* 2 x)))
+                           (double-inc (lambda (x) (+ 1 (double x)))))
+                   (+ (double 3) (double-inc 2)))";
+
+        let expected = s.num(11);
+        let terminal = s.get_cont_terminal();
+        test_aux(s, expr, Some(expected), None, Some(terminal), None, 31);
+    }
+
+    #[test]
+    fn evaluate_multiple_letrec_bindings_recursive() {
+        let s = &mut Store::<Fr>::default();
+        let expr = "(letrec ((exp (lambda (base exponent)
+                                  (if (= 0 exponent)
+                                      1
+                                      (* base (exp base (- exponent 1))))))
+                           (exp2 (lambda (base exponent)
+                                  (if (= 0 exponent)
+                                      1
+                                      (* base (exp2 base (- exponent 1))))))
+                           (exp3 (lambda (base exponent)
+                                  (if (= 0 exponent)
+                                      1
+                                      (* base (exp3 base (- exponent 1)))))))
+                   (+ (+ (exp 3 2) (exp2 2 3))
+                      (exp3 4 2)))";
+
+        let expected = s.num(33);
+        let terminal = s.get_cont_terminal();
+        test_aux(s, expr, Some(expected), None, Some(terminal), None, 242);
+    }
+
+    #[test]
+    fn evaluate_eq() {
+        {
+            let s = &mut Store::<Fr>::default();
+            let expr = "(eq 'a 'a)";
+
+            let expected = s.t();
+            let terminal = s.get_cont_terminal();
+            test_aux(s, expr, Some(expected), None, Some(terminal), None, 3);
+        }
+        {
+            let s = &mut Store::<Fr>::default();
+            let expr = "(eq 'a 1)";
+
+            let expected = s.nil();
+            let terminal = s.get_cont_terminal();
+            test_aux(s, expr, Some(expected), None, Some(terminal), None, 3);
+        }
+    }
+    #[test]
+    fn evaluate_zero_arg_lambda() {
+        {
+            let s = &mut Store::<Fr>::default();
+            let expr = "((lambda () 123))";
+
+            let expected = s.num(123);
+            let terminal = s.get_cont_terminal();
+            test_aux(s, expr, Some(expected), None, Some(terminal), None, 3);
+        }
+        {
+            let s = &mut Store::<Fr>::default();
+            let expr = "(letrec ((x 9) (f (lambda () (+ x 1)))) (f))";
+
+            let expected = s.num(10);
+            let terminal = s.get_cont_terminal();
+            test_aux(s, expr, Some(expected), None, Some(terminal), None, 12);
+        }
+    }
+
+    #[test]
+    fn evaluate_zero_arg_lambda_variants() {
+        {
+            let mut s = Store::<Fr>::default();
+            let limit = 20;
+            let expr = s.read("((lambda (x) 123))").unwrap();
+
+            let (
+                IO {
+                    expr: result_expr,
+                    env: _new_env,
+                    cont: _continuation,
+                },
+                iterations,
+                _emitted,
+            ) = Evaluator::new(expr, empty_sym_env(&s), &mut s, limit).eval();
+
+            assert_eq!(crate::store::Tag::Fun, result_expr.tag());
+            assert_eq!(3, iterations);
+        }
+        {
+            let s = &mut Store::<Fr>::default();
+            let expr = "((lambda () 123) 1)";
+
+            let one = s.num(1);
+            let error = s.intern_cont_error_with(ErrorCode::InvalidCall, one);
+            test_aux(s, expr, None, None, Some(error), None, 3);
+        }
+        {
+            let s = &mut Store::<Fr>::default();
+            let expr = "(123)";
+
+            let num = s.num(123);
+            let error = s.intern_cont_error_with(ErrorCode::InvalidCall, num);
+            test_aux(s, expr, None, None, Some(error), None, 2);
+        }
+    }
+
+    #[test]
+    fn evaluate_variadic_lambda() {
+        let s = &mut Store::<Fr>::default();
+        let nil = s.nil();
+        let one = s.num(1);
+        let two = s.num(2);
+        let three = s.num(3);
+        let one_two = s.list(&[one, two]);
+        let just_two = s.list(&[two]);
+        let two_three = s.list(&[two, three]);
+        let one_two_three = s.list(&[one, two, three]);
+        let terminal = s.get_cont_terminal();
+
+        test_aux(
+            s,
+            "((lambda xs xs))",
+            Some(nil),
+            None,
+            Some(terminal),
+            None,
+            3,
+        );
+        test_aux(
+            s,
+            "((lambda xs xs) 1 2 3)",
+            Some(one_two_three),
+            None,
+            Some(terminal),
+            None,
+            16,
+        );
+        test_aux(
+            s,
+            "((lambda (a . rest) rest) 1 2 3)",
+            Some(two_three),
+            None,
+            Some(terminal),
+            None,
+            16,
+        );
+        test_aux(
+            s,
+            "((lambda (a . rest) rest) 1 2)",
+            Some(just_two),
+            None,
+            Some(terminal),
+            None,
+            11,
+        );
+        // Arguments are evaluated from left to right.
+        test_aux(
+            s,
+            "((lambda xs xs) (emit 1) (emit 2))",
+            Some(one_two),
+            None,
+            Some(terminal),
+            Some(vec![one, two]),
+            17,
+        );
+    }
+
+    #[test]
+    fn evaluate_variadic_lambda_currying() {
+        let s = &mut Store::<Fr>::default();
+        let nil = s.nil();
+        let one = s.num(1);
+        let two = s.num(2);
+        let one_two = s.list(&[one, two]);
+        let terminal = s.get_cont_terminal();
+        let error = s.intern_cont_error_with(ErrorCode::InvalidCall, one_two);
+
+        // The rest parameter only gets the arguments of its own call, so the list is then called
+        // with 3.
+        test_aux(
+            s,
+            "(((lambda xs xs) 1 2) 3)",
+            Some(one_two),
+            None,
+            Some(error),
+            None,
+            13,
+        );
+        // Neither a call nor APPLY in the function position of an enclosing call gives its
+        // argument to the rest parameter.
+        test_aux(
+            s,
+            "(((lambda x (lambda (y) y)) 1) 2)",
+            Some(two),
+            None,
+            Some(terminal),
+            None,
+            10,
+        );
+        test_aux(
+            s,
+            "((apply (lambda x (lambda (y) y)) '(1)) 2)",
+            Some(two),
+            None,
+            Some(terminal),
+            None,
+            13,
+        );
+        // Supplying only the fixed arguments returns the variadic function which remains, and
+        // calling that with no arguments binds the rest parameter to NIL.
+        {
+            let mut s = Store::<Fr>::default();
+            let expr = s.read("((lambda (a . rest) rest) 1)").unwrap();
+
+            let (
+                IO {
+                    expr: result_expr,
+                    env: _new_env,
+                    cont: _continuation,
+                },
+                iterations,
+                _emitted,
+            ) = Evaluator::new(expr, empty_sym_env(&s), &mut s, 20).eval();
+
+            assert_eq!(crate::store::Tag::Fun, result_expr.tag());
+            assert_eq!(4, iterations);
+        }
+        test_aux(
+            s,
+            "(((lambda (a . rest) rest) 1))",
+            Some(nil),
+            None,
+            Some(terminal),
+            None,
+            7,
+        );
+    }
+
+    #[test]
+    fn evaluate_eval() {
+        let s = &mut Store::<Fr>::default();
+        let one = s.num(1);
+        let three = s.num(3);
+        let x = s.sym("x");
+        let terminal = s.get_cont_terminal();
+
+        test_aux(
+            s,
+            "(eval '(+ 1 2))",
+            Some(three),
+            None,
+            Some(terminal),
+            None,
+            9,
+        );
+        test_aux(
+            s,
+            "(let ((x 1)) (eval 'x (current-env)))",
+            Some(one),
+            None,
+            Some(terminal),
+            None,
+            8,
+        );
+        // Without an environment, the expression is evaluated in the empty one.
+        let error = s.intern_cont_error_with(ErrorCode::UnboundVariable, x);
+        test_aux(
+            s,
+            "(let ((x 1)) (eval 'x))",
+            None,
+            None,
+            Some(error),
+            None,
+            8,
+        );
+        for form in ["(eval)", "(eval 'x (current-env) 1)"].iter() {
+            let bad_form = s.read(form).unwrap();
+            let error = s.intern_cont_error_with(ErrorCode::InvalidForm, bad_form);
+            test_aux(s, form, None, None, Some(error), None, 1);
+        }
+    }
+
+    #[test]
+    fn evaluate_error_codes() {
+        let s = &mut Store::<Fr>::default();
+        let one = s.num(1);
+        let x = s.sym("x");
+        let bad_form = s.read("(current-env 1)").unwrap();
+
+        let unbound = s.intern_cont_error_with(ErrorCode::UnboundVariable, x);
+        test_aux(s, "x", Some(x), None, Some(unbound), None, 1);
+        test_aux(
+            s,
+            "(let ((x 1)) (eval 'x))",
+            Some(x),
+            None,
+            Some(unbound),
+            None,
+            8,
+        );
+
+        let invalid_form = s.intern_cont_error_with(ErrorCode::InvalidForm, bad_form);
+        test_aux(
+            s,
+            "(current-env 1)",
+            Some(bad_form),
+            None,
+            Some(invalid_form),
+            None,
+            1,
+        );
+
+        let invalid_call = s.intern_cont_error_with(ErrorCode::InvalidCall, one);
+        test_aux(s, "(1 2)", Some(one), None, Some(invalid_call), None, 2);
+
+        let invalid_argument = s.intern_cont_error_with(ErrorCode::InvalidArgument, one);
+        test_aux(
+            s,
+            "(car 1)",
+            Some(one),
+            None,
+            Some(invalid_argument),
+            None,
+            2,
+        );
+    }
+
+    #[test]
+    fn evaluate_malformed_forms_without_panicking() {
+        let s = &mut Store::<Fr>::default();
+        let limit = 1000;
+        let env = empty_sym_env(s);
+
+        let opaque_cons = s.intern_opaque_cons(Fr::from(123));
+        let opaque_fun = s.intern_opaque_fun(Fr::from(123));
+        let car = s.sym("car");
+        let quoted = s.quoted(opaque_cons);
+        let car_opaque = s.list(&[car, quoted]);
+        let call_opaque = s.list(&[opaque_fun]);
+
+        let mut errors = Vec::new();
+        for src in [
+            // Values which cannot be used.
+            "(secret 1)",
+            "(char 1114112)",
+            // Malformed environments.
+            "(eval 'x '((1 . 2)))",
+            "(eval '(letrec ((f 1)) f) '((1 . 2)))",
+        ] {
+            errors.push(s.read(src).unwrap());
+        }
+        for expr in errors {
+            let (output, _, _) = Evaluator::new(expr, env, s, limit).try_eval().unwrap();
+            assert!(output.cont.is_error(), "{}", expr.fmt_to_string(s));
+        }
+
+        // Opaque data cannot be taken apart, called or opened, and evaluation stops where it
+        // would have to be.
+        let mut opaque = vec![opaque_cons, car_opaque, call_opaque];
+        for src in ["(open 123)", "(secret (comm 123))"] {
+            opaque.push(s.read(src).unwrap());
+        }
+        for expr in opaque {
+            assert_eq!(
+                Err(ReductionError::Opaque),
+                Evaluator::new(expr, env, s, limit).try_eval(),
+                "{}",
+                expr.fmt_to_string(s)
+            );
+            let (output, _, _) = Evaluator::new(expr, env, s, limit).eval();
+            assert!(!output.is_complete(), "{}", expr.fmt_to_string(s));
+        }
+    }
+
+    #[test]
+    fn evaluate_improper_forms_as_nil_terminated() {
+        let s = &mut Store::<Fr>::default();
+        let limit = 1000;
+        let env = empty_sym_env(s);
+
+        // As in the circuit, a value which is not a list is destructured as `nil`, so the tail of
+        // an improper form is ignored.
+        for (improper, nil_terminated) in [
+            ("(car . 1)", "(car)"),
+            ("(commit . 1)", "(commit)"),
+            ("(if . 5)", "(if)"),
+            ("(quote . 5)", "(quote)"),
+            ("(let ((x . 1)) x)", "(let ((x)) x)"),
+        ] {
+            let improper = s.read(improper).unwrap();
+            let nil_terminated = s.read(nil_terminated).unwrap();
+            assert_eq!(
+                Evaluator::new(nil_terminated, env, s, limit).eval(),
+                Evaluator::new(improper, env, s, limit).eval()
+            );
+        }
+    }
+
+    #[test]
+    fn evaluate_quasiquote() {
+        let s = &mut Store::<Fr>::default();
+        let limit = 1000;
+        let env = empty_sym_env(s);
+
+        // A template evaluates exactly like the expression it expands to.
+        for (template, hand_written, expected) in [
+            (
+                "(let ((b 2)) `(a ,b))",
+                "(let ((b 2)) (cons 'a (cons b nil)))",
+                "(a 2)",
+            ),
+            (
+                "(let ((b 2)) `((a . ,b) ,(+ b 1)))",
+                "(let ((b 2)) (cons (cons 'a b) (cons (+ b 1) nil)))",
+                "((a . 2) 3)",
+            ),
+        ] {
+            let template = s.read(template).unwrap();
+            let hand_written = s.read(hand_written).unwrap();
+            let expected = s.read(expected).unwrap();
+
+            let (output, iterations, _) = Evaluator::new(template, env, s, limit).eval();
+            let (hand_written_output, hand_written_iterations, _) =
+                Evaluator::new(hand_written, env, s, limit).eval();
+
+            assert_eq!(expected, output.expr);
+            assert_eq!(hand_written_output, output);
+            assert_eq!(hand_written_iterations, iterations);
+        }
+
+        for (template, expected) in [
+            ("(let ((b '(2 3))) `(a ,@b))", "(a 2 3)"),
+            ("(let ((b '(2 3))) `(a ,@b 4))", "(a 2 3 4)"),
+            ("(let ((b nil)) `(a ,@b 4))", "(a 4)"),
+            ("`(a `(b ,(c ,(+ 1 2))))", "(a `(b ,(c 3)))"),
+        ] {
+            let template = s.read(template).unwrap();
+            let expected = s.read(expected).unwrap();
+            let (output, _, _) = Evaluator::new(template, env, s, limit).eval();
+            assert_eq!(expected, output.expr);
+        }
+    }
+
+    #[test]
+    fn evaluate_macrolet() {
+        let s = &mut Store::<Fr>::default();
+        let limit = 1000;
+        let env = empty_sym_env(s);
+
+        let program = s
+            .read(
+                "(macrolet ((unless (c x y) `(if ,c ,y ,x)))
+                   (let ((a 1)) (unless (= a 1) 2 3)))",
+            )
+            .unwrap();
+        let hand_written = s.read("(let ((a 1)) (if (= a 1) 3 2))").unwrap();
+        let expected = s.num(3);
+
+        // A macro call evaluates exactly like its expansion.
+        let (output, iterations, _) = Evaluator::new(program, env, s, limit).eval();
+        let (hand_written_output, hand_written_iterations, _) =
+            Evaluator::new(hand_written, env, s, limit).eval();
+
+        assert_eq!(expected, output.expr);
+        assert_eq!(hand_written_output, output);
+        assert_eq!(hand_written_iterations, iterations);
+
+        // A macro call which cannot be expanded makes the whole program an invalid form, before
+        // any of it is evaluated.
+        let call = s.read("(bad 1)").unwrap();
+        let error = s.intern_cont_error_with(ErrorCode::InvalidForm, call);
+        test_aux(
+            s,
+            "(+ 1 (macrolet ((bad (x) (car x))) (bad 1)))",
+            Some(call),
+            None,
+            Some(error),
+            None,
+            0,
+        );
+    }
+
+    #[test]
+    fn evaluate_type_of() {
+        let s = &mut Store::<Fr>::default();
+        let terminal = s.get_cont_terminal();
+
+        for (expr, keyword) in [
+            ("(type-of nil)", ":nil"),
+            ("(type-of '(1 2))", ":cons"),
+            ("(type-of 'a)", ":sym"),
+            ("(type-of (lambda (x) x))", ":fun"),
+            ("(type-of 1)", ":num"),
+            ("(type-of \"abc\")", ":str"),
+            ("(type-of #\\a)", ":char"),
+            ("(type-of 1u64)", ":u64"),
+        ] {
+            let expected = s.sym(keyword);
+            test_aux(s, expr, Some(expected), None, Some(terminal), None, 2);
+        }
+
+        let t = s.t();
+        let nil = s.nil();
+        for (expr, expected) in [
+            ("(nil? nil)", t),
+            ("(nil? '(1))", nil),
+            ("(cons? '(1))", t),
+            ("(cons? nil)", nil),
+            ("(sym? 'a)", t),
+            ("(sym? \"a\")", nil),
+            ("(fun? (lambda (x) x))", t),
+            ("(num? 1)", t),
+            ("(num? 1u64)", nil),
+            ("(str? \"a\")", t),
+            ("(char? #\\a)", t),
+            ("(u64? 1u64)", t),
+        ] {
+            test_aux(s, expr, Some(expected), None, Some(terminal), None, 2);
+        }
+
+        // The commitment is returned to TYPE-OF and COMM? through a thunk.
+        let comm = s.sym(":comm");
+        test_aux(
+            s,
+            "(type-of (commit 1))",
+            Some(comm),
+            None,
+            Some(terminal),
+            None,
+            4,
+        );
+        test_aux(
+            s,
+            "(comm? (commit 1))",
+            Some(t),
+            None,
+            Some(terminal),
+            None,
+            4,
+        );
+
+        let bad_form = s.read("(type-of 1 2)").unwrap();
+        let invalid_form = s.intern_cont_error_with(ErrorCode::InvalidForm, bad_form);
+        test_aux(
+            s,
+            "(type-of 1 2)",
+            Some(bad_form),
+            None,
+            Some(invalid_form),
+            None,
+            1,
+        );
+    }
+
+    #[test]
+    fn evaluate_fail_assert() {
+        let s = &mut Store::<Fr>::default();
+        let t = s.t();
+        let num = s.num(42);
+        let condition = s.read("(= 1 2)").unwrap();
+        let terminal = s.get_cont_terminal();
+
+        test_aux(
+            s,
+            "(assert (= 1 1))",
+            Some(t),
+            None,
+            Some(terminal),
+            None,
+            6,
+        );
+
+        let failed_assert = s.intern_cont_error_with(ErrorCode::UserFailure, condition);
+        test_aux(
+            s,
+            "(assert (= 1 2))",
+            Some(condition),
+            None,
+            Some(failed_assert),
+            None,
+            7,
+        );
+
+        let failure = s.intern_cont_error_with(ErrorCode::UserFailure, num);
+        test_aux(s, "(fail 42)", Some(num), None, Some(failure), None, 2);
+
+        for bad in ["(fail)", "(fail 1 2)", "(assert)", "(assert t nil)"] {
+            let form = s.read(bad).unwrap();
+            let invalid_form = s.intern_cont_error_with(ErrorCode::InvalidForm, form);
+            test_aux(s, bad, Some(form), None, Some(invalid_form), None, 1);
+        }
+    }
+
+    #[test]
+    fn evaluate_apply() {
+        let s = &mut Store::<Fr>::default();
+        let limit = 1000;
+        let env = empty_sym_env(s);
+
+        let expected = s.num(3);
+        test_aux(
+            s,
+            "(apply (lambda (a b) (+ a b)) '(1 2))",
+            Some(expected),
+            None,
+            None,
+            None,
+            13,
+        );
+        let expected = s.num(7);
+        test_aux(
+            s,
+            "(apply (lambda () 7) nil)",
+            Some(expected),
+            None,
+            None,
+            None,
+            7,
+        );
+        // A variadic function collects all the arguments.
+        let expected = s.read("(1 2 3)").unwrap();
+        test_aux(
+            s,
+            "(apply (lambda args args) '(1 2 3))",
+            Some(expected),
+            None,
+            None,
+            None,
+            15,
+        );
+
+        // APPLY evaluates both its arguments.
+        let program = s
+            .read(
+                "(let ((f (lambda (a b c) (+ a (* b c))))
+                       (args (cons 2 '(3 4))))
+                   (apply f args))",
+            )
+            .unwrap();
+        let (output, _, _) = Evaluator::new(program, env, s, limit).eval();
+        assert_eq!(s.num(14), output.expr);
+
+        // Applying a non-function, or to an improper list, is a bad call of (FN . ARGS).
+        let fun_and_args = s.read("(1 2)").unwrap();
+        let invalid_call = s.intern_cont_error_with(ErrorCode::InvalidCall, fun_and_args);
+        test_aux(
+            s,
+            "(apply 1 '(2))",
+            Some(fun_and_args),
+            None,
+            Some(invalid_call),
+            None,
+            5,
+        );
+        for (bad, fun_and_args) in [
+            ("(apply (lambda (x) x) 2)", "(cons (lambda (x) x) 2)"),
+            (
+                "(apply (lambda (x y) y) '(1 . 2))",
+                "(cons (lambda (x y) y) '(1 . 2))",
+            ),
+        ] {
+            let fun_and_args = s.read(fun_and_args).unwrap();
+            let (fun_and_args, _, _) = Evaluator::new(fun_and_args, env, s, limit).eval();
+            let invalid_call = s.intern_cont_error_with(ErrorCode::InvalidCall, fun_and_args.expr);
+            test_aux(
+                s,
+                bad,
+                Some(fun_and_args.expr),
+                None,
+                Some(invalid_call),
+                None,
+                5,
+            );
+        }
+
+        for bad in ["(apply)", "(apply car)", "(apply car '(1) 2)"] {
+            let form = s.read(bad).unwrap();
+            let invalid_form = s.intern_cont_error_with(ErrorCode::InvalidForm, form);
+            test_aux(s, bad, Some(form), None, Some(invalid_form), None, 1);
+        }
     }
 
     #[test]
-    fn evaluate_tail_recursion() {
+    fn evaluate_match() {
         let s = &mut Store::<Fr>::default();
-        let expr = "(letrec ((exp (lambda (base)
-                                  (lambda (exponent-remaining)
-                                    (lambda (acc)
-                                      (if (= 0 exponent-remaining)
-                                          acc
-                                          (((exp base) (- exponent-remaining 1)) (* acc base))))))))
-                          (((exp 5) 3) 1))";
-
-        let expected = s.num(125);
+        let limit = 1000;
+        let env = empty_sym_env(s);
         let terminal = s.get_cont_terminal();
-        test_aux(s, expr, Some(expected), None, Some(terminal), None, 129);
-    }
 
-    #[test]
-    fn evaluate_tail_recursion_somewhat_optimized() {
-        let s = &mut Store::<Fr>::default();
-        let expr =
-                "(letrec ((exp (lambda (base)
-                             (letrec ((base-inner
-                                        (lambda (exponent-remaining)
-                                          (lambda (acc)
-                                            (if (= 0 exponent-remaining)
-                                              acc
-                                             ((base-inner (- exponent-remaining 1)) (* acc base)))))))
-                                      base-inner))))
-                   (((exp 5) 3) 1))";
+        let expected = s.num(5);
+        test_aux(
+            s,
+            "(match 5 (x x))",
+            Some(expected),
+            None,
+            Some(terminal),
+            None,
+            14,
+        );
+        let expected = s.sym("one");
+        test_aux(
+            s,
+            "(match 1 (1 'one) (_ 'other))",
+            Some(expected),
+            None,
+            Some(terminal),
+            None,
+            26,
+        );
 
-        let expected = s.num(125);
-        let terminal = s.get_cont_terminal();
-        test_aux(s, expr, Some(expected), None, Some(terminal), None, 110);
+        // A value which no clause matches becomes a user failure.
+        let two = s.num(2);
+        let user_failure = s.intern_cont_error_with(ErrorCode::UserFailure, two);
+        test_aux(
+            s,
+            "(match 2 (1 'one))",
+            Some(two),
+            None,
+            Some(user_failure),
+            None,
+            19,
+        );
+
+        for (program, expected) in [
+            ("(match '(1 (2 . 3)) ((a (b . c)) (+ a (+ b c))))", "6"),
+            ("(match '(x 1) (('y n) n) (('x n) (* n 10)))", "10"),
+            ("(match \"a\" (\"b\" 1) (\"a\" 2))", "2"),
+            ("(match nil ((a . b) 1) (nil 2))", "2"),
+            ("(match (type-of 1) (:num 'number) (_ 'other))", "number"),
+            (
+                "(letrec ((sum (lambda (l) (match l (nil 0) ((x . xs) (+ x (sum xs)))))))
+                   (sum '(1 2 3)))",
+                "6",
+            ),
+            // The names used by the expansion do not capture those of the program.
+            (
+                "(let ((value 1) (k0 2)) (match 3 (x (+ x (+ value k0)))))",
+                "6",
+            ),
+        ] {
+            let program = s.read(program).unwrap();
+            let expected = s.read(expected).unwrap();
+            let (output, _, _) = Evaluator::new(program, env, s, limit).eval();
+            assert!(s.ptr_eq(&expected, &output.expr));
+        }
     }
 
     #[test]
-    fn evaluate_multiple_letrec_bindings() {
+    fn evaluate_defrecord() {
         let s = &mut Store::<Fr>::default();
-        let expr = "(letrec ((double (lambda (x) (* 2 x)))
-                           (square (lambda (x) (* x x))))
-                   (+ (square 3) (double 2)))";
-
-        let expected = s.num(13);
+        let limit = 1000;
+        let env = empty_sym_env(s);
         let terminal = s.get_cont_terminal();
-        test_aux(s, expr, Some(expected), None, Some(terminal), None, 22);
-    }
 
-    #[test]
-    fn evaluate_multiple_letrec_bindings_referencing() {
-        let s = &mut Store::<Fr>::default();
-        let expr = "(letrec ((double (lambda (x) (* 2 x)))
-                           (double-inc (lambda (x) (+ 1 (double x)))))
-                   (+ (double 3) (double-inc 2)))";
+        let expected = s.num(7);
+        test_aux(
+            s,
+            "(defrecord box (v) (box-v (make-box 7)))",
+            Some(expected),
+            None,
+            Some(terminal),
+            None,
+            21,
+        );
 
-        let expected = s.num(11);
-        let terminal = s.get_cont_terminal();
-        test_aux(s, expr, Some(expected), None, Some(terminal), None, 31);
+        for (program, expected) in [
+            (
+                "(defrecord point (x y) (let ((p (make-point 1 2))) (+ (point-x p) (point-y p))))",
+                "3",
+            ),
+            ("(defrecord point (x y) (make-point 1 2))", "(1 2)"),
+            ("(defrecord point (x y) (point? '(1 2)))", "t"),
+            ("(defrecord point (x y) (point? '(1 2 3)))", "nil"),
+            ("(defrecord point (x y) (point? 1))", "nil"),
+            ("(defrecord empty () (empty? (make-empty)))", "t"),
+            (
+                "(defrecord point (x y)
+                   (defrecord segment (from to)
+                     (point-y (segment-to (make-segment (make-point 1 2) (make-point 3 4))))))",
+                "4",
+            ),
+        ] {
+            let program = s.read(program).unwrap();
+            let expected = s.read(expected).unwrap();
+            let (output, _, _) = Evaluator::new(program, env, s, limit).eval();
+            assert!(s.ptr_eq(&expected, &output.expr));
+        }
+
+        // A record hashes the same way as the list of its fields.
+        let program = s.read("(defrecord point (x y) (make-point 1 2))").unwrap();
+        let (output, _, _) = Evaluator::new(program, env, s, limit).eval();
+        let list = s.read("(1 2)").unwrap();
+        assert_eq!(s.hash_expr(&list), s.hash_expr(&output.expr));
     }
 
     #[test]
-    fn evaluate_multiple_letrec_bindings_recursive() {
+    fn evaluate_delay_force() {
         let s = &mut Store::<Fr>::default();
-        let expr = "(letrec ((exp (lambda (base exponent)
-                                  (if (= 0 exponent)
-                                      1
-                                      (* base (exp base (- exponent 1))))))
-                           (exp2 (lambda (base exponent)
-                                  (if (= 0 exponent)
-                                      1
-                                      (* base (exp2 base (- exponent 1))))))
-                           (exp3 (lambda (base exponent)
-                                  (if (= 0 exponent)
-                                      1
-                                      (* base (exp3 base (- exponent 1)))))))
-                   (+ (+ (exp 3 2) (exp2 2 3))
-                      (exp3 4 2)))";
-
-        let expected = s.num(33);
+        let limit = 1000;
+        let env = empty_sym_env(s);
         let terminal = s.get_cont_terminal();
-        test_aux(s, expr, Some(expected), None, Some(terminal), None, 242);
-    }
 
-    #[test]
-    fn evaluate_eq() {
-        {
-            let s = &mut Store::<Fr>::default();
-            let expr = "(eq 'a 'a)";
+        let expected = s.num(3);
+        test_aux(
+            s,
+            "(force (delay (+ 1 2)))",
+            Some(expected),
+            None,
+            Some(terminal),
+            None,
+            5,
+        );
 
-            let expected = s.t();
-            let terminal = s.get_cont_terminal();
-            test_aux(s, expr, Some(expected), None, Some(terminal), None, 3);
+        // A promise is a function, and its expression is not evaluated until it is forced.
+        let program = s.read("(delay (fail 1))").unwrap();
+        let (output, iterations, _) = Evaluator::new(program, env, s, limit).eval();
+        assert!(output.is_terminal());
+        assert_eq!(Tag::Fun, output.expr.tag());
+        assert_eq!(1, iterations);
+
+        for (program, expected) in [
+            ("(car (cons 1 (delay (fail 2))))", "1"),
+            (
+                "(let ((x 2)) (let ((p (delay (* x 3)))) (let ((x 5)) (force p))))",
+                "6",
+            ),
+            // An infinite stream only evaluates the elements which are used.
+            (
+                "(letrec ((from (lambda (n) (cons n (delay (from (+ n 1))))))
+                          (stream-nth (lambda (s i)
+                                        (if (= i 0)
+                                            (car s)
+                                            (stream-nth (force (cdr s)) (- i 1))))))
+                   (stream-nth (from 10) 5))",
+                "15",
+            ),
+        ] {
+            let program = s.read(program).unwrap();
+            let expected = s.read(expected).unwrap();
+            let (output, _, _) = Evaluator::new(program, env, s, limit).eval();
+            assert!(s.ptr_eq(&expected, &output.expr));
         }
-        {
-            let s = &mut Store::<Fr>::default();
-            let expr = "(eq 'a 1)";
 
-            let expected = s.nil();
-            let terminal = s.get_cont_terminal();
-            test_aux(s, expr, Some(expected), None, Some(terminal), None, 3);
-        }
+        // Binding FORCE would change what calls to it mean, so the program is an invalid form.
+        let expr = "(+ 1 (let ((force (lambda (x) x))) (force 2)))";
+        let form = s.read("(let ((force (lambda (x) x))) (force 2))").unwrap();
+        let error = s.intern_cont_error_with(ErrorCode::InvalidForm, form);
+        test_aux(s, expr, Some(form), None, Some(error), None, 0);
     }
+
     #[test]
-    fn evaluate_zero_arg_lambda() {
-        {
-            let s = &mut Store::<Fr>::default();
-            let expr = "((lambda () 123))";
+    fn evaluate_oracle() {
+        let s = &mut Store::<Fr>::default();
+        let limit = 1000;
+        let env = empty_sym_env(s);
+        let secret = s.read(":secret").unwrap();
+        let mut oracle = |store: &mut Store<Fr>, name: Ptr<Fr>| {
+            if name == secret {
+                Some(store.num(42))
+            } else if name.is_nil() {
+                Some(store.num(7))
+            } else {
+                None
+            }
+        };
+
+        let program = s.read("(witness :secret)").unwrap();
+        let (output, iterations, _) = Evaluator::new(program, env, s, limit)
+            .with_oracle(&mut oracle)
+            .eval();
+        assert!(output.is_terminal());
+        assert_eq!(s.num(42), output.expr);
+        assert_eq!(1, iterations);
+
+        for (program, expected) in [
+            ("(* (witness :secret) 2)", 84),
+            ("(+ (read) 1)", 8),
+            ("(let ((x (witness :secret))) (if (< x 100) x 0))", 42),
+        ] {
+            let program = s.read(program).unwrap();
+            let (output, _, _) = Evaluator::new(program, env, s, limit)
+                .with_oracle(&mut oracle)
+                .eval();
+            assert_eq!(s.num(expected), output.expr);
+        }
 
-            let expected = s.num(123);
-            let terminal = s.get_cont_terminal();
-            test_aux(s, expr, Some(expected), None, Some(terminal), None, 3);
+        // A form the oracle has no value for, or which is malformed, is an invalid form.
+        for bad in [
+            "(witness :other)",
+            "(witness)",
+            "(witness :secret 1)",
+            "(read 1)",
+        ] {
+            let form = s.read(bad).unwrap();
+            let (output, iterations, _) = Evaluator::new(form, env, s, limit)
+                .with_oracle(&mut oracle)
+                .eval();
+            let invalid_form = s.intern_cont_error_with(ErrorCode::InvalidForm, form);
+            assert_eq!(invalid_form, output.cont);
+            assert_eq!(1, iterations);
         }
-        {
-            let s = &mut Store::<Fr>::default();
-            let expr = "(letrec ((x 9) (f (lambda () (+ x 1)))) (f))";
 
-            let expected = s.num(10);
-            let terminal = s.get_cont_terminal();
-            test_aux(s, expr, Some(expected), None, Some(terminal), None, 12);
+        // Without an oracle, there are no values at all.
+        let form = s.read("(read)").unwrap();
+        let invalid_form = s.intern_cont_error_with(ErrorCode::InvalidForm, form);
+        test_aux(s, "(read)", Some(form), None, Some(invalid_form), None, 1);
+    }
+
+    #[test]
+    fn evaluate_emit_observer() {
+        let s = &mut Store::<Fr>::default();
+        let limit = 1000;
+        let env = empty_sym_env(s);
+        let program = s.read("(let ((a 1)) (emit a) (emit (+ a 1)) 3)").unwrap();
+
+        let mut observed = Vec::new();
+        let mut observer = |_: &Store<Fr>, value: Ptr<Fr>, i: usize| observed.push((value, i));
+        let (output, iterations, emitted) = Evaluator::new(program, env, s, limit)
+            .with_emit_observer(&mut observer)
+            .eval();
+        assert!(output.is_terminal());
+        assert_eq!(vec![s.num(1), s.num(2)], emitted);
+
+        // The observer sees the same values as the result, each at the iteration emitting it.
+        assert_eq!(
+            emitted,
+            observed.iter().map(|(value, _)| *value).collect::<Vec<_>>()
+        );
+        assert!(observed.iter().all(|(_, i)| *i < iterations));
+        let frames: Vec<_> = Evaluator::new(program, env, s, limit)
+            .iter()
+            .collect::<Result<_, _>>()
+            .unwrap();
+        for (value, i) in observed.iter() {
+            assert_eq!(Some(*value), frames[*i].output.maybe_emitted_expression(s));
         }
+
+        // Iterating over the frames calls the observer too.
+        let mut count = 0;
+        let mut observer = |_: &Store<Fr>, _: Ptr<Fr>, _: usize| count += 1;
+        Evaluator::new(program, env, s, limit)
+            .with_emit_observer(&mut observer)
+            .iter()
+            .for_each(drop);
+        assert_eq!(2, count);
     }
 
     #[test]
-    fn evaluate_zero_arg_lambda_variants() {
-        {
-            let mut s = Store::<Fr>::default();
-            let limit = 20;
-            let expr = s.read("((lambda (x) 123))").unwrap();
+    fn evaluate_catch_throw() {
+        let s = &mut Store::<Fr>::default();
+        let limit = 1000;
+        let env = empty_sym_env(s);
+        let terminal = s.get_cont_terminal();
 
-            let (
-                IO {
-                    expr: result_expr,
-                    env: _new_env,
-                    cont: _continuation,
-                },
-                iterations,
-                _emitted,
-            ) = Evaluator::new(expr, empty_sym_env(&s), &mut s, limit).eval();
+        let expected = s.num(2);
+        test_aux(
+            s,
+            "(catch found (+ 1 (throw found 2)))",
+            Some(expected),
+            None,
+            Some(terminal),
+            None,
+            6,
+        );
+        let expected = s.num(3);
+        test_aux(
+            s,
+            "(catch found (+ 1 2))",
+            Some(expected),
+            None,
+            Some(terminal),
+            None,
+            5,
+        );
 
-            assert_eq!(crate::store::Tag::Fun, result_expr.tag());
-            assert_eq!(3, iterations);
-        }
-        {
-            let s = &mut Store::<Fr>::default();
-            let expr = "((lambda () 123) 1)";
+        // A value thrown to a tag nothing catches becomes a user failure.
+        let one = s.num(1);
+        let user_failure = s.intern_cont_error_with(ErrorCode::UserFailure, one);
+        test_aux(
+            s,
+            "(throw oops 1)",
+            Some(one),
+            None,
+            Some(user_failure),
+            None,
+            2,
+        );
+        test_aux(
+            s,
+            "(catch a (throw b 1))",
+            Some(one),
+            None,
+            Some(user_failure),
+            None,
+            4,
+        );
 
-            let error = s.get_cont_error();
-            test_aux(s, expr, None, None, Some(error), None, 3);
+        // A throw unwinds through function calls and the catches of other tags.
+        for (program, expected) in [
+            (
+                "(letrec ((walk (lambda (tree)
+                                  (if (atom tree)
+                                      (if (eq tree 3) (throw found (* tree 10)) nil)
+                                      (begin (walk (car tree)) (walk (cdr tree)))))))
+                   (catch found (walk '((1 . 2) . ((3 . 4) . 5)))))",
+                30,
+            ),
+            ("(catch outer (+ 1 (catch inner (throw outer 5))))", 5),
+            ("(catch outer (+ 1 (catch inner (throw inner 5))))", 6),
+        ] {
+            let program = s.read(program).unwrap();
+            let (output, _, _) = Evaluator::new(program, env, s, limit).eval();
+            assert_eq!(s.num(expected), output.expr);
         }
-        {
-            let s = &mut Store::<Fr>::default();
-            let expr = "(123)";
 
-            let error = s.get_cont_error();
-            test_aux(s, expr, None, None, Some(error), None, 2);
+        for bad in [
+            "(catch)",
+            "(catch a)",
+            "(catch a 1 2)",
+            "(throw)",
+            "(throw a)",
+            "(throw a 1 2)",
+        ] {
+            let form = s.read(bad).unwrap();
+            let invalid_form = s.intern_cont_error_with(ErrorCode::InvalidForm, form);
+            test_aux(s, bad, Some(form), None, Some(invalid_form), None, 1);
         }
     }
 
@@ -2300,7 +4706,7 @@ mod test {
                                                   (map-tree f (cdr tree)))))))
                        (map-tree (lambda (x) (+ 1 x)) '((1 . 2) . (3 . 4))))";
             let expected = s.nil();
-            let error = s.get_cont_error();
+            let error = s.intern_cont_error_with(ErrorCode::InvalidArgument, expected);
             test_aux(s, expr, Some(expected), None, Some(error), None, 169);
         }
     }
@@ -2391,10 +4797,60 @@ mod test {
     #[test]
     fn test_one_arg_cons_error() {
         let s = &mut Store::<Fr>::default();
-        let error = s.get_cont_error();
+        let empty = s.str("");
+        let error = s.intern_cont_error_with(ErrorCode::InvalidForm, empty);
         test_aux(s, r#"(cons "")"#, None, None, Some(error), None, 1);
     }
 
+    #[test]
+    fn test_strcons() {
+        let s = &mut Store::<Fr>::default();
+        let apple = s.read(r#" "apple" "#).unwrap();
+        let a = s.read(r#" "a" "#).unwrap();
+        let b = s.str("b");
+        let one = s.num(1);
+        let two = s.num(2);
+        let char_a = s.get_char('a');
+        let terminal = s.get_cont_terminal();
+
+        test_aux(
+            s,
+            r#"(strcons #\a "pple")"#,
+            Some(apple),
+            None,
+            Some(terminal),
+            None,
+            3,
+        );
+        test_aux(
+            s,
+            r#"(strcons #\a "")"#,
+            Some(a),
+            None,
+            Some(terminal),
+            None,
+            3,
+        );
+        let error = s.intern_cont_error_with(ErrorCode::InvalidForm, char_a);
+        test_aux(s, r#"(strcons #\a)"#, None, None, Some(error), None, 1);
+        let error = s.intern_cont_error_with(ErrorCode::InvalidArgument, one);
+        test_aux(s, r#"(strcons #\a 1)"#, None, None, Some(error), None, 3);
+        let error = s.intern_cont_error_with(ErrorCode::InvalidArgument, b);
+        test_aux(s, r#"(strcons "a" "b")"#, None, None, Some(error), None, 3);
+        let error = s.intern_cont_error_with(ErrorCode::InvalidArgument, two);
+        test_aux(s, r#"(strcons 1 2)"#, None, None, Some(error), None, 3);
+        let error = s.intern_cont_error_with(ErrorCode::InvalidArgument, char_a);
+        test_aux(
+            s,
+            r#"(strcons #\a "b" "c")"#,
+            None,
+            None,
+            Some(error),
+            None,
+            2,
+        );
+    }
+
     #[test]
     fn test_car_nil() {
         let s = &mut Store::<Fr>::default();
@@ -2430,7 +4886,8 @@ mod test {
     #[test]
     fn test_car_cdr_invalid_tag_error_sym() {
         let s = &mut Store::<Fr>::default();
-        let error = s.get_cont_error();
+        let car = s.sym("car");
+        let error = s.intern_cont_error_with(ErrorCode::UnboundVariable, car);
         test_aux(s, r#"(car car)"#, None, None, Some(error), None, 2);
         test_aux(s, r#"(cdr car)"#, None, None, Some(error), None, 2);
     }
@@ -2438,7 +4895,8 @@ mod test {
     #[test]
     fn test_car_cdr_invalid_tag_error_char() {
         let s = &mut Store::<Fr>::default();
-        let error = s.get_cont_error();
+        let a = s.get_char('a');
+        let error = s.intern_cont_error_with(ErrorCode::InvalidArgument, a);
         test_aux(s, r#"(car #\a)"#, None, None, Some(error), None, 2);
         test_aux(s, r#"(cdr #\a)"#, None, None, Some(error), None, 2);
     }
@@ -2446,7 +4904,8 @@ mod test {
     #[test]
     fn test_car_cdr_invalid_tag_error_num() {
         let s = &mut Store::<Fr>::default();
-        let error = s.get_cont_error();
+        let num = s.num(42);
+        let error = s.intern_cont_error_with(ErrorCode::InvalidArgument, num);
         test_aux(s, r#"(car 42)"#, None, None, Some(error), None, 2);
         test_aux(s, r#"(cdr 42)"#, None, None, Some(error), None, 2);
     }
@@ -2454,7 +4913,11 @@ mod test {
     #[test]
     fn test_car_cdr_invalid_tag_error_lambda() {
         let s = &mut Store::<Fr>::default();
-        let error = s.get_cont_error();
+        let x = s.sym("x");
+        let body = s.list(&[x]);
+        let env = empty_sym_env(s);
+        let fun = s.intern_fun(x, body, env);
+        let error = s.intern_cont_error_with(ErrorCode::InvalidArgument, fun);
         test_aux(
             s,
             r#"(car (lambda (x) x))"#,
@@ -2580,7 +5043,6 @@ mod test {
     }
 
     #[test]
-    #[should_panic]
     fn hide_opaque_open_unavailable() {
         use crate::store::ScalarPointer;
 
@@ -2593,11 +5055,14 @@ mod test {
         let s2 = &mut Store::<Fr>::default();
         let comm = s2.intern_maybe_opaque_comm(*c);
         let open = s2.sym("open");
-        let x = s2.sym("x");
 
         let expr = s2.list(&[open, comm]);
+        let env = empty_sym_env(s2);
 
-        test_aux2(s2, &expr, Some(x), None, None, None, 2);
+        assert_eq!(
+            Err(ReductionError::Opaque),
+            Evaluator::new(expr, env, s2, 10).try_eval()
+        );
     }
 
     #[test]
@@ -2612,7 +5077,8 @@ mod test {
     fn commit_error() {
         let s = &mut Store::<Fr>::default();
         let expr = "(commit 123 456)";
-        let error = s.get_cont_error();
+        let form = s.read(expr).unwrap();
+        let error = s.intern_cont_error_with(ErrorCode::InvalidForm, form);
         test_aux(s, expr, None, None, Some(error), None, 1);
     }
 
@@ -2620,7 +5086,8 @@ mod test {
     fn open_error() {
         let s = &mut Store::<Fr>::default();
         let expr = "(open 123 456)";
-        let error = s.get_cont_error();
+        let form = s.read(expr).unwrap();
+        let error = s.intern_cont_error_with(ErrorCode::InvalidForm, form);
         test_aux(s, expr, None, None, Some(error), None, 1);
     }
 
@@ -2628,7 +5095,8 @@ mod test {
     fn secret_error() {
         let s = &mut Store::<Fr>::default();
         let expr = "(secret 123 456)";
-        let error = s.get_cont_error();
+        let form = s.read(expr).unwrap();
+        let error = s.intern_cont_error_with(ErrorCode::InvalidForm, form);
         test_aux(s, expr, None, None, Some(error), None, 1);
     }
 
@@ -2636,7 +5104,8 @@ mod test {
     fn num_error() {
         let s = &mut Store::<Fr>::default();
         let expr = "(num 123 456)";
-        let error = s.get_cont_error();
+        let form = s.read(expr).unwrap();
+        let error = s.intern_cont_error_with(ErrorCode::InvalidForm, form);
         test_aux(s, expr, None, None, Some(error), None, 1);
     }
 
@@ -2644,7 +5113,8 @@ mod test {
     fn comm_error() {
         let s = &mut Store::<Fr>::default();
         let expr = "(comm 123 456)";
-        let error = s.get_cont_error();
+        let form = s.read(expr).unwrap();
+        let error = s.intern_cont_error_with(ErrorCode::InvalidForm, form);
         test_aux(s, expr, None, None, Some(error), None, 1);
     }
 
@@ -2652,7 +5122,8 @@ mod test {
     fn char_error() {
         let s = &mut Store::<Fr>::default();
         let expr = "(char 123 456)";
-        let error = s.get_cont_error();
+        let form = s.read(expr).unwrap();
+        let error = s.intern_cont_error_with(ErrorCode::InvalidForm, form);
         test_aux(s, expr, None, None, Some(error), None, 1);
     }
 
@@ -2749,7 +5220,8 @@ mod test {
     fn num_invalid_tag() {
         let s = &mut Store::<Fr>::default();
         let expr = "(num (quote x))";
-        let error = s.get_cont_error();
+        let x = s.sym("x");
+        let error = s.intern_cont_error_with(ErrorCode::InvalidArgument, x);
         test_aux(s, expr, None, None, Some(error), None, 2);
     }
 
@@ -2757,7 +5229,8 @@ mod test {
     fn comm_invalid_tag() {
         let s = &mut Store::<Fr>::default();
         let expr = "(comm (quote x))";
-        let error = s.get_cont_error();
+        let x = s.sym("x");
+        let error = s.intern_cont_error_with(ErrorCode::InvalidArgument, x);
         test_aux(s, expr, None, None, Some(error), None, 2);
     }
 
@@ -2765,7 +5238,8 @@ mod test {
     fn char_invalid_tag() {
         let s = &mut Store::<Fr>::default();
         let expr = "(char (quote x))";
-        let error = s.get_cont_error();
+        let x = s.sym("x");
+        let error = s.intern_cont_error_with(ErrorCode::InvalidArgument, x);
         test_aux(s, expr, None, None, Some(error), None, 2);
     }
 
@@ -2779,26 +5253,42 @@ mod test {
     }
 
     #[test]
-    #[should_panic = "hidden value could not be opened"]
     fn open_opaque_commit() {
         let s = &mut Store::<Fr>::default();
-        let expr = "(open 123)";
-        test_aux(s, expr, None, None, None, None, 2);
+        let expr = s.read("(open 123)").unwrap();
+        let env = empty_sym_env(s);
+        assert_eq!(
+            Err(ReductionError::Opaque),
+            Evaluator::new(expr, env, s, 10).try_eval()
+        );
+    }
+
+    #[test]
+    fn open_invalid_tag() {
+        let s = &mut Store::<Fr>::default();
+        let expr = "(open 'x)";
+        let x = s.sym("x");
+        let error = s.intern_cont_error_with(ErrorCode::InvalidArgument, x);
+        test_aux(s, expr, None, None, Some(error), None, 2);
     }
 
     #[test]
-    #[should_panic]
     fn secret_invalid_tag() {
         let s = &mut Store::<Fr>::default();
         let expr = "(secret 123)";
-        test_aux(s, expr, None, None, None, None, 2);
+        let num = s.num(123);
+        let error = s.intern_cont_error_with(ErrorCode::InvalidArgument, num);
+        test_aux(s, expr, None, None, Some(error), None, 2);
     }
 
     #[test]
-    #[should_panic = "secret could not be extracted"]
     fn secret_opaque_commit() {
         let s = &mut Store::<Fr>::default();
-        let expr = "(secret (comm 123))";
-        test_aux(s, expr, None, None, None, None, 2);
+        let expr = s.read("(secret (comm 123))").unwrap();
+        let env = empty_sym_env(s);
+        assert_eq!(
+            Err(ReductionError::Opaque),
+            Evaluator::new(expr, env, s, 10).try_eval()
+        );
     }
 }
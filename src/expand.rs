@@ -0,0 +1,276 @@
+//! The traversal shared by the pre-passes which expand forms before evaluation: quasiquote,
+//! macros, records, match and promises.
+
+use crate::field::LurkField;
+use crate::store::{Ptr, ScalarPtr, Store};
+
+impl<F: LurkField> Store<F> {
+    /// Expands the forms in `expr` which are not reduced by the evaluator itself: templates, then
+    /// macros, records, `match` and promises. Returns the form which cannot be expanded, if there
+    /// is one: see `expand_macros` and `expand_promises`.
+    pub fn expand(&mut self, expr: Ptr<F>) -> Result<Ptr<F>, Ptr<F>> {
+        let expr = self.expand_quasiquote(expr);
+        let expr = self.expand_macros(expr)?;
+        self.expand_after_macros(expr)
+    }
+
+    /// The passes which follow macro expansion, which the evaluation of macros needs too.
+    /// Promises come last, so that the functions `match` binds its variables with are checked
+    /// for binding `delay` or `force` too.
+    pub(crate) fn expand_after_macros(&mut self, expr: Ptr<F>) -> Result<Ptr<F>, Ptr<F>> {
+        let expr = self.expand_records(expr);
+        let expr = self.expand_match(expr);
+        self.expand_promises(expr)
+    }
+
+    /// Returns the hashes of `expr` and of the expression its evaluation starts from: its
+    /// expansion, or the form which cannot be expanded. A proof about `expr` has the latter as
+    /// its input, so together they bind the proof to `expr`, which anyone can check by expanding
+    /// it again.
+    pub fn hash_expansion(&mut self, expr: Ptr<F>) -> Option<(ScalarPtr<F>, ScalarPtr<F>)> {
+        let expanded = self.expand(expr).unwrap_or_else(|form| form);
+        Some((self.hash_expr(&expr)?, self.hash_expr(&expanded)?))
+    }
+
+    /// Rewrites the forms in `expr` which are in expression position: not within quoted data, nor
+    /// the names bound by `lambda`, `let`, `letrec`, `macrolet`, `defrecord` or the patterns of
+    /// `match`. `rewrite` is called on `expr` first, and returns its replacement, or `None` to
+    /// rewrite its subexpressions instead. A replacement is not rewritten again, so `rewrite`
+    /// expands the forms within it as it needs.
+    pub(crate) fn rewrite_forms(
+        &mut self,
+        expr: Ptr<F>,
+        rewrite: &mut dyn FnMut(&mut Self, Ptr<F>) -> Option<Ptr<F>>,
+    ) -> Ptr<F> {
+        if !expr.is_cons() || expr.is_opaque() || self.form_arg(expr, "quote").is_some() {
+            return expr;
+        }
+        if let Some(rewritten) = rewrite(self, expr) {
+            return rewritten;
+        }
+        let (head, rest) = self.car_cdr(&expr);
+        if !rest.is_cons() || rest.is_opaque() {
+            return self.rewrite_elements(expr, rewrite);
+        }
+        let rest = if head == self.sym("lambda") {
+            // (lambda PARAMS BODY...)
+            self.rewrite_elements_after(rest, 1, rewrite)
+        } else if head == self.sym("let") || head == self.sym("letrec") {
+            // (let ((NAME EXPR)...) BODY...)
+            self.rewrite_bindings(rest, 1, rewrite)
+        } else if head == self.sym("macrolet") {
+            // (macrolet ((NAME ARGS BODY...)...) BODY...)
+            self.rewrite_bindings(rest, 2, rewrite)
+        } else if head == self.sym("defrecord") {
+            // (defrecord NAME (FIELD...) BODY...)
+            self.rewrite_elements_after(rest, 2, rewrite)
+        } else if head == self.sym("match") {
+            // (match EXPR (PATTERN BODY...)...)
+            let (matched, clauses) = self.car_cdr(&rest);
+            let matched = self.rewrite_forms(matched, rewrite);
+            let clauses = self.rewrite_entries(clauses, 1, rewrite);
+            self.cons(matched, clauses)
+        } else {
+            return self.rewrite_elements(expr, rewrite);
+        };
+        self.cons(head, rest)
+    }
+
+    /// Rewrites the forms in each element of a (possibly improper) list, leaving its tail alone.
+    pub(crate) fn rewrite_elements(
+        &mut self,
+        list: Ptr<F>,
+        rewrite: &mut dyn FnMut(&mut Self, Ptr<F>) -> Option<Ptr<F>>,
+    ) -> Ptr<F> {
+        if !list.is_cons() || list.is_opaque() {
+            return list;
+        }
+        let (car, cdr) = self.car_cdr(&list);
+        let car = self.rewrite_forms(car, rewrite);
+        let cdr = self.rewrite_elements(cdr, rewrite);
+        self.cons(car, cdr)
+    }
+
+    // Rewrites the elements of a list but the first `skip` ones.
+    fn rewrite_elements_after(
+        &mut self,
+        list: Ptr<F>,
+        skip: usize,
+        rewrite: &mut dyn FnMut(&mut Self, Ptr<F>) -> Option<Ptr<F>>,
+    ) -> Ptr<F> {
+        if skip == 0 {
+            return self.rewrite_elements(list, rewrite);
+        }
+        if !list.is_cons() || list.is_opaque() {
+            return list;
+        }
+        let (car, cdr) = self.car_cdr(&list);
+        let cdr = self.rewrite_elements_after(cdr, skip - 1, rewrite);
+        self.cons(car, cdr)
+    }
+
+    // Rewrites each entry of a list, such as a binding or a clause, but its first `skip` elements.
+    fn rewrite_entries(
+        &mut self,
+        list: Ptr<F>,
+        skip: usize,
+        rewrite: &mut dyn FnMut(&mut Self, Ptr<F>) -> Option<Ptr<F>>,
+    ) -> Ptr<F> {
+        if !list.is_cons() || list.is_opaque() {
+            return list;
+        }
+        let (entry, more) = self.car_cdr(&list);
+        let entry = self.rewrite_elements_after(entry, skip, rewrite);
+        let more = self.rewrite_entries(more, skip, rewrite);
+        self.cons(entry, more)
+    }
+
+    // Rewrites `(BINDINGS BODY...)`, the rest of a form whose bindings each start with `skip`
+    // elements which are not expressions.
+    fn rewrite_bindings(
+        &mut self,
+        rest: Ptr<F>,
+        skip: usize,
+        rewrite: &mut dyn FnMut(&mut Self, Ptr<F>) -> Option<Ptr<F>>,
+    ) -> Ptr<F> {
+        let (bindings, body) = self.car_cdr(&rest);
+        let bindings = self.rewrite_entries(bindings, skip, rewrite);
+        let body = self.rewrite_elements(body, rewrite);
+        self.cons(bindings, body)
+    }
+
+    /// Returns `(quote expr)`.
+    pub(crate) fn quoted(&mut self, expr: Ptr<F>) -> Ptr<F> {
+        let quote = self.sym("quote");
+        self.list(&[quote, expr])
+    }
+
+    /// Returns `arg` if `expr` is the form `(name arg)`.
+    pub(crate) fn form_arg(&mut self, expr: Ptr<F>, name: &str) -> Option<Ptr<F>> {
+        if !expr.is_cons() || expr.is_opaque() {
+            return None;
+        }
+        let (head, rest) = self.car_cdr(&expr);
+        if head != self.sym(name) || !rest.is_cons() || rest.is_opaque() {
+            return None;
+        }
+        let (arg, end) = self.car_cdr(&rest);
+        if end.is_nil() {
+            Some(arg)
+        } else {
+            None
+        }
+    }
+
+    /// Returns the rest of `expr` if it is a form whose head is the symbol `name`.
+    pub(crate) fn form_rest(&mut self, expr: Ptr<F>, name: &str) -> Option<Ptr<F>> {
+        if !expr.is_cons() || expr.is_opaque() {
+            return None;
+        }
+        let (head, rest) = self.car_cdr(&expr);
+        if head == self.sym(name) {
+            Some(rest)
+        } else {
+            None
+        }
+    }
+}
+
+#[cfg(test)]
+pub(crate) mod test {
+    use super::*;
+    use blstrs::Scalar as Fr;
+
+    /// Checks that `expand` turns the expression read from `input` into the one read from
+    /// `expected`.
+    pub(crate) fn test_expansion(
+        s: &mut Store<Fr>,
+        expand: fn(&mut Store<Fr>, Ptr<Fr>) -> Ptr<Fr>,
+        input: &str,
+        expected: &str,
+    ) {
+        let expr = s.read(input).unwrap();
+        let expected = s.read(expected).unwrap();
+        assert_eq!(expected, expand(s, expr));
+    }
+
+    #[test]
+    fn rewrite_forms() {
+        let s = &mut Store::<Fr>::default();
+        let mut twice = |s: &mut Store<Fr>, expr: Ptr<Fr>| {
+            let arg = s.form_arg(expr, "twice")?;
+            let begin = s.sym("begin");
+            Some(s.list(&[begin, arg, arg]))
+        };
+        let expr = s.read("(f (twice (g (twice x))) '(twice y))").unwrap();
+        let expected = s
+            .read("(f (begin (g (twice x)) (g (twice x))) '(twice y))")
+            .unwrap();
+
+        // Replacements are not rewritten again, and neither is quoted data.
+        assert_eq!(expected, s.rewrite_forms(expr, &mut twice));
+    }
+
+    #[test]
+    fn rewrite_forms_in_expression_position() {
+        let s = &mut Store::<Fr>::default();
+        let expand_force = |s: &mut Store<Fr>, expr: Ptr<Fr>| {
+            s.rewrite_forms(expr, &mut |s, expr| {
+                let promise = s.form_arg(expr, "force")?;
+                Some(s.list(&[promise]))
+            })
+        };
+
+        // The names a form binds are not forms, even when they look like them.
+        test_expansion(
+            s,
+            expand_force,
+            "(let ((force 5)) force)",
+            "(let ((force 5)) force)",
+        );
+        test_expansion(
+            s,
+            expand_force,
+            "(letrec ((f (force p))) (lambda (delay x) (force x)))",
+            "(letrec ((f (p))) (lambda (delay x) (x)))",
+        );
+        test_expansion(
+            s,
+            expand_force,
+            "(macrolet ((m (delay) (force delay))) (m 1))",
+            "(macrolet ((m (delay) (delay))) (m 1))",
+        );
+        test_expansion(
+            s,
+            expand_force,
+            "(defrecord promise (delay force) (force p))",
+            "(defrecord promise (delay force) (p))",
+        );
+        test_expansion(
+            s,
+            expand_force,
+            "(match (force p) ((delay x) (force x)))",
+            "(match (p) ((delay x) (x)))",
+        );
+    }
+
+    #[test]
+    fn hash_expansion() {
+        let s = &mut Store::<Fr>::default();
+        let expr = s.read("(macrolet ((id (x) x)) (id `(,a)))").unwrap();
+        let expanded = s.read("(cons a nil)").unwrap();
+
+        let (expr_hash, expansion_hash) = s.hash_expansion(expr).unwrap();
+        assert_eq!(s.hash_expr(&expr).unwrap(), expr_hash);
+        assert_eq!(s.hash_expr(&expanded).unwrap(), expansion_hash);
+
+        // An evaluation which cannot be expanded starts from the form which cannot be.
+        let expr = s
+            .read("(f (macrolet ((bad (x) (car x))) (bad 1)))")
+            .unwrap();
+        let form = s.read("(bad 1)").unwrap();
+
+        let (_, expansion_hash) = s.hash_expansion(expr).unwrap();
+        assert_eq!(s.hash_expr(&form).unwrap(), expansion_hash);
+    }
+}
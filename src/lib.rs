@@ -11,16 +11,24 @@ extern crate quickcheck;
 #[macro_use(quickcheck)]
 extern crate quickcheck_macros;
 
+pub mod checkpoint;
 pub mod circuit;
 pub mod eval;
 pub mod field;
+pub mod macros;
 pub mod parser;
+pub mod pattern;
+pub mod prelude;
+pub mod promise;
 pub mod proof;
+pub mod quasiquote;
+pub mod record;
 pub mod repl;
 pub mod scalar_store;
 pub mod store;
 pub mod writer;
 
+mod expand;
 mod num;
 pub use num::Num;
 
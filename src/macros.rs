@@ -0,0 +1,313 @@
+//! Syntactic macros, expanded by a pre-pass before evaluation.
+//!
+//! `(macrolet ((NAME ARGS BODY...) ...) EXPR...)` makes each NAME a macro within the EXPRs. A form
+//! `(NAME FORM...)` is replaced by the value of `((lambda ARGS BODY...) 'FORM...)`, evaluated in
+//! the empty environment, and the result is expanded again. The `macrolet` itself is replaced by
+//! its expanded EXPRs, wrapped in a `begin` if there are several of them.
+//!
+//! Since expansion happens before evaluation, a proof is about the expanded expression. The
+//! expansion is deterministic, so a verifier who knows the original expression can check the
+//! proof is about it by expanding it again: see `Store::hash_expansion`.
+
+use crate::eval::{empty_sym_env, Evaluator, IO};
+use crate::field::LurkField;
+use crate::store::{ContTag, Pointer, Ptr, Store};
+
+// The number of iterations all the macro calls of an expansion may take together.
+const MACRO_EXPANSION_LIMIT: usize = 10_000;
+
+// A macro whose expansion is still a macro call is expanded again, up to this many times.
+const MAX_EXPANSION_DEPTH: usize = 100;
+
+// The state shared by all the macro calls of an expansion.
+struct Expansion<F: LurkField> {
+    // The iterations left for macro calls to take.
+    remaining: usize,
+    // The first form which could not be expanded.
+    failed: Option<Ptr<F>>,
+}
+
+impl<F: LurkField> Expansion<F> {
+    fn fail(&mut self, form: Ptr<F>) {
+        self.failed.get_or_insert(form);
+    }
+}
+
+impl<F: LurkField> Store<F> {
+    /// Expands all the macros defined by `macrolet` forms in `expr`. Returns the form which cannot
+    /// be expanded, if there is one: a malformed `macrolet`, or one whose macros fail to evaluate,
+    /// or a macro call which fails or expands more than `MAX_EXPANSION_DEPTH` times in a row. All
+    /// the macros of an expansion are evaluated within a total of `MACRO_EXPANSION_LIMIT`
+    /// iterations.
+    pub fn expand_macros(&mut self, expr: Ptr<F>) -> Result<Ptr<F>, Ptr<F>> {
+        let mut expansion = Expansion {
+            remaining: MACRO_EXPANSION_LIMIT,
+            failed: None,
+        };
+        let expanded = self.expand_macros_with(expr, &[], 0, &mut expansion);
+        match expansion.failed {
+            Some(form) => Err(form),
+            None => Ok(expanded),
+        }
+    }
+
+    // The depth is that of the expansion `expr` is part of, so that a macro cannot grow forever by
+    // expanding into a form which contains a call to it either.
+    fn expand_macros_with(
+        &mut self,
+        expr: Ptr<F>,
+        macros: &[(Ptr<F>, Ptr<F>)],
+        depth: usize,
+        expansion: &mut Expansion<F>,
+    ) -> Ptr<F> {
+        self.rewrite_forms(expr, &mut |s, expr| {
+            s.expand_macro_form(expr, macros, depth, expansion)
+        })
+    }
+
+    // Expands each element of a (possibly improper) list, leaving its tail alone.
+    fn expand_macro_elements(
+        &mut self,
+        list: Ptr<F>,
+        macros: &[(Ptr<F>, Ptr<F>)],
+        depth: usize,
+        expansion: &mut Expansion<F>,
+    ) -> Ptr<F> {
+        self.rewrite_elements(list, &mut |s, expr| {
+            s.expand_macro_form(expr, macros, depth, expansion)
+        })
+    }
+
+    // Expands a `macrolet` or a macro call, or returns `None` if `expr` is neither. Once a form
+    // has failed to expand, the rest are left alone.
+    fn expand_macro_form(
+        &mut self,
+        expr: Ptr<F>,
+        macros: &[(Ptr<F>, Ptr<F>)],
+        depth: usize,
+        expansion: &mut Expansion<F>,
+    ) -> Option<Ptr<F>> {
+        if expansion.failed.is_some() {
+            return Some(expr);
+        }
+        if let Some(rest) = self.form_rest(expr, "macrolet") {
+            let expanded = self.expand_macrolet(rest, macros, depth, expansion);
+            if expanded.is_none() {
+                expansion.fail(expr);
+            }
+            return Some(expanded.unwrap_or(expr));
+        }
+        let (head, rest) = self.car_cdr(&expr);
+        let (_, function) = macros.iter().rev().find(|(name, _)| *name == head)?;
+        let expanded = if depth < MAX_EXPANSION_DEPTH {
+            self.call_macro(*function, rest, expansion)
+        } else {
+            None
+        };
+        match expanded {
+            Some(expanded) => {
+                // The expansion may itself contain templates.
+                let expanded = self.expand_quasiquote(expanded);
+                Some(self.expand_macros_with(expanded, macros, depth + 1, expansion))
+            }
+            None => {
+                expansion.fail(expr);
+                Some(expr)
+            }
+        }
+    }
+
+    // Expands `(macrolet . REST)`, or returns `None` if it is malformed or one of its macros
+    // fails to evaluate.
+    fn expand_macrolet(
+        &mut self,
+        rest: Ptr<F>,
+        macros: &[(Ptr<F>, Ptr<F>)],
+        depth: usize,
+        expansion: &mut Expansion<F>,
+    ) -> Option<Ptr<F>> {
+        if !rest.is_cons() || rest.is_opaque() {
+            return None;
+        }
+        let (mut bindings, body) = self.car_cdr(&rest);
+
+        let mut macros = macros.to_vec();
+        while bindings.is_cons() && !bindings.is_opaque() {
+            let (binding, more) = self.car_cdr(&bindings);
+            if !binding.is_cons() || binding.is_opaque() {
+                return None;
+            }
+            let (name, definition) = self.car_cdr(&binding);
+            let lambda = self.sym("lambda");
+            let function = self.cons(lambda, definition);
+            // A macro's definition may use the macros defined before it.
+            let function = self.expand_macros_with(function, &macros, 0, expansion);
+            let function = self.eval_macro_expr(function, expansion)?;
+            macros.push((name, function));
+            bindings = more;
+        }
+        if !bindings.is_nil() {
+            return None;
+        }
+
+        let body = self.expand_macro_elements(body, &macros, depth, expansion);
+        let (first, more) = self.car_cdr(&body);
+        if more.is_nil() {
+            Some(first)
+        } else {
+            let begin = self.sym("begin");
+            Some(self.cons(begin, body))
+        }
+    }
+
+    // Calls a macro's function with its unevaluated arguments.
+    fn call_macro(
+        &mut self,
+        function: Ptr<F>,
+        args: Ptr<F>,
+        expansion: &mut Expansion<F>,
+    ) -> Option<Ptr<F>> {
+        let mut call = vec![function];
+        let mut args = args;
+        while args.is_cons() && !args.is_opaque() {
+            let (arg, more) = self.car_cdr(&args);
+            let quote = self.sym("quote");
+            call.push(self.list(&[quote, arg]));
+            args = more;
+        }
+        if !args.is_nil() {
+            return None;
+        }
+        let call = self.list(&call);
+        self.eval_macro_expr(call, expansion)
+    }
+
+    // Evaluates `expr` within the iterations left to the expansion, and takes those it used. The
+    // macros in `expr` have been expanded as part of the expansion, so only the later passes are
+    // left: `Evaluator::new` would expand them again, with a limit of their own. Once the
+    // expansion has failed, its forms may not have been expanded, so nothing is evaluated.
+    fn eval_macro_expr(&mut self, expr: Ptr<F>, expansion: &mut Expansion<F>) -> Option<Ptr<F>> {
+        if expansion.failed.is_some() {
+            return None;
+        }
+        let expr = match self.expand_after_macros(expr) {
+            Ok(expr) => expr,
+            Err(form) => {
+                expansion.fail(form);
+                return None;
+            }
+        };
+        let input = IO {
+            expr,
+            env: empty_sym_env(self),
+            cont: self.intern_cont_outermost(),
+        };
+        let (output, iterations, _) = Evaluator::resume(input, self, expansion.remaining).eval();
+        expansion.remaining = expansion.remaining.saturating_sub(iterations);
+        if output.cont.tag() == ContTag::Terminal {
+            Some(output.expr)
+        } else {
+            None
+        }
+    }
+}
+
+#[cfg(test)]
+mod test {
+    use super::*;
+    use crate::expand::test::test_expansion;
+    use blstrs::Scalar as Fr;
+
+    // Macros are defined with templates, so these are expanded first.
+    fn expand(s: &mut Store<Fr>, expr: Ptr<Fr>) -> Ptr<Fr> {
+        let expr = s.expand_quasiquote(expr);
+        s.expand_macros(expr).unwrap()
+    }
+
+    // Checks that expanding the expression read from `input` fails at the form read from `form`.
+    fn test_expansion_failure(s: &mut Store<Fr>, input: &str, form: &str) {
+        let expr = s.read(input).unwrap();
+        let expr = s.expand_quasiquote(expr);
+        let form = s.read(form).unwrap();
+        assert_eq!(Err(form), s.expand_macros(expr));
+    }
+
+    #[test]
+    fn expand_macrolet() {
+        let s = &mut Store::<Fr>::default();
+
+        test_expansion(
+            s,
+            expand,
+            "(macrolet ((unless (c x y) `(if ,c ,y ,x))) (unless (= a 1) 2 3))",
+            "(if (= a 1) 3 2)",
+        );
+        // The body of a macrolet is a sequence, and macros are not expanded in quoted data.
+        test_expansion(
+            s,
+            expand,
+            "(macrolet ((twice (x) `(begin ,x ,x))) (twice (emit 1)) '(twice 2))",
+            "(begin (begin (emit 1) (emit 1)) '(twice 2))",
+        );
+        // Macros can use the macros defined before them, and their expansions are expanded.
+        test_expansion(
+            s,
+            expand,
+            "(macrolet ((my-list args (if args `(cons ,(car args) (my-list ,@(cdr args))) nil))
+                        (pair (a b) (my-list 'my-list a b)))
+               (pair 1 2))",
+            "(cons 1 (cons 2 nil))",
+        );
+    }
+
+    #[test]
+    fn expand_macros_failure() {
+        let s = &mut Store::<Fr>::default();
+
+        // A macro call which fails, or expands too many times in a row, cannot be expanded.
+        test_expansion_failure(s, "(macrolet ((bad (x) (car x))) (bad 1))", "(bad 1)");
+        test_expansion_failure(
+            s,
+            "(macrolet ((forever (x) `(forever ,x))) (forever 1))",
+            "(forever 1)",
+        );
+        test_expansion_failure(s, "(macrolet ((grow () '(begin (grow)))) (grow))", "(grow)");
+        // Neither can a malformed macrolet, or one whose macros fail to evaluate.
+        test_expansion_failure(s, "(f (macrolet (bad) 1))", "(macrolet (bad) 1)");
+        test_expansion_failure(s, "(macrolet ((bad . x)) 1)", "(macrolet ((bad . x)) 1)");
+
+        // All the calls of an expansion share its limit, so calls which could each be expanded
+        // on their own may not be expanded together.
+        let slow = "(slow () (letrec ((count (lambda (n) (if (= n 0) nil (count (- n 1))))))
+                                (count 400)))";
+        test_expansion(s, expand, &format!("(macrolet ({}) (slow))", slow), "nil");
+        test_expansion_failure(
+            s,
+            &format!(
+                "(macrolet ({}) (list (slow) (slow) (slow) (slow) (slow)))",
+                slow
+            ),
+            "(slow)",
+        );
+        // That includes the calls expanded within a macro's definition, or within a macrolet
+        // nested in one.
+        test_expansion_failure(
+            s,
+            &format!(
+                "(macrolet ({})
+                   (list (slow) (slow) (macrolet ((m () (list (slow) (slow) (slow)))) (m))))",
+                slow
+            ),
+            "(slow)",
+        );
+        test_expansion_failure(
+            s,
+            &format!(
+                "(macrolet ((m () (macrolet ({}) (list (slow) (slow) (slow) (slow) (slow)))))
+                   (m))",
+                slow
+            ),
+            "(slow)",
+        );
+    }
+}
@@ -1,6 +1,7 @@
 use crate::field::FWrap;
 use serde::{Deserialize, Serialize};
 use std::{
+    cmp::Ordering,
     fmt::Display,
     hash::Hash,
     ops::{AddAssign, DivAssign, MulAssign, SubAssign},
@@ -169,6 +170,24 @@ impl<F: LurkField> Num<F> {
     pub fn from_scalar(s: F) -> Self {
         Num::Scalar(s)
     }
+
+    /// Returns the value as a `u64`, if its field value is less than 2^64.
+    pub fn to_u64(&self) -> Option<u64> {
+        match self {
+            Num::U64(n) => Some(*n),
+            Num::Scalar(s) => s.to_u64(),
+        }
+    }
+
+    /// Compares two numbers as unsigned 64-bit integers.
+    ///
+    /// Ordering is only defined on numbers whose field value is less than 2^64: every `Num::U64`,
+    /// and any `Num::Scalar` holding such a value. A number therefore compares the same way
+    /// regardless of its internal representation. If either number is out of range, there is no
+    /// ordering and `None` is returned.
+    pub fn cmp_u64(&self, other: &Self) -> Option<Ordering> {
+        Some(self.to_u64()?.cmp(&other.to_u64()?))
+    }
 }
 
 impl<F: LurkField> From<u64> for Num<F> {
@@ -375,6 +394,27 @@ mod tests {
         );
     }
 
+    #[test]
+    fn test_cmp_u64() {
+        let a = Num::<Scalar>::U64(5);
+        let b = Num::Scalar(Scalar::from(10));
+
+        assert_eq!(Some(Ordering::Less), a.cmp_u64(&b));
+        assert_eq!(Some(Ordering::Greater), b.cmp_u64(&a));
+        assert_eq!(
+            Some(Ordering::Equal),
+            a.cmp_u64(&Num::Scalar(Scalar::from(5)))
+        );
+
+        // Field elements which do not fit in a u64 are not ordered.
+        let big = Num::Scalar(Scalar::from(u64::MAX) + Scalar::from(1));
+        assert_eq!(None, a.cmp_u64(&big));
+        assert_eq!(None, big.cmp_u64(&a));
+
+        let negative = Num::Scalar(Scalar::from(0) - Scalar::from(1));
+        assert_eq!(None, negative.cmp_u64(&a));
+    }
+
     #[test]
     fn test_num_hash() {
         use std::collections::hash_map::DefaultHasher;
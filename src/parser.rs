@@ -66,7 +66,8 @@ impl<F: LurkField> Store<F> {
         while let Some(&c) = chars.peek() {
             if let Some(next_expr) = match c {
                 '(' => self.read_list(chars),
-                '0'..='9' => self.read_number(chars),
+                // A malformed number is a parse failure, rather than the start of another expression.
+                '0'..='9' => return self.read_number(chars),
                 ' ' | '\t' | '\n' | '\r' => {
                     // Skip whitespace.
                     chars.next();
@@ -79,6 +80,23 @@ impl<F: LurkField> Store<F> {
                     let inner = self.intern_list(&[quoted]);
                     Some(self.cons(quote, inner))
                 }
+                '`' => {
+                    chars.next();
+                    let quasiquote = self.sym("quasiquote");
+                    let template = self.read_next(chars)?;
+                    Some(self.intern_list(&[quasiquote, template]))
+                }
+                ',' => {
+                    chars.next();
+                    let unquote = if let Some('@') = chars.peek() {
+                        chars.next();
+                        self.sym("unquote-splicing")
+                    } else {
+                        self.sym("unquote")
+                    };
+                    let unquoted = self.read_next(chars)?;
+                    Some(self.intern_list(&[unquote, unquoted]))
+                }
                 '\"' => self.read_string(chars),
                 '#' => self.read_pound(chars),
                 ';' => {
@@ -125,15 +143,15 @@ impl<F: LurkField> Store<F> {
                 }
                 '.' => {
                     chars.next();
-                    let cdr = self.read_next(chars).unwrap();
-                    let remaining_tail = self.read_tail(chars).unwrap();
+                    let cdr = self.read_next(chars)?;
+                    let remaining_tail = self.read_tail(chars)?;
                     assert!(remaining_tail.is_nil());
 
                     Some(cdr)
                 }
                 _ => {
-                    let car = self.read_next(chars).unwrap();
-                    let rest = self.read_tail(chars).unwrap();
+                    let car = self.read_next(chars)?;
+                    let rest = self.read_tail(chars)?;
                     Some(self.cons(car, rest))
                 }
             }
@@ -144,7 +162,7 @@ impl<F: LurkField> Store<F> {
 
     fn read_number<T: Iterator<Item = char>>(&mut self, chars: &mut Peekable<T>) -> Option<Ptr<F>> {
         // As written, read_number assumes the next char is known to be a digit.
-        // So it will never return None.
+        // So it only returns None for a bad suffix, or a u64 literal out of range.
         let mut acc: u64 = 0;
         let ten = 10;
 
@@ -176,7 +194,11 @@ impl<F: LurkField> Store<F> {
                 break;
             }
         }
-        Some(self.intern_num(acc))
+        if read_u64_suffix(chars)? {
+            Some(self.intern_u64(acc))
+        } else {
+            Some(self.intern_num(acc))
+        }
     }
 
     fn read_number_aux<T: Iterator<Item = char>>(
@@ -202,6 +224,10 @@ impl<F: LurkField> Store<F> {
                 break;
             }
         }
+        if read_u64_suffix(chars)? {
+            // The literal is out of range.
+            return None;
+        }
         Some(self.intern_num(crate::num::Num::Scalar(acc)))
     }
 
@@ -277,7 +303,7 @@ impl<F: LurkField> Store<F> {
 fn is_symbol_char(c: &char, initial: bool) -> bool {
     match c {
         // FIXME: suppport more than just alpha.
-        'a'..='z' | 'A'..='Z' | '+' | '-' | '*' | '/' | '=' | ':' => true,
+        'a'..='z' | 'A'..='Z' | '+' | '-' | '*' | '/' | '%' | '=' | '<' | '>' | ':' | '?' => true,
         _ => {
             if initial {
                 false
@@ -288,6 +314,20 @@ fn is_symbol_char(c: &char, initial: bool) -> bool {
     }
 }
 
+// Consumes a `u64` suffix, as in `123u64`, if one follows. Returns `None` for any other suffix
+// starting with `u`.
+fn read_u64_suffix<T: Iterator<Item = char>>(chars: &mut Peekable<T>) -> Option<bool> {
+    if chars.peek() != Some(&'u') {
+        return Some(false);
+    }
+    chars.next();
+    if chars.next() == Some('6') && chars.next() == Some('4') {
+        Some(true)
+    } else {
+        None
+    }
+}
+
 fn is_digit_char(c: &char) -> bool {
     matches!(c, '0'..='9')
 }
@@ -370,6 +410,8 @@ mod test {
             "
 asdf(", "ASDF",
         );
+        test("<=", "<=");
+        test(">= ", ">=");
     }
 
     #[test]
@@ -456,6 +498,32 @@ asdf(", "ASDF",
         );
     }
 
+    #[test]
+    fn read_u64() {
+        let test = |input, expected: u64| {
+            let mut store = Store::<Fr>::default();
+            let expr = store.read(input).unwrap();
+            let expected = store.intern_u64(expected);
+            assert_eq!(expected, expr);
+            assert_eq!(input.trim(), expr.fmt_to_string(&store));
+        };
+        test("0u64", 0);
+        test("123u64", 123);
+        test(" 123u64 ", 123);
+        test("18446744073709551615u64", u64::MAX);
+
+        let mut store = Store::<Fr>::default();
+        let num = store.read("123").unwrap();
+        let n = store.read("123u64").unwrap();
+        assert_ne!(num, n);
+
+        // A bad suffix, or a literal out of range, is a parse failure.
+        assert!(store.read("1u").is_none());
+        assert!(store.read("1u8").is_none());
+        assert!(store.read("18446744073709551616u64").is_none());
+        assert!(store.read("(1 2u8 3)").is_none());
+    }
+
     #[test]
     fn read_list() {
         let mut s = Store::<Fr>::default();
@@ -495,6 +563,20 @@ asdf(", "ASDF",
         test(&mut s, "((1 . 2) 3)", &expected7);
     }
 
+    #[test]
+    fn read_quasiquote() {
+        let mut s = Store::<Fr>::default();
+        let test = |store: &mut Store<Fr>, input, expected| {
+            let expr = store.read(input).unwrap();
+            let expected = store.read(expected).unwrap();
+            assert_eq!(expected, expr);
+        };
+
+        test(&mut s, "`a", "(quasiquote a)");
+        test(&mut s, "`(a ,b)", "(quasiquote (a (unquote b)))");
+        test(&mut s, "`(a ,@b)", "(quasiquote (a (unquote-splicing b)))");
+    }
+
     #[test]
     fn read_improper_list() {
         let mut s = Store::<Fr>::default();
@@ -523,6 +605,8 @@ asdf(", "ASDF",
         test(&mut s, "(A B C)");
         test(&mut s, "(A (B) C)");
         test(&mut s, "(A (B . C) (D E (F)) G)");
+        test(&mut s, "`(A ,B ,@C)");
+        test(&mut s, "`(A `(B ,,C))");
         // test(&mut s, "'A");
         // test(&mut s, "'(A B)");
     }
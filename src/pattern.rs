@@ -0,0 +1,202 @@
+//! Pattern matching with `match`, desugared into existing forms before evaluation.
+//!
+//! `(match EXPR (PATTERN BODY...) ...)` evaluates `EXPR` once, and then the body of the first
+//! clause whose pattern matches its value. A pattern is one of:
+//! - `_`, which matches anything;
+//! - a symbol, which matches anything and binds the symbol to it in the body;
+//! - a literal, such as a number, string, character, `nil`, `t`, a keyword or a quoted datum,
+//!   which matches a value equal to it;
+//! - a list or dotted pair of patterns, such as `(a (b . _) . rest)`, which matches a cons whose
+//!   elements match them.
+//!
+//! When no clause matches, the `match` fails with the value as the error's expression.
+//!
+//! The clauses are tested by a function of the value and of one function per clause, each of
+//! which takes the clause's variables and evaluates its body. The test function only refers to
+//! its own parameters, so it cannot capture any of the names used by the clauses.
+
+use crate::field::LurkField;
+use crate::store::{Pointer, Ptr, Store, Tag};
+
+impl<F: LurkField> Store<F> {
+    /// Expands every `match` form in `expr`, except within quoted data. A malformed `match` is
+    /// left as it is.
+    pub fn expand_match(&mut self, expr: Ptr<F>) -> Ptr<F> {
+        self.rewrite_forms(expr, &mut Self::expand_match_form)
+    }
+
+    // Expands `expr` if it is a well-formed `match`, or returns `None`.
+    fn expand_match_form(&mut self, expr: Ptr<F>) -> Option<Ptr<F>> {
+        let rest = self.form_rest(expr, "match")?;
+        if !rest.is_cons() || rest.is_opaque() {
+            return None;
+        }
+        let (expr, mut clauses) = self.car_cdr(&rest);
+        let value = self.sym("value");
+        let lambda = self.sym("lambda");
+
+        let mut params = vec![value];
+        let mut args = vec![self.expand_match(expr)];
+        let mut branches = Vec::new();
+        while clauses.is_cons() && !clauses.is_opaque() {
+            let (clause, more) = self.car_cdr(&clauses);
+            if !clause.is_cons() || clause.is_opaque() {
+                return None;
+            }
+            let (pattern, body) = self.car_cdr(&clause);
+            if !body.is_cons() {
+                return None;
+            }
+
+            let mut tests = Vec::new();
+            let mut bindings = Vec::new();
+            self.compile_pattern(pattern, value, &mut tests, &mut bindings)?;
+            let (vars, accessors): (Vec<_>, Vec<_>) = bindings.into_iter().unzip();
+
+            let vars = self.list(&vars);
+            let body = self.rewrite_elements(body, &mut Self::expand_match_form);
+            let definition = self.cons(vars, body);
+            args.push(self.cons(lambda, definition));
+
+            let k = self.sym(&format!("k{}", branches.len()));
+            params.push(k);
+            let call = self.list(&[&[k][..], &accessors[..]].concat());
+            let test = match tests.len() {
+                0 => None,
+                1 => Some(tests[0]),
+                _ => {
+                    let and = self.sym("and");
+                    Some(self.list(&[&[and][..], &tests[..]].concat()))
+                }
+            };
+            branches.push((test, call));
+            clauses = more;
+        }
+        if !clauses.is_nil() {
+            return None;
+        }
+
+        // The clauses are tried in order, and a clause which always matches ends the search.
+        let fail = self.sym("fail");
+        let mut dispatch = self.list(&[fail, value]);
+        for (test, call) in branches.into_iter().rev() {
+            dispatch = match test {
+                Some(test) => {
+                    let if_ = self.sym("if");
+                    self.list(&[if_, test, call, dispatch])
+                }
+                None => call,
+            };
+        }
+        let params = self.list(&params);
+        let matcher = self.list(&[lambda, params, dispatch]);
+        Some(self.list(&[&[matcher][..], &args[..]].concat()))
+    }
+
+    // Adds the tests which the value of `accessor` must pass to match `pattern`, and the
+    // variables it binds with their accessors. Returns `None` if the pattern binds a variable
+    // twice.
+    fn compile_pattern(
+        &mut self,
+        pattern: Ptr<F>,
+        accessor: Ptr<F>,
+        tests: &mut Vec<Ptr<F>>,
+        bindings: &mut Vec<(Ptr<F>, Ptr<F>)>,
+    ) -> Option<()> {
+        let eq = self.sym("eq");
+        match pattern.tag() {
+            Tag::Sym if pattern == self.sym("_") => Some(()),
+            Tag::Sym if pattern == self.t() => {
+                tests.push(self.list(&[eq, accessor, pattern]));
+                Some(())
+            }
+            Tag::Sym if self.fetch(&pattern)?.is_keyword_sym() => {
+                let quoted = self.quoted(pattern);
+                tests.push(self.list(&[eq, accessor, quoted]));
+                Some(())
+            }
+            Tag::Sym => {
+                if bindings.iter().any(|(var, _)| *var == pattern) {
+                    return None;
+                }
+                bindings.push((pattern, accessor));
+                Some(())
+            }
+            Tag::Cons if !pattern.is_opaque() && self.form_arg(pattern, "quote").is_none() => {
+                let is_cons = self.sym("cons?");
+                tests.push(self.list(&[is_cons, accessor]));
+                let (car_pattern, cdr_pattern) = self.car_cdr(&pattern);
+                let car = self.sym("car");
+                let car = self.list(&[car, accessor]);
+                self.compile_pattern(car_pattern, car, tests, bindings)?;
+                let cdr = self.sym("cdr");
+                let cdr = self.list(&[cdr, accessor]);
+                self.compile_pattern(cdr_pattern, cdr, tests, bindings)
+            }
+            _ => {
+                tests.push(self.list(&[eq, accessor, pattern]));
+                Some(())
+            }
+        }
+    }
+}
+
+#[cfg(test)]
+mod test {
+    use super::*;
+    use crate::expand::test::test_expansion;
+    use blstrs::Scalar as Fr;
+
+    #[test]
+    fn expand_match() {
+        let s = &mut Store::<Fr>::default();
+
+        test_expansion(
+            s,
+            Store::expand_match,
+            "(match x (1 'one) (_ 'other))",
+            "((lambda (value k0 k1) (if (eq value 1) (k0) (k1)))
+              x (lambda () 'one) (lambda () 'other))",
+        );
+        test_expansion(
+            s,
+            Store::expand_match,
+            "(match (f x) ((a . b) b) (:none 0))",
+            "((lambda (value k0 k1)
+                (if (cons? value)
+                    (k0 (car value) (cdr value))
+                    (if (eq value ':none) (k1) (fail value))))
+              (f x) (lambda (a b) b) (lambda () 0))",
+        );
+        test_expansion(
+            s,
+            Store::expand_match,
+            "(match x (('a y) y))",
+            "((lambda (value k0)
+                (if (and (cons? value) (eq (car value) 'a) (cons? (cdr value))
+                         (eq (cdr (cdr value)) nil))
+                    (k0 (car (cdr value)))
+                    (fail value)))
+              x (lambda (y) y))",
+        );
+        // Matches are expanded in the clauses, except within quoted data.
+        test_expansion(
+            s,
+            Store::expand_match,
+            "(match x (y (match y (_ 1))) (_ '(match z)))",
+            "((lambda (value k0 k1) (k0 value))
+              x
+              (lambda (y) ((lambda (value k0) (k0)) y (lambda () 1)))
+              (lambda () '(match z)))",
+        );
+        // A malformed match is left as it is.
+        test_expansion(
+            s,
+            Store::expand_match,
+            "(match x ((a a) a))",
+            "(match x ((a a) a))",
+        );
+        test_expansion(s, Store::expand_match, "(match x (1))", "(match x (1))");
+        test_expansion(s, Store::expand_match, "(match)", "(match)");
+    }
+}
@@ -0,0 +1,131 @@
+//! The standard Lurk prelude, shipped with the crate.
+//!
+//! The prelude binds `map`, `filter`, `fold`, `append`, `reverse`, `length`, `nth`, `acons`,
+//! `assoc`, `string-append` and `list->string`. `Store::prelude_env` returns the environment
+//! binding them, in which programs can be evaluated. Since an evaluation's environment is part
+//! of its public input, the hash of that environment pins the exact version of the prelude a
+//! proof was made with.
+
+use libipld::Cid;
+
+use crate::eval::{empty_sym_env, Evaluator};
+use crate::field::LurkField;
+use crate::store::{Ptr, ScalarPointer, ScalarPtr, Store};
+
+/// The version of the prelude. It changes whenever the prelude, and so its hash, does.
+pub const PRELUDE_VERSION: &str = "0.2.0";
+
+/// The source of the prelude. It evaluates to the environment binding its functions.
+pub const PRELUDE: &str = include_str!("../lurk-lib/prelude.lurk");
+
+// The number of iterations evaluating the prelude may take.
+const PRELUDE_LIMIT: usize = 100_000;
+
+impl<F: LurkField> Store<F> {
+    /// Returns the environment binding the functions of the prelude.
+    pub fn prelude_env(&mut self) -> Ptr<F> {
+        let prelude = self.read(PRELUDE).expect("the prelude is well-formed");
+        let env = empty_sym_env(self);
+        let (output, _, _) = Evaluator::new(prelude, env, self, PRELUDE_LIMIT).eval();
+        assert!(output.is_terminal(), "the prelude failed to evaluate");
+        output.expr
+    }
+
+    /// Returns the hash of the prelude's environment, which identifies this version of it.
+    pub fn prelude_hash(&mut self) -> ScalarPtr<F> {
+        let env = self.prelude_env();
+        self.hash_expr(&env)
+            .expect("the prelude's environment can be hashed")
+    }
+
+    /// Returns the hash of the prelude's environment as a CID.
+    pub fn prelude_cid(&mut self) -> Cid {
+        let hash = self.prelude_hash();
+        F::to_cid(*hash.tag(), *hash.value()).expect("the prelude's environment has a CID")
+    }
+}
+
+#[cfg(test)]
+mod test {
+    use super::*;
+    use blstrs::Scalar as Fr;
+    use multihash::{Blake3_256, Hasher};
+
+    #[test]
+    fn prelude_functions() {
+        let s = &mut Store::<Fr>::default();
+        let env = s.prelude_env();
+
+        let mut test = |expr: &str, expected: &str| {
+            let expr = s.read(expr).unwrap();
+            let expected = s.read(expected).unwrap();
+            let (output, _, _) = Evaluator::new(expr, env, s, PRELUDE_LIMIT).eval();
+            assert!(output.is_terminal());
+            assert!(s.ptr_eq(&expected, &output.expr));
+        };
+
+        test("(map (lambda (x) (* x x)) '(1 2 3))", "(1 4 9)");
+        test("(map (lambda (x) x) nil)", "nil");
+        test("(filter (lambda (x) (< x 3)) '(1 4 2 5))", "(1 2)");
+        test("(fold (lambda (acc x) (+ acc x)) 0 '(1 2 3))", "6");
+        test("(fold (lambda (acc x) (cons x acc)) nil '(1 2))", "(2 1)");
+        test("(append '(1 2) '(3))", "(1 2 3)");
+        test("(append nil '(3))", "(3)");
+        test("(reverse '(1 2 3))", "(3 2 1)");
+        test("(length '(a b c))", "3");
+        test("(length nil)", "0");
+        test("(nth 1 '(a b c))", "b");
+        test("(nth 3 '(a b c))", "nil");
+        test("(assoc 'b (acons 'a 1 (acons 'b 2 nil)))", "(b . 2)");
+        test("(assoc 'c '((a . 1)))", "nil");
+        test(r#"(string-append "ab" "cd")"#, r#" "abcd" "#);
+        test(r#"(string-append "" "cd")"#, r#" "cd" "#);
+        test(r#"(string-append "ab" "")"#, r#" "ab" "#);
+        test(r#"(list->string '(#\a #\b #\c))"#, r#" "abc" "#);
+        test(r#"(list->string nil)"#, r#" "" "#);
+    }
+
+    #[test]
+    fn prelude_hash() {
+        let s = &mut Store::<Fr>::default();
+        let env = s.prelude_env();
+        let hash = s.prelude_hash();
+        assert_eq!(s.hash_expr(&env).unwrap(), hash);
+
+        // The hash only depends on the prelude.
+        let other = &mut Store::<Fr>::default();
+        other.read("(some unrelated expression)").unwrap();
+        assert_eq!(hash, other.prelude_hash());
+
+        let cid = s.prelude_cid();
+        assert_eq!(Some((*hash.tag(), *hash.value())), Fr::from_cid(cid));
+    }
+
+    #[test]
+    fn prelude_version() {
+        // The prelude's hash is that of the environment its source evaluates to, so pinning the
+        // source pins the hash, as long as evaluation and hashing do not change. A change to the
+        // prelude must come with a new version: update both below, and `PRELUDE_VERSION`.
+        // The source is read from disk rather than taken from `PRELUDE`, so the pin is checked
+        // against the file as it is now.
+        let path = concat!(env!("CARGO_MANIFEST_DIR"), "/lurk-lib/prelude.lurk");
+        let source = std::fs::read(path).unwrap();
+        assert_eq!(PRELUDE.as_bytes(), source.as_slice());
+
+        let mut hasher = Blake3_256::default();
+        hasher.update(&source);
+        let digest: String = hasher
+            .finalize()
+            .iter()
+            .map(|b| format!("{:02x}", b))
+            .collect();
+
+        assert_eq!(
+            (
+                "0.2.0",
+                "ff1939f3e7d08f46a269e145065a1f45d704b2eb699f9c7d5e0f39f5e9048f41"
+            ),
+            (PRELUDE_VERSION, digest.as_str())
+        );
+    }
+}
@@ -0,0 +1,160 @@
+//! Lazy evaluation with `delay` and `force`, expanded into existing forms before evaluation.
+//!
+//! `(delay EXPR)` is a promise to evaluate `EXPR` later, in the current environment, and
+//! `(force PROMISE)` evaluates it. A promise is the function `(lambda () EXPR)`, and forcing it
+//! calls it with no arguments, so an unforced promise costs a single iteration however large its
+//! expression is. Promises are not memoized: each `force` evaluates the expression again. Since
+//! evaluation is pure, it always gives the same value.
+//!
+//! `delay` and `force` are expanded wherever they head a form, so a program cannot bind them as
+//! variables: a `lambda`, `let` or `letrec` which does cannot be expanded, rather than have the
+//! calls to its variable rewritten.
+
+use crate::field::LurkField;
+use crate::store::{Ptr, Store};
+
+impl<F: LurkField> Store<F> {
+    /// Expands every `delay` and `force` form in `expr`, except within quoted data. Returns the
+    /// form which cannot be expanded, if there is one: a `lambda`, `let` or `letrec` binding
+    /// `delay` or `force`.
+    pub fn expand_promises(&mut self, expr: Ptr<F>) -> Result<Ptr<F>, Ptr<F>> {
+        let mut failed = None;
+        let expanded = self.expand_promises_with(expr, &mut failed);
+        match failed {
+            Some(form) => Err(form),
+            None => Ok(expanded),
+        }
+    }
+
+    fn expand_promises_with(&mut self, expr: Ptr<F>, failed: &mut Option<Ptr<F>>) -> Ptr<F> {
+        self.rewrite_forms(expr, &mut |s, expr| s.expand_promise(expr, failed))
+    }
+
+    // Expands `expr` if it is a `delay` or a `force`, or returns `None`. Once a form has failed
+    // to expand, the rest are left alone.
+    fn expand_promise(&mut self, expr: Ptr<F>, failed: &mut Option<Ptr<F>>) -> Option<Ptr<F>> {
+        if failed.is_some() {
+            return Some(expr);
+        }
+        if self.binds_promise_name(expr) {
+            *failed = Some(expr);
+            return Some(expr);
+        }
+        if let Some(delayed) = self.form_arg(expr, "delay") {
+            let delayed = self.expand_promises_with(delayed, failed);
+            let lambda = self.sym("lambda");
+            let nil = self.nil();
+            return Some(self.list(&[lambda, nil, delayed]));
+        }
+        let promise = self.form_arg(expr, "force")?;
+        let promise = self.expand_promises_with(promise, failed);
+        Some(self.list(&[promise]))
+    }
+
+    // Returns whether `expr` is a `lambda` with a parameter, or a `let` or `letrec` with a
+    // binding, named `delay` or `force`.
+    fn binds_promise_name(&mut self, expr: Ptr<F>) -> bool {
+        let (bindings, rest) = if let Some(rest) = self.form_rest(expr, "lambda") {
+            (false, rest)
+        } else if let Some(rest) = self.form_rest(expr, "let") {
+            (true, rest)
+        } else if let Some(rest) = self.form_rest(expr, "letrec") {
+            (true, rest)
+        } else {
+            return false;
+        };
+        if !rest.is_cons() || rest.is_opaque() {
+            return false;
+        }
+        let (mut names, _) = self.car_cdr(&rest);
+
+        let delay = self.sym("delay");
+        let force = self.sym("force");
+        while names.is_cons() && !names.is_opaque() {
+            let (name, more) = self.car_cdr(&names);
+            // A binding is (NAME EXPR), and a parameter just NAME.
+            let name = if bindings && name.is_cons() && !name.is_opaque() {
+                self.car_cdr(&name).0
+            } else {
+                name
+            };
+            if name == delay || name == force {
+                return true;
+            }
+            names = more;
+        }
+        // The rest parameter of a variadic `lambda`.
+        !bindings && (names == delay || names == force)
+    }
+}
+
+#[cfg(test)]
+mod test {
+    use super::*;
+    use crate::expand::test::test_expansion;
+    use blstrs::Scalar as Fr;
+
+    fn expand(s: &mut Store<Fr>, expr: Ptr<Fr>) -> Ptr<Fr> {
+        s.expand_promises(expr).unwrap()
+    }
+
+    #[test]
+    fn expand_promises() {
+        let s = &mut Store::<Fr>::default();
+
+        test_expansion(s, expand, "(delay (+ 1 2))", "(lambda () (+ 1 2))");
+        test_expansion(s, expand, "(force p)", "(p)");
+        test_expansion(
+            s,
+            expand,
+            "(force (delay (delay x)))",
+            "((lambda () (lambda () x)))",
+        );
+        // Promises are expanded anywhere in an expression, except within quoted data.
+        test_expansion(
+            s,
+            expand,
+            "(cons 1 (delay (f x)))",
+            "(cons 1 (lambda () (f x)))",
+        );
+        test_expansion(s, expand, "'(delay x)", "'(delay x)");
+        // Malformed forms are left as they are.
+        test_expansion(s, expand, "(delay)", "(delay)");
+        test_expansion(s, expand, "(force p q)", "(force p q)");
+    }
+
+    #[test]
+    fn expand_promises_failure() {
+        let s = &mut Store::<Fr>::default();
+
+        // Binding `delay` or `force` would change the meaning of calls to it, so it cannot be
+        // expanded.
+        for (input, form) in [
+            ("(let ((force 5)) force)", "(let ((force 5)) force)"),
+            (
+                "(f (letrec ((g 1) (delay g)) (delay 1)))",
+                "(letrec ((g 1) (delay g)) (delay 1))",
+            ),
+            (
+                "(lambda (x force) (force x))",
+                "(lambda (x force) (force x))",
+            ),
+            ("(delay (lambda (x . delay) x))", "(lambda (x . delay) x)"),
+            ("(lambda force force)", "(lambda force force)"),
+        ]
+        .iter()
+        {
+            let expr = s.read(input).unwrap();
+            let form = s.read(form).unwrap();
+            assert_eq!(Err(form), s.expand_promises(expr));
+        }
+
+        // Other names, and quoted data, are left alone.
+        test_expansion(
+            s,
+            expand,
+            "(let ((forced (force p))) '(lambda (force) force))",
+            "(let ((forced (p))) '(lambda (force) force))",
+        );
+    }
+}
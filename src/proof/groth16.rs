@@ -19,7 +19,7 @@ use rand_xorshift::XorShiftRng;
 use serde::{Deserialize, Serialize};
 
 use crate::circuit::MultiFrame;
-use crate::eval::{Evaluator, Witness, IO};
+use crate::eval::{Evaluator, Oracle, Witness, IO};
 use crate::field::LurkField;
 use crate::proof::{Provable, Prover};
 use crate::store::{Ptr, Store};
@@ -138,12 +138,14 @@ where
         env: Ptr<<Self::E as Engine>::Fr>,
         store: &'a mut Store<<Self::E as Engine>::Fr>,
         limit: usize,
+        oracle: Option<&mut dyn Oracle<<Self::E as Engine>::Fr>>,
         mut rng: R,
     ) -> Result<
         (
             Proof<Self::E>,
             IO<<Self::E as Engine>::Fr>,
             IO<<Self::E as Engine>::Fr>,
+            Vec<Ptr<<Self::E as Engine>::Fr>>,
         ),
         SynthesisError,
     >
@@ -152,7 +154,16 @@ where
         <<Self as Groth16<F>>::E as Engine>::Fr: ff::PrimeField,
     {
         let padding_predicate = |count| self.needs_frame_padding(count);
-        let frames = Evaluator::generate_frames(expr, env, store, limit, padding_predicate);
+        let frames = Evaluator::generate_frames_with_oracle(
+            expr,
+            env,
+            store,
+            limit,
+            padding_predicate,
+            oracle,
+        )
+        // An evaluation which cannot be reduced cannot be proved.
+        .map_err(|_| SynthesisError::Unsatisfiable)?;
         store.hydrate_scalar_cache();
 
         let multiframes = MultiFrame::from_frames(self.chunk_frame_count(), &frames, store);
@@ -167,6 +178,7 @@ where
         let mut multiframe_proofs = Vec::with_capacity(multiframes_count);
 
         let last_multiframe = multiframes.last().unwrap().clone();
+        let emitted_hash = last_multiframe.output_emitted_hash;
         for multiframe in multiframes.into_iter() {
             statements.push(multiframe.public_inputs());
             let proof = self
@@ -181,6 +193,7 @@ where
             let dummy_multiframe = MultiFrame::make_dummy(
                 self.chunk_frame_count(),
                 last_multiframe.frames.and_then(|x| x.last().copied()),
+                emitted_hash,
                 store,
             );
 
@@ -208,6 +221,10 @@ where
 
         let public_inputs = frames[0].input;
         let public_outputs = frames[frames.len() - 1].output;
+        let emitted = frames
+            .iter()
+            .filter_map(|frame| frame.output.maybe_emitted_expression(store))
+            .collect();
 
         Ok((
             Proof {
@@ -217,6 +234,7 @@ where
             },
             public_inputs,
             public_outputs,
+            emitted,
         ))
     }
 
@@ -375,7 +393,7 @@ fn verify_sequential_groth16_proofs(
 #[cfg(test)]
 mod tests {
     use super::*;
-    use crate::circuit::ToInputs;
+    use crate::circuit::public_inputs_with_emitted;
     use crate::eval::{empty_sym_env, Frame};
     use crate::proof::{verify_sequential_css, SequentialCS};
     use bellperson::{
@@ -385,6 +403,7 @@ mod tests {
     };
 
     use blstrs::Scalar as Fr;
+    use ff::Field;
     use rand::rngs::OsRng;
 
     const DEFAULT_CHECK_GROTH16: bool = false;
@@ -437,7 +456,7 @@ mod tests {
 
         if check_constraint_systems {
             let padding_predicate = |count| groth_prover.needs_frame_padding(count);
-            let frames = Evaluator::generate_frames(expr, e, s, limit, padding_predicate);
+            let frames = Evaluator::generate_frames(expr, e, s, limit, padding_predicate).unwrap();
             s.hydrate_scalar_cache();
 
             let multi_frames = MultiFrame::from_frames(DEFAULT_CHUNK_FRAME_COUNT, &frames, &s);
@@ -472,6 +491,7 @@ mod tests {
                         empty_sym_env(&s),
                         s,
                         limit,
+                        None,
                         rng,
                     )
                     .unwrap(),
@@ -480,20 +500,35 @@ mod tests {
             None
         };
 
-        if let Some((proof, public_inputs, public_outputs)) = proof_results {
+        if let Some((proof, public_inputs, public_outputs, emitted)) = proof_results {
             let srs_vk = INNER_PRODUCT_SRS.specialize_vk(proof.proof_count);
+            let emitted_hash = s.emitted_hash(&emitted).unwrap();
             let aggregate_proof_and_instances_verified =
                 verify_aggregate_proof_and_aggregate_instances(
                     &srs_vk,
                     &pvk,
                     rng,
-                    &public_inputs.to_inputs(&s),
-                    &public_outputs.to_inputs(&s),
+                    &public_inputs_with_emitted(&public_inputs, Fr::zero(), &s),
+                    &public_inputs_with_emitted(&public_outputs, emitted_hash, &s),
                     &proof.proof,
                     TRANSCRIPT_INCLUDE,
                 )
                 .unwrap();
             assert!(aggregate_proof_and_instances_verified);
+
+            // The proof attests to the values which were emitted.
+            let forged_hash = emitted_hash + Fr::one();
+            let forged_verified = verify_aggregate_proof_and_aggregate_instances(
+                &srs_vk,
+                &pvk,
+                rng,
+                &public_inputs_with_emitted(&public_inputs, Fr::zero(), &s),
+                &public_inputs_with_emitted(&public_outputs, forged_hash, &s),
+                &proof.proof,
+                TRANSCRIPT_INCLUDE,
+            )
+            .unwrap();
+            assert!(!forged_verified);
         };
     }
 
@@ -605,6 +640,38 @@ mod tests {
             false,
         )
     }
+    #[test]
+    #[ignore]
+    fn outer_prove_and_or_cond() {
+        outer_prove_aux(
+            "(and 1 2)",
+            |store| store.num(2),
+            3,
+            DEFAULT_CHECK_GROTH16,
+            true,
+            128,
+            false,
+        );
+        outer_prove_aux(
+            "(or nil 2)",
+            |store| store.num(2),
+            3,
+            DEFAULT_CHECK_GROTH16,
+            true,
+            128,
+            false,
+        );
+        outer_prove_aux(
+            "(cond (nil 1) (t 2))",
+            |store| store.num(2),
+            6,
+            DEFAULT_CHECK_GROTH16,
+            true,
+            128,
+            false,
+        );
+    }
+
     #[test]
     #[ignore]
     fn outer_prove_if_fully_evaluates() {
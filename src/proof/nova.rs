@@ -20,7 +20,7 @@ use nova::{
 use pasta_curves::pallas;
 
 use crate::circuit::MultiFrame;
-use crate::eval::{Evaluator, Frame, Witness, IO};
+use crate::eval::{Evaluator, Frame, Oracle, ReductionError, Witness, IO};
 
 use crate::field::LurkField;
 use crate::proof::Prover;
@@ -79,16 +79,27 @@ where
         env: Ptr<<Self::Grp as Group>::Scalar>,
         store: &mut Store<<Self::Grp as Group>::Scalar>,
         limit: usize,
-    ) -> Vec<Frame<IO<<Self::Grp as Group>::Scalar>, Witness<<Self::Grp as Group>::Scalar>>>
+        oracle: Option<&mut dyn Oracle<<Self::Grp as Group>::Scalar>>,
+    ) -> Result<
+        Vec<Frame<IO<<Self::Grp as Group>::Scalar>, Witness<<Self::Grp as Group>::Scalar>>>,
+        ReductionError,
+    >
     where
         <<Self as Nova<F>>::Grp as Group>::Scalar: LurkField,
     {
         let padding_predicate = |count| self.needs_frame_padding(count);
 
-        let frames = Evaluator::generate_frames(expr, env, store, limit, padding_predicate);
+        let frames = Evaluator::generate_frames_with_oracle(
+            expr,
+            env,
+            store,
+            limit,
+            padding_predicate,
+            oracle,
+        )?;
         store.hydrate_scalar_cache();
 
-        frames
+        Ok(frames)
     }
     fn evaluate_and_prove(
         &self,
@@ -96,11 +107,15 @@ where
         env: Ptr<<Self::Grp as Group>::Scalar>,
         store: &mut Store<<Self::Grp as Group>::Scalar>,
         limit: usize,
+        oracle: Option<&mut dyn Oracle<<Self::Grp as Group>::Scalar>>,
     ) -> Result<(Proof<Self::Grp>, RelaxedR1CSInstance<Self::Grp>), SynthesisError>
     where
         <<Self as Nova<F>>::Grp as Group>::Scalar: LurkField,
     {
-        let frames = self.get_evaluation_frames(expr, env, store, limit);
+        // An evaluation which cannot be reduced cannot be proved.
+        let frames = self
+            .get_evaluation_frames(expr, env, store, limit, oracle)
+            .map_err(|_| SynthesisError::Unsatisfiable)?;
 
         let (shape, gens) = self.make_shape_and_gens();
 
@@ -243,7 +258,8 @@ mod tests {
     use super::*;
     use crate::eval::empty_sym_env;
     use crate::proof::Provable;
-    use crate::store::ContPtr;
+    use crate::store::{ContPtr, ErrorCode};
+    use crate::writer::Write;
 
     use bellperson::util_cs::{
         metric_cs::MetricCS, test_cs::TestConstraintSystem, Comparable, Delta,
@@ -294,7 +310,7 @@ mod tests {
         let proof_results = if check_nova {
             Some(
                 nova_prover
-                    .evaluate_and_prove(expr, empty_sym_env(&s), s, limit)
+                    .evaluate_and_prove(expr, empty_sym_env(&s), s, limit, None)
                     .unwrap(),
             )
         } else {
@@ -308,7 +324,9 @@ mod tests {
             }
         }
 
-        let frames = nova_prover.get_evaluation_frames(expr, e, s, limit);
+        let frames = nova_prover
+            .get_evaluation_frames(expr, e, s, limit, None)
+            .unwrap();
 
         let multiframes = MultiFrame::from_frames(nova_prover.chunk_frame_count(), &frames, &s);
 
@@ -341,7 +359,8 @@ mod tests {
             dbg!(&i, &delta);
             assert!(delta == Delta::Equal);
         }
-        let output = previous_frame.unwrap().output.unwrap();
+        let last_multiframe = previous_frame.unwrap();
+        let output = last_multiframe.output.unwrap();
 
         if let Some(expected_emitted) = expected_emitted {
             let emitted_vec: Vec<_> = frames
@@ -349,6 +368,12 @@ mod tests {
                 .flat_map(|frame| frame.output.maybe_emitted_expression(&s))
                 .collect();
             assert_eq!(expected_emitted, emitted_vec);
+
+            // The proof's public output binds exactly the values emitted, in order.
+            assert_eq!(
+                s.emitted_hash(&expected_emitted),
+                last_multiframe.output_emitted_hash
+            );
         }
 
         if let Some(expected_result) = expected_result {
@@ -363,90 +388,827 @@ mod tests {
             assert_eq!(s.get_cont_terminal(), output.cont);
         }
 
-        assert_eq!(expected_iterations, Frame::significant_frame_count(&frames));
-        assert_eq!(adjusted_iterations, len);
+        assert_eq!(expected_iterations, Frame::significant_frame_count(&frames));
+        assert_eq!(adjusted_iterations, len);
+    }
+
+    // Synthesizes every frame of the evaluation of `expr`, even after one which does not satisfy
+    // the circuit, and returns whether all of them do. Fails if there are no frames to synthesize.
+    fn nova_frames_satisfied(s: &mut Store<Fr>, expr: Ptr<Fr>) -> Result<bool, ReductionError> {
+        let limit = 1000;
+        let env = empty_sym_env(s);
+        let nova_prover = NovaProver::<Fr>::new(DEFAULT_CHUNK_FRAME_COUNT);
+        let frames = nova_prover.get_evaluation_frames(expr, env, s, limit, None)?;
+        let multiframes = MultiFrame::from_frames(nova_prover.chunk_frame_count(), &frames, s);
+
+        Ok(multiframes.into_iter().fold(true, |satisfied, multiframe| {
+            let mut cs = TestConstraintSystem::new();
+            multiframe.synthesize(&mut cs).unwrap();
+            satisfied && cs.is_satisfied()
+        }))
+    }
+
+    // IMPORTANT: Run next tests at least once. Some are ignored because they
+    // are expensive. The criteria is that if the number of iteractions is
+    // more than 30 we ignore it.
+    ////////////////////////////////////////////////////////////////////////////
+
+    #[test]
+    fn test_outer_prove_binop() {
+        let s = &mut Store::<Fr>::default();
+        let expected = s.num(3);
+        let terminal = s.get_cont_terminal();
+        nova_test_aux(s, "(+ 1 2)", Some(expected), None, Some(terminal), None, 3);
+    }
+
+    #[test]
+    #[ignore]
+    fn outer_prove_witness() {
+        let s = &mut Store::<Fr>::default();
+        let limit = 1000;
+        let expr = s.read("(+ 1 (witness :secret))").unwrap();
+        let env = empty_sym_env(s);
+        let secret = s.read(":secret").unwrap();
+        let mut oracle = |store: &mut Store<Fr>, name: Ptr<Fr>| {
+            if name == secret {
+                Some(store.num(41))
+            } else {
+                None
+            }
+        };
+
+        // The prover reads the value from its oracle, and the proof does not reveal it.
+        let nova_prover = NovaProver::<Fr>::new(DEFAULT_CHUNK_FRAME_COUNT);
+        let (proof, instance) = nova_prover
+            .evaluate_and_prove(expr, env, s, limit, Some(&mut oracle))
+            .unwrap();
+        assert!(proof.verify(&nova_prover.make_shape_and_gens(), &instance));
+
+        let frames = nova_prover
+            .get_evaluation_frames(expr, env, s, limit, Some(&mut oracle))
+            .unwrap();
+        let output = frames.last().unwrap().output;
+        let expected = s.num(42);
+        assert!(s.ptr_eq(&expected, &output.expr));
+        assert_eq!(s.get_cont_terminal(), output.cont);
+    }
+
+    #[test]
+    #[ignore]
+    fn outer_prove_arithmetic_let() {
+        let s = &mut Store::<Fr>::default();
+        let expected = s.num(3);
+        let terminal = s.get_cont_terminal();
+        nova_test_aux(
+            s,
+            "(let ((a 5)
+                      (b 1)
+                      (c 2))
+                 (/ (+ a b) c))",
+            Some(expected),
+            None,
+            Some(terminal),
+            None,
+            18,
+        );
+    }
+
+    #[test]
+    #[ignore]
+    fn outer_prove_eq() {
+        let s = &mut Store::<Fr>::default();
+        let expected = s.t();
+        let terminal = s.get_cont_terminal();
+        nova_test_full_aux(
+            s,
+            "(eq 5 5)",
+            Some(expected),
+            None,
+            Some(terminal),
+            None,
+            3,
+            DEFAULT_CHUNK_FRAME_COUNT,
+            true,
+        );
+    }
+
+    #[test]
+    #[ignore]
+    fn outer_prove_num_equal() {
+        let s = &mut Store::<Fr>::default();
+        let expected = s.t();
+        let terminal = s.get_cont_terminal();
+        nova_test_aux(s, "(= 5 5)", Some(expected), None, Some(terminal), None, 3);
+
+        let expected = s.nil();
+        let terminal = s.get_cont_terminal();
+        nova_test_aux(s, "(= 5 6)", Some(expected), None, Some(terminal), None, 3);
+    }
+
+    #[test]
+    fn outer_prove_invalid_num_equal() {
+        let s = &mut Store::<Fr>::default();
+        let expected = s.nil();
+        let error = s.intern_cont_error_with(ErrorCode::InvalidArgument, expected);
+        nova_test_aux(s, "(= 5 nil)", Some(expected), None, Some(error), None, 3);
+
+        let expected = s.num(5);
+        let error = s.intern_cont_error_with(ErrorCode::InvalidArgument, expected);
+        nova_test_aux(s, "(= nil 5)", Some(expected), None, Some(error), None, 3);
+    }
+
+    #[test]
+    fn outer_prove_num_ordering() {
+        let s = &mut Store::<Fr>::default();
+        let t = s.t();
+        let nil = s.nil();
+        let terminal = s.get_cont_terminal();
+        nova_test_aux(s, "(< 5 6)", Some(t), None, Some(terminal), None, 3);
+        nova_test_aux(s, "(> 5 6)", Some(nil), None, Some(terminal), None, 3);
+        nova_test_aux(s, "(<= 6 6)", Some(t), None, Some(terminal), None, 3);
+        nova_test_aux(s, "(>= 5 6)", Some(nil), None, Some(terminal), None, 3);
+    }
+
+    #[test]
+    fn outer_prove_invalid_num_ordering() {
+        let s = &mut Store::<Fr>::default();
+        let expected = s.nil();
+        let error = s.intern_cont_error_with(ErrorCode::InvalidArgument, expected);
+        nova_test_aux(s, "(< 5 nil)", Some(expected), None, Some(error), None, 3);
+
+        let expected = s.num(5);
+        let error = s.intern_cont_error_with(ErrorCode::InvalidArgument, expected);
+        nova_test_aux(
+            s,
+            "(> (- 0 1) 5)",
+            Some(expected),
+            None,
+            Some(error),
+            None,
+            6,
+        );
+    }
+
+    #[test]
+    fn outer_prove_u64_arithmetic() {
+        let s = &mut Store::<Fr>::default();
+        let terminal = s.get_cont_terminal();
+
+        let expected = s.uint64(u64::MAX);
+        nova_test_aux(
+            s,
+            "(- 0u64 1u64)",
+            Some(expected),
+            None,
+            Some(terminal),
+            None,
+            3,
+        );
+        let expected = s.uint64(u64::MAX - 1);
+        nova_test_aux(
+            s,
+            "(* 18446744073709551615u64 2u64)",
+            Some(expected),
+            None,
+            Some(terminal),
+            None,
+            3,
+        );
+        let expected = s.uint64(3);
+        nova_test_aux(
+            s,
+            "(/ 7u64 2u64)",
+            Some(expected),
+            None,
+            Some(terminal),
+            None,
+            3,
+        );
+        let expected = s.uint64(1);
+        nova_test_aux(
+            s,
+            "(% 7u64 2u64)",
+            Some(expected),
+            None,
+            Some(terminal),
+            None,
+            3,
+        );
+        let expected = s.uint64(5);
+        nova_test_aux(s, "(u64 5)", Some(expected), None, Some(terminal), None, 2);
+    }
+
+    #[test]
+    fn outer_prove_invalid_u64_arithmetic() {
+        let s = &mut Store::<Fr>::default();
+
+        let expected = s.uint64(0);
+        let error = s.intern_cont_error_with(ErrorCode::InvalidArgument, expected);
+        nova_test_aux(
+            s,
+            "(% 7u64 0u64)",
+            Some(expected),
+            None,
+            Some(error),
+            None,
+            3,
+        );
+        let expected = s.num(1);
+        let error = s.intern_cont_error_with(ErrorCode::InvalidArgument, expected);
+        nova_test_aux(s, "(+ 1u64 1)", Some(expected), None, Some(error), None, 3);
+        let expected = s.read("18446744073709551616").unwrap();
+        let error = s.intern_cont_error_with(ErrorCode::InvalidArgument, expected);
+        nova_test_aux(
+            s,
+            "(u64 18446744073709551616)",
+            Some(expected),
+            None,
+            Some(error),
+            None,
+            2,
+        );
+    }
+
+    #[test]
+    fn outer_prove_int_quotient_and_modulo() {
+        let s = &mut Store::<Fr>::default();
+        let terminal = s.get_cont_terminal();
+
+        let expected = s.num(3);
+        nova_test_aux(
+            s,
+            "(div 7 2)",
+            Some(expected),
+            None,
+            Some(terminal),
+            None,
+            3,
+        );
+        let expected = s.num(1);
+        nova_test_aux(s, "(% 7 2)", Some(expected), None, Some(terminal), None, 3);
+        let expected = s.num(3);
+        nova_test_aux(
+            s,
+            "(div 20 3 2)",
+            Some(expected),
+            None,
+            Some(terminal),
+            None,
+            4,
+        );
+        let expected = s.num(1);
+        nova_test_aux(
+            s,
+            "(% 7 4 2)",
+            Some(expected),
+            None,
+            Some(terminal),
+            None,
+            4,
+        );
+    }
+
+    #[test]
+    fn outer_prove_invalid_int_quotient_and_modulo() {
+        let s = &mut Store::<Fr>::default();
+
+        for form in ["(div)", "(div 1)", "(%)", "(% 1)"].iter() {
+            let bad_form = s.read(form).unwrap();
+            let error = s.intern_cont_error_with(ErrorCode::InvalidForm, bad_form);
+            nova_test_aux(s, form, None, None, Some(error), None, 1);
+        }
+
+        let expected = s.num(0);
+        let error = s.intern_cont_error_with(ErrorCode::InvalidArgument, expected);
+        nova_test_aux(s, "(div 7 0)", Some(expected), None, Some(error), None, 3);
+        let expected = s.num(2);
+        let error = s.intern_cont_error_with(ErrorCode::InvalidArgument, expected);
+        nova_test_aux(
+            s,
+            "(% (- 0 1) 2)",
+            Some(expected),
+            None,
+            Some(error),
+            None,
+            6,
+        );
+    }
+
+    #[test]
+    fn outer_prove_variadic_arithmetic() {
+        let s = &mut Store::<Fr>::default();
+        let terminal = s.get_cont_terminal();
+
+        let expected = s.num(4);
+        nova_test_aux(
+            s,
+            "(- 10 1 2 3)",
+            Some(expected),
+            None,
+            Some(terminal),
+            None,
+            5,
+        );
+        let expected = s.num(0);
+        nova_test_aux(s, "(+)", Some(expected), None, Some(terminal), None, 1);
+        let expected = s.uint64(5);
+        nova_test_aux(s, "(* 5u64)", Some(expected), None, Some(terminal), None, 2);
+        let expected = s.num(1);
+        nova_test_aux(
+            s,
+            "(* 2 (/ 2))",
+            Some(expected),
+            None,
+            Some(terminal),
+            None,
+            6,
+        );
+    }
+
+    #[test]
+    fn outer_prove_invalid_variadic_arithmetic() {
+        let s = &mut Store::<Fr>::default();
+        let zero = s.num(0);
+        let one = s.num(1);
+        let a = s.sym("a");
+        let bad_form = s.read("(-)").unwrap();
+
+        let error = s.intern_cont_error_with(ErrorCode::InvalidForm, bad_form);
+        nova_test_aux(s, "(-)", None, None, Some(error), None, 1);
+        let error = s.intern_cont_error_with(ErrorCode::InvalidArgument, zero);
+        nova_test_aux(s, "(/ 6 2 0)", None, None, Some(error), None, 4);
+        let error = s.intern_cont_error_with(ErrorCode::InvalidArgument, one);
+        nova_test_aux(s, "(cons 1 2 3)", None, None, Some(error), None, 2);
+        let error = s.intern_cont_error_with(ErrorCode::InvalidArgument, a);
+        nova_test_aux(s, "(+ 'a)", None, None, Some(error), None, 2);
+    }
+
+    #[test]
+    fn outer_prove_chained_relations() {
+        let s = &mut Store::<Fr>::default();
+        let t = s.t();
+        let nil = s.nil();
+        let terminal = s.get_cont_terminal();
+
+        nova_test_aux(s, "(= 1 1 1)", Some(t), None, Some(terminal), None, 4);
+        nova_test_aux(s, "(< 1 3 2)", Some(nil), None, Some(terminal), None, 4);
+        nova_test_aux(
+            s,
+            "(= 1 2 (car 3))",
+            Some(nil),
+            None,
+            Some(terminal),
+            None,
+            3,
+        );
+        nova_test_aux(s, "(eq 5)", Some(t), None, Some(terminal), None, 2);
+        nova_test_aux(s, "(=)", Some(t), None, Some(terminal), None, 1);
+        let a = s.sym("a");
+        let error = s.intern_cont_error_with(ErrorCode::InvalidArgument, a);
+        nova_test_aux(s, "(< 'a)", None, None, Some(error), None, 2);
+    }
+
+    #[test]
+    fn outer_prove_variadic_lambda() {
+        let s = &mut Store::<Fr>::default();
+        let terminal = s.get_cont_terminal();
+
+        let expected = s.nil();
+        nova_test_aux(
+            s,
+            "((lambda xs xs))",
+            Some(expected),
+            None,
+            Some(terminal),
+            None,
+            3,
+        );
+        let two = s.num(2);
+        let expected = s.list(&[two]);
+        nova_test_aux(
+            s,
+            "((lambda (a . rest) rest) 1 2)",
+            Some(expected),
+            None,
+            Some(terminal),
+            None,
+            11,
+        );
+        let expected = s.nil();
+        nova_test_aux(
+            s,
+            "(((lambda (a . rest) rest) 1))",
+            Some(expected),
+            None,
+            Some(terminal),
+            None,
+            7,
+        );
+        // The rest parameter does not get the argument of an enclosing call.
+        nova_test_aux(
+            s,
+            "(((lambda x (lambda (y) y)) 1) 2)",
+            Some(two),
+            None,
+            Some(terminal),
+            None,
+            10,
+        );
+    }
+
+    #[test]
+    fn outer_prove_quasiquote() {
+        let s = &mut Store::<Fr>::default();
+        let expected = s.read("(a 2)").unwrap();
+        let terminal = s.get_cont_terminal();
+
+        // A template is proved as the cons chain it expands to.
+        nova_test_aux(
+            s,
+            "(let ((b 2)) `(a ,b))",
+            Some(expected),
+            None,
+            Some(terminal),
+            None,
+            8,
+        );
+    }
+
+    #[test]
+    fn outer_prove_eval() {
+        let s = &mut Store::<Fr>::default();
+        let terminal = s.get_cont_terminal();
+
+        let expected = s.num(3);
+        nova_test_aux(
+            s,
+            "(eval '(+ 1 2))",
+            Some(expected),
+            None,
+            Some(terminal),
+            None,
+            9,
+        );
+        let expected = s.num(1);
+        nova_test_aux(
+            s,
+            "(let ((x 1)) (eval 'x (current-env)))",
+            Some(expected),
+            None,
+            Some(terminal),
+            None,
+            8,
+        );
+        let bad_form = s.read("(eval)").unwrap();
+        let error = s.intern_cont_error_with(ErrorCode::InvalidForm, bad_form);
+        nova_test_aux(s, "(eval)", None, None, Some(error), None, 1);
+    }
+
+    #[test]
+    fn outer_prove_error_codes() {
+        let s = &mut Store::<Fr>::default();
+        let one = s.num(1);
+        let x = s.sym("x");
+
+        let unbound = s.intern_cont_error_with(ErrorCode::UnboundVariable, x);
+        nova_test_aux(s, "x", Some(x), None, Some(unbound), None, 1);
+
+        let invalid_call = s.intern_cont_error_with(ErrorCode::InvalidCall, one);
+        nova_test_aux(s, "(1 2)", Some(one), None, Some(invalid_call), None, 2);
+
+        let invalid_argument = s.intern_cont_error_with(ErrorCode::InvalidArgument, one);
+        nova_test_aux(
+            s,
+            "(car 1)",
+            Some(one),
+            None,
+            Some(invalid_argument),
+            None,
+            2,
+        );
+    }
+
+    #[test]
+    fn outer_prove_type_of() {
+        let s = &mut Store::<Fr>::default();
+        let terminal = s.get_cont_terminal();
+
+        let expected = s.sym(":num");
+        nova_test_aux(
+            s,
+            "(type-of 1)",
+            Some(expected),
+            None,
+            Some(terminal),
+            None,
+            2,
+        );
+        let expected = s.sym(":cons");
+        nova_test_aux(
+            s,
+            "(type-of '(1 2))",
+            Some(expected),
+            None,
+            Some(terminal),
+            None,
+            2,
+        );
+        let expected = s.t();
+        nova_test_aux(
+            s,
+            "(str? \"abc\")",
+            Some(expected),
+            None,
+            Some(terminal),
+            None,
+            2,
+        );
+        let expected = s.nil();
+        nova_test_aux(
+            s,
+            "(num? 'a)",
+            Some(expected),
+            None,
+            Some(terminal),
+            None,
+            2,
+        );
+    }
+
+    #[test]
+    fn outer_prove_fail_assert() {
+        let s = &mut Store::<Fr>::default();
+        let t = s.t();
+        let num = s.num(42);
+        let condition = s.read("(= 1 2)").unwrap();
+        let terminal = s.get_cont_terminal();
+
+        nova_test_aux(
+            s,
+            "(assert (= 1 1))",
+            Some(t),
+            None,
+            Some(terminal),
+            None,
+            6,
+        );
+
+        let failed_assert = s.intern_cont_error_with(ErrorCode::UserFailure, condition);
+        nova_test_aux(
+            s,
+            "(assert (= 1 2))",
+            Some(condition),
+            None,
+            Some(failed_assert),
+            None,
+            7,
+        );
+
+        let failure = s.intern_cont_error_with(ErrorCode::UserFailure, num);
+        nova_test_aux(s, "(fail 42)", Some(num), None, Some(failure), None, 2);
+    }
+
+    #[test]
+    fn outer_prove_apply() {
+        let s = &mut Store::<Fr>::default();
+        let terminal = s.get_cont_terminal();
+
+        let expected = s.num(3);
+        nova_test_aux(
+            s,
+            "(apply (lambda (a b) (+ a b)) '(1 2))",
+            Some(expected),
+            None,
+            Some(terminal),
+            None,
+            13,
+        );
+        let expected = s.num(7);
+        nova_test_aux(
+            s,
+            "(apply (lambda () 7) nil)",
+            Some(expected),
+            None,
+            Some(terminal),
+            None,
+            7,
+        );
+        let expected = s.read("(1 2 3)").unwrap();
+        nova_test_aux(
+            s,
+            "(apply (lambda args args) '(1 2 3))",
+            Some(expected),
+            None,
+            Some(terminal),
+            None,
+            15,
+        );
+
+        let fun_and_args = s.read("(1 2)").unwrap();
+        let invalid_call = s.intern_cont_error_with(ErrorCode::InvalidCall, fun_and_args);
+        nova_test_aux(
+            s,
+            "(apply 1 '(2))",
+            Some(fun_and_args),
+            None,
+            Some(invalid_call),
+            None,
+            5,
+        );
+    }
+
+    #[test]
+    fn outer_prove_match() {
+        let s = &mut Store::<Fr>::default();
+        let terminal = s.get_cont_terminal();
+
+        let expected = s.num(5);
+        nova_test_aux(
+            s,
+            "(match 5 (x x))",
+            Some(expected),
+            None,
+            Some(terminal),
+            None,
+            14,
+        );
+        let expected = s.sym("one");
+        nova_test_aux(
+            s,
+            "(match 1 (1 'one) (_ 'other))",
+            Some(expected),
+            None,
+            Some(terminal),
+            None,
+            26,
+        );
     }
 
-    // IMPORTANT: Run next tests at least once. Some are ignored because they
-    // are expensive. The criteria is that if the number of iteractions is
-    // more than 30 we ignore it.
-    ////////////////////////////////////////////////////////////////////////////
-
     #[test]
-    fn test_outer_prove_binop() {
+    fn outer_prove_defrecord() {
         let s = &mut Store::<Fr>::default();
-        let expected = s.num(3);
         let terminal = s.get_cont_terminal();
-        nova_test_aux(s, "(+ 1 2)", Some(expected), None, Some(terminal), None, 3);
+
+        let expected = s.num(7);
+        nova_test_aux(
+            s,
+            "(defrecord box (v) (box-v (make-box 7)))",
+            Some(expected),
+            None,
+            Some(terminal),
+            None,
+            21,
+        );
     }
 
     #[test]
-    #[ignore]
-    fn outer_prove_arithmetic_let() {
+    fn outer_prove_delay_force() {
         let s = &mut Store::<Fr>::default();
+        let terminal = s.get_cont_terminal();
+
         let expected = s.num(3);
+        nova_test_aux(
+            s,
+            "(force (delay (+ 1 2)))",
+            Some(expected),
+            None,
+            Some(terminal),
+            None,
+            5,
+        );
+    }
+
+    #[test]
+    fn outer_prove_catch_throw() {
+        let s = &mut Store::<Fr>::default();
         let terminal = s.get_cont_terminal();
+
+        let expected = s.num(2);
         nova_test_aux(
             s,
-            "(let ((a 5)
-                      (b 1)
-                      (c 2))
-                 (/ (+ a b) c))",
+            "(catch found (+ 1 (throw found 2)))",
             Some(expected),
             None,
             Some(terminal),
             None,
-            18,
+            6,
+        );
+        let expected = s.num(3);
+        nova_test_aux(
+            s,
+            "(catch found (+ 1 2))",
+            Some(expected),
+            None,
+            Some(terminal),
+            None,
+            5,
+        );
+
+        let one = s.num(1);
+        let user_failure = s.intern_cont_error_with(ErrorCode::UserFailure, one);
+        nova_test_aux(
+            s,
+            "(catch a (throw b 1))",
+            Some(one),
+            None,
+            Some(user_failure),
+            None,
+            4,
         );
     }
 
     #[test]
-    #[ignore]
-    fn outer_prove_eq() {
+    fn outer_prove_and() {
         let s = &mut Store::<Fr>::default();
-        let expected = s.t();
         let terminal = s.get_cont_terminal();
-        nova_test_full_aux(
+
+        let expected = s.t();
+        nova_test_aux(s, "(and)", Some(expected), None, Some(terminal), None, 1);
+        let expected = s.num(2);
+        nova_test_aux(
             s,
-            "(eq 5 5)",
+            "(and 1 2)",
             Some(expected),
             None,
             Some(terminal),
             None,
             3,
-            DEFAULT_CHUNK_FRAME_COUNT,
-            true,
+        );
+        let expected = s.nil();
+        nova_test_aux(
+            s,
+            "(and nil (/ 1 0))",
+            Some(expected),
+            None,
+            Some(terminal),
+            None,
+            2,
         );
     }
 
     #[test]
-    #[ignore]
-    fn outer_prove_num_equal() {
+    fn outer_prove_or() {
         let s = &mut Store::<Fr>::default();
-        let expected = s.t();
         let terminal = s.get_cont_terminal();
-        nova_test_aux(s, "(= 5 5)", Some(expected), None, Some(terminal), None, 3);
 
         let expected = s.nil();
-        let terminal = s.get_cont_terminal();
-        nova_test_aux(s, "(= 5 6)", Some(expected), None, Some(terminal), None, 3);
+        nova_test_aux(s, "(or)", Some(expected), None, Some(terminal), None, 1);
+        let expected = s.num(2);
+        nova_test_aux(
+            s,
+            "(or nil 2)",
+            Some(expected),
+            None,
+            Some(terminal),
+            None,
+            3,
+        );
+        let expected = s.num(1);
+        nova_test_aux(
+            s,
+            "(or 1 (/ 1 0))",
+            Some(expected),
+            None,
+            Some(terminal),
+            None,
+            2,
+        );
     }
 
     #[test]
-    fn outer_prove_invalid_num_equal() {
+    fn outer_prove_cond() {
         let s = &mut Store::<Fr>::default();
-        let expected = s.nil();
-        let error = s.get_cont_error();
-        nova_test_aux(s, "(= 5 nil)", Some(expected), None, Some(error), None, 3);
+        let terminal = s.get_cont_terminal();
 
+        let expected = s.nil();
+        nova_test_aux(s, "(cond)", Some(expected), None, Some(terminal), None, 1);
+        let expected = s.num(2);
+        nova_test_aux(
+            s,
+            "(cond (nil 1) (t 2))",
+            Some(expected),
+            None,
+            Some(terminal),
+            None,
+            6,
+        );
         let expected = s.num(5);
-        nova_test_aux(s, "(= nil 5)", Some(expected), None, Some(error), None, 3);
+        nova_test_aux(
+            s,
+            "(cond (nil) (5))",
+            Some(expected),
+            None,
+            Some(terminal),
+            None,
+            4,
+        );
     }
 
     #[test]
     fn outer_prove_quote_end_is_nil_error() {
         let s = &mut Store::<Fr>::default();
-        let error = s.get_cont_error();
+        let form = s.read("(quote (1) (2))").unwrap();
+        let error = s.intern_cont_error_with(ErrorCode::InvalidForm, form);
         nova_test_aux(s, "(quote (1) (2))", None, None, Some(error), None, 1);
     }
 
@@ -482,7 +1244,7 @@ mod tests {
     fn outer_prove_if_end_is_nil_error() {
         let s = &mut Store::<Fr>::default();
         let expected = s.num(5);
-        let error = s.get_cont_error();
+        let error = s.intern_cont_error_with(ErrorCode::InvalidArgument, expected);
         nova_test_aux(
             s,
             "(if nil 5 6 7)",
@@ -634,7 +1396,7 @@ mod tests {
             Some(expected),
             None,
             Some(terminal),
-            None,
+            Some(vec![expected]),
             3,
         );
     }
@@ -761,16 +1523,41 @@ mod tests {
     fn outer_prove_evaluate_binop_rest_is_nil() {
         let s = &mut Store::<Fr>::default();
         let expected = s.num(9);
-        let error = s.get_cont_error();
-        nova_test_aux(s, "(- 9 8 7)", Some(expected), None, Some(error), None, 2);
+        let error = s.intern_cont_error_with(ErrorCode::InvalidArgument, expected);
+        // Only arithmetic operators take more than two arguments.
+        nova_test_aux(
+            s,
+            "(cons 9 8 7)",
+            Some(expected),
+            None,
+            Some(error),
+            None,
+            2,
+        );
     }
 
     #[test]
     fn outer_prove_evaluate_relop_rest_is_nil() {
         let s = &mut Store::<Fr>::default();
-        let expected = s.num(9);
-        let error = s.get_cont_error();
-        nova_test_aux(s, "(= 9 8 7)", Some(expected), None, Some(error), None, 2);
+        let t = s.t();
+        let nil = s.nil();
+        let terminal = s.get_cont_terminal();
+
+        // A single argument is related to nothing, so the relation holds.
+        nova_test_aux(s, "(= 9)", Some(t), None, Some(terminal), None, 2);
+        nova_test_aux(s, "(= 9 9 9)", Some(t), None, Some(terminal), None, 4);
+        nova_test_aux(s, "(= 9 9 8)", Some(nil), None, Some(terminal), None, 4);
+        // The arguments after the first relation which does not hold are not evaluated.
+        nova_test_aux(s, "(= 9 8 7)", Some(nil), None, Some(terminal), None, 3);
+        nova_test_aux(
+            s,
+            "(= 9 8 (car 7))",
+            Some(nil),
+            None,
+            Some(terminal),
+            None,
+            3,
+        );
     }
 
     #[test]
@@ -803,7 +1590,7 @@ mod tests {
     fn outer_prove_error_div_by_zero() {
         let s = &mut Store::<Fr>::default();
         let expected = s.num(0);
-        let error = s.get_cont_error();
+        let error = s.intern_cont_error_with(ErrorCode::InvalidArgument, expected);
         nova_test_aux(s, "(/ 21 0)", Some(expected), None, Some(error), None, 3);
     }
 
@@ -811,7 +1598,7 @@ mod tests {
     fn outer_prove_error_invalid_type_and_not_cons() {
         let s = &mut Store::<Fr>::default();
         let expected = s.nil();
-        let error = s.get_cont_error();
+        let error = s.intern_cont_error_with(ErrorCode::InvalidArgument, expected);
         nova_test_aux(s, "(/ 21 nil)", Some(expected), None, Some(error), None, 3);
     }
 
@@ -855,8 +1642,8 @@ mod tests {
     #[test]
     fn outer_prove_evaluate_current_env_rest_is_nil_error() {
         let s = &mut Store::<Fr>::default();
-        let expected = s.nil();
-        let error = s.get_cont_error();
+        let expected = s.read("(current-env a)").unwrap();
+        let error = s.intern_cont_error_with(ErrorCode::InvalidForm, expected);
         nova_test_aux(
             s,
             "(current-env a)",
@@ -889,42 +1676,48 @@ mod tests {
     #[test]
     fn outer_prove_evaluate_let_end_is_nil_error() {
         let s = &mut Store::<Fr>::default();
-        let error = s.get_cont_error();
+        let form = s.read("(let ((a 1 2)) a)").unwrap();
+        let error = s.intern_cont_error_with(ErrorCode::InvalidForm, form);
         nova_test_aux(s, "(let ((a 1 2)) a)", None, None, Some(error), None, 1);
     }
 
     #[test]
     fn outer_prove_evaluate_letrec_end_is_nil_error() {
         let s = &mut Store::<Fr>::default();
-        let error = s.get_cont_error();
+        let form = s.read("(letrec ((a 1 2)) a)").unwrap();
+        let error = s.intern_cont_error_with(ErrorCode::InvalidForm, form);
         nova_test_aux(s, "(letrec ((a 1 2)) a)", None, None, Some(error), None, 1);
     }
 
     #[test]
     fn outer_prove_evaluate_let_empty_error() {
         let s = &mut Store::<Fr>::default();
-        let error = s.get_cont_error();
+        let form = s.read("(let)").unwrap();
+        let error = s.intern_cont_error_with(ErrorCode::InvalidForm, form);
         nova_test_aux(s, "(let)", None, None, Some(error), None, 1);
     }
 
     #[test]
     fn outer_prove_evaluate_let_empty_body_error() {
         let s = &mut Store::<Fr>::default();
-        let error = s.get_cont_error();
+        let form = s.read("(let ((a 1)))").unwrap();
+        let error = s.intern_cont_error_with(ErrorCode::InvalidForm, form);
         nova_test_aux(s, "(let ((a 1)))", None, None, Some(error), None, 1);
     }
 
     #[test]
     fn outer_prove_evaluate_letrec_empty_error() {
         let s = &mut Store::<Fr>::default();
-        let error = s.get_cont_error();
+        let form = s.read("(letrec)").unwrap();
+        let error = s.intern_cont_error_with(ErrorCode::InvalidForm, form);
         nova_test_aux(s, "(letrec)", None, None, Some(error), None, 1);
     }
 
     #[test]
     fn outer_prove_evaluate_letrec_empty_body_error() {
         let s = &mut Store::<Fr>::default();
-        let error = s.get_cont_error();
+        let form = s.read("(letrec ((a 1)))").unwrap();
+        let error = s.intern_cont_error_with(ErrorCode::InvalidForm, form);
         nova_test_aux(s, "(letrec ((a 1)))", None, None, Some(error), None, 1);
     }
 
@@ -945,17 +1738,85 @@ mod tests {
     }
 
     #[test]
-    fn outer_prove_evaluate_let_rest_body_is_nil_error() {
+    fn outer_prove_evaluate_let_implicit_begin() {
+        let s = &mut Store::<Fr>::default();
+        let expected = s.num(1);
+        let terminal = s.get_cont_terminal();
+        nova_test_aux(
+            s,
+            "(let ((a 1)) a 1)",
+            Some(expected),
+            None,
+            Some(terminal),
+            None,
+            5,
+        );
+    }
+
+    #[test]
+    fn outer_prove_evaluate_letrec_implicit_begin() {
+        let s = &mut Store::<Fr>::default();
+        let expected = s.num(1);
+        let terminal = s.get_cont_terminal();
+        nova_test_aux(
+            s,
+            "(letrec ((a 1)) a 1)",
+            Some(expected),
+            None,
+            Some(terminal),
+            None,
+            5,
+        );
+    }
+
+    #[test]
+    fn outer_prove_evaluate_let_implicit_begin_emit() {
+        let s = &mut Store::<Fr>::default();
+        let expected = s.num(1);
+        let emitted = vec![s.num(1)];
+        let terminal = s.get_cont_terminal();
+        nova_test_aux(
+            s,
+            "(let ((a 1)) (emit a) a)",
+            Some(expected),
+            None,
+            Some(terminal),
+            Some(emitted),
+            7,
+        );
+    }
+
+    #[test]
+    fn outer_prove_evaluate_letrec_implicit_begin_emit() {
         let s = &mut Store::<Fr>::default();
-        let error = s.get_cont_error();
-        nova_test_aux(s, "(let ((a 1)) a 1)", None, None, Some(error), None, 1);
+        let expected = s.num(1);
+        let emitted = vec![s.num(1)];
+        let terminal = s.get_cont_terminal();
+        nova_test_aux(
+            s,
+            "(letrec ((a 1)) (emit a) a)",
+            Some(expected),
+            None,
+            Some(terminal),
+            Some(emitted),
+            7,
+        );
     }
 
     #[test]
-    fn outer_prove_evaluate_letrec_rest_body_is_nil_error() {
+    fn outer_prove_evaluate_lambda_implicit_begin() {
         let s = &mut Store::<Fr>::default();
-        let error = s.get_cont_error();
-        nova_test_aux(s, "(letrec ((a 1)) a 1)", None, None, Some(error), None, 1);
+        let expected = s.num(2);
+        let terminal = s.get_cont_terminal();
+        nova_test_aux(
+            s,
+            "((lambda (x) x 2) 1)",
+            Some(expected),
+            None,
+            Some(terminal),
+            None,
+            6,
+        );
     }
 
     #[test]
@@ -1090,11 +1951,11 @@ mod tests {
                        (false (lambda (a)
                                 (lambda (b)
                                   b)))
-                      ;; NOTE: We cannot shadow IF because it is built-in.
+                      ;; NOTE: We cannot shadow IF, or bind COND, because they are built-in.
                       (if- (lambda (a)
                              (lambda (c)
-                               (lambda (cond)
-                                 ((cond a) c))))))
+                               (lambda (test)
+                                 ((test a) c))))))
                  (((if- 5) 6) true))",
             Some(expected),
             None,
@@ -1118,11 +1979,11 @@ mod tests {
                        (false (lambda (a)
                                 (lambda (b)
                                   b)))
-                      ;; NOTE: We cannot shadow IF because it is built-in.
+                      ;; NOTE: We cannot shadow IF, or bind COND, because they are built-in.
                       (if- (lambda (a)
                              (lambda (c)
-                               (lambda (cond)
-                                 ((cond a) c))))))
+                               (lambda (test)
+                                 ((test a) c))))))
                  (((if- 5) 6) false))",
             Some(expected),
             None,
@@ -1143,11 +2004,11 @@ mod tests {
             "(let ((true (lambda (a)
                                (lambda (b)
                                  a)))
-                       ;; NOTE: We cannot shadow IF because it is built-in.
+                       ;; NOTE: We cannot shadow IF, or bind COND, because they are built-in.
                        (if- (lambda (a)
                               (lambda (c)
-                               (lambda (cond)
-                                 ((cond a) c))))))
+                               (lambda (test)
+                                 ((test a) c))))))
                  (((if- 5) 6) true))",
             Some(expected),
             None,
@@ -1335,7 +2196,8 @@ mod tests {
     #[ignore]
     fn outer_prove_evaluate_no_mutual_recursion_error() {
         let s = &mut Store::<Fr>::default();
-        let error = s.get_cont_error();
+        let even = s.sym("even");
+        let error = s.intern_cont_error_with(ErrorCode::UnboundVariable, even);
         nova_test_aux(
             s,
             "(letrec ((even (lambda (n)
@@ -1372,31 +2234,67 @@ mod tests {
         );
     }
 
+    #[test]
+    fn outer_prove_strcons() {
+        let s = &mut Store::<Fr>::default();
+        let expected = s.read(r#" "apple" "#).unwrap();
+        let terminal = s.get_cont_terminal();
+        nova_test_aux(
+            s,
+            r#"(strcons #\a "pple")"#,
+            Some(expected),
+            None,
+            Some(terminal),
+            None,
+            3,
+        );
+        let expected = s.read(r#" "a" "#).unwrap();
+        nova_test_aux(
+            s,
+            r#"(strcons #\a "")"#,
+            Some(expected),
+            None,
+            Some(terminal),
+            None,
+            3,
+        );
+        let a = s.get_char('a');
+        let error = s.intern_cont_error_with(ErrorCode::InvalidForm, a);
+        nova_test_aux(s, r#"(strcons #\a)"#, None, None, Some(error), None, 1);
+        let pple = s.str("pple");
+        let error = s.intern_cont_error_with(ErrorCode::InvalidArgument, pple);
+        nova_test_aux(s, r#"(strcons 1 "pple")"#, None, None, Some(error), None, 3);
+    }
+
     #[test]
     fn outer_prove_evaluate_car_end_is_nil_error() {
         let s = &mut Store::<Fr>::default();
-        let error = s.get_cont_error();
+        let form = s.read("(car (1 2) 3)").unwrap();
+        let error = s.intern_cont_error_with(ErrorCode::InvalidForm, form);
         nova_test_aux(s, "(car (1 2) 3)", None, None, Some(error), None, 1);
     }
 
     #[test]
     fn outer_prove_evaluate_cdr_end_is_nil_error() {
         let s = &mut Store::<Fr>::default();
-        let error = s.get_cont_error();
+        let form = s.read("(cdr (1 2) 3)").unwrap();
+        let error = s.intern_cont_error_with(ErrorCode::InvalidForm, form);
         nova_test_aux(s, "(cdr (1 2) 3)", None, None, Some(error), None, 1);
     }
 
     #[test]
     fn outer_prove_evaluate_atom_end_is_nil_error() {
         let s = &mut Store::<Fr>::default();
-        let error = s.get_cont_error();
+        let form = s.read("(atom 123 4)").unwrap();
+        let error = s.intern_cont_error_with(ErrorCode::InvalidForm, form);
         nova_test_aux(s, "(atom 123 4)", None, None, Some(error), None, 1);
     }
 
     #[test]
     fn outer_prove_evaluate_emit_end_is_nil_error() {
         let s = &mut Store::<Fr>::default();
-        let error = s.get_cont_error();
+        let form = s.read("(emit 123 4)").unwrap();
+        let error = s.intern_cont_error_with(ErrorCode::InvalidForm, form);
         nova_test_aux(s, "(emit 123 4)", None, None, Some(error), None, 1);
     }
 
@@ -1475,7 +2373,8 @@ mod tests {
     #[test]
     fn outer_prove_evaluate_zero_arg_lambda4() {
         let s = &mut Store::<Fr>::default();
-        let error = s.get_cont_error();
+        let one = s.num(1);
+        let error = s.intern_cont_error_with(ErrorCode::InvalidCall, one);
         nova_test_aux(s, "((lambda () 123) 1)", None, None, Some(error), None, 3);
     }
 
@@ -1483,7 +2382,7 @@ mod tests {
     fn outer_prove_evaluate_zero_arg_lambda5() {
         let s = &mut Store::<Fr>::default();
         let expected = s.num(123);
-        let error = s.get_cont_error();
+        let error = s.intern_cont_error_with(ErrorCode::InvalidCall, expected);
         nova_test_aux(s, "(123)", Some(expected), None, Some(error), None, 2);
     }
 
@@ -1901,7 +2800,8 @@ mod tests {
     #[test]
     fn outer_prove_one_arg_cons_error() {
         let s = &mut Store::<Fr>::default();
-        let error = s.get_cont_error();
+        let empty = s.str("");
+        let error = s.intern_cont_error_with(ErrorCode::InvalidForm, empty);
         nova_test_aux(s, r#"(cons "")"#, None, None, Some(error), None, 1);
     }
 
@@ -1940,7 +2840,8 @@ mod tests {
     #[test]
     fn outer_prove_car_cdr_invalid_tag_error_sym() {
         let s = &mut Store::<Fr>::default();
-        let error = s.get_cont_error();
+        let car = s.sym("car");
+        let error = s.intern_cont_error_with(ErrorCode::UnboundVariable, car);
         nova_test_aux(s, r#"(car car)"#, None, None, Some(error), None, 2);
         nova_test_aux(s, r#"(cdr car)"#, None, None, Some(error), None, 2);
     }
@@ -1948,7 +2849,8 @@ mod tests {
     #[test]
     fn outer_prove_car_cdr_invalid_tag_error_char() {
         let s = &mut Store::<Fr>::default();
-        let error = s.get_cont_error();
+        let a = s.get_char('a');
+        let error = s.intern_cont_error_with(ErrorCode::InvalidArgument, a);
         nova_test_aux(s, r#"(car #\a)"#, None, None, Some(error), None, 2);
         nova_test_aux(s, r#"(cdr #\a)"#, None, None, Some(error), None, 2);
     }
@@ -1956,7 +2858,8 @@ mod tests {
     #[test]
     fn outer_prove_car_cdr_invalid_tag_error_num() {
         let s = &mut Store::<Fr>::default();
-        let error = s.get_cont_error();
+        let num = s.num(42);
+        let error = s.intern_cont_error_with(ErrorCode::InvalidArgument, num);
         nova_test_aux(s, r#"(car 42)"#, None, None, Some(error), None, 2);
         nova_test_aux(s, r#"(cdr 42)"#, None, None, Some(error), None, 2);
     }
@@ -1964,7 +2867,11 @@ mod tests {
     #[test]
     fn outer_prove_car_cdr_invalid_tag_error_lambda() {
         let s = &mut Store::<Fr>::default();
-        let error = s.get_cont_error();
+        let x = s.sym("x");
+        let body = s.list(&[x]);
+        let env = empty_sym_env(s);
+        let fun = s.intern_fun(x, body, env);
+        let error = s.intern_cont_error_with(ErrorCode::InvalidArgument, fun);
         nova_test_aux(
             s,
             r#"(car (lambda (x) x))"#,
@@ -2034,7 +2941,8 @@ mod tests {
     fn outer_prove_commit_error() {
         let s = &mut Store::<Fr>::default();
         let expr = "(commit 123 456)";
-        let error = s.get_cont_error();
+        let form = s.read(expr).unwrap();
+        let error = s.intern_cont_error_with(ErrorCode::InvalidForm, form);
         nova_test_aux(s, expr, None, None, Some(error), None, 1);
     }
 
@@ -2042,7 +2950,8 @@ mod tests {
     fn outer_prove_open_error() {
         let s = &mut Store::<Fr>::default();
         let expr = "(open 123 456)";
-        let error = s.get_cont_error();
+        let form = s.read(expr).unwrap();
+        let error = s.intern_cont_error_with(ErrorCode::InvalidForm, form);
         nova_test_aux(s, expr, None, None, Some(error), None, 1);
     }
 
@@ -2050,7 +2959,8 @@ mod tests {
     fn outer_prove_secret_error() {
         let s = &mut Store::<Fr>::default();
         let expr = "(secret 123 456)";
-        let error = s.get_cont_error();
+        let form = s.read(expr).unwrap();
+        let error = s.intern_cont_error_with(ErrorCode::InvalidForm, form);
         nova_test_aux(s, expr, None, None, Some(error), None, 1);
     }
 
@@ -2058,7 +2968,8 @@ mod tests {
     fn outer_prove_num_error() {
         let s = &mut Store::<Fr>::default();
         let expr = "(num 123 456)";
-        let error = s.get_cont_error();
+        let form = s.read(expr).unwrap();
+        let error = s.intern_cont_error_with(ErrorCode::InvalidForm, form);
         nova_test_aux(s, expr, None, None, Some(error), None, 1);
     }
 
@@ -2066,7 +2977,8 @@ mod tests {
     fn outer_prove_comm_error() {
         let s = &mut Store::<Fr>::default();
         let expr = "(comm 123 456)";
-        let error = s.get_cont_error();
+        let form = s.read(expr).unwrap();
+        let error = s.intern_cont_error_with(ErrorCode::InvalidForm, form);
         nova_test_aux(s, expr, None, None, Some(error), None, 1);
     }
 
@@ -2074,7 +2986,8 @@ mod tests {
     fn outer_prove_char_error() {
         let s = &mut Store::<Fr>::default();
         let expr = "(char 123 456)";
-        let error = s.get_cont_error();
+        let form = s.read(expr).unwrap();
+        let error = s.intern_cont_error_with(ErrorCode::InvalidForm, form);
         nova_test_aux(s, expr, None, None, Some(error), None, 1);
     }
 
@@ -2171,7 +3084,8 @@ mod tests {
     fn outer_prove_num_invalid_tag() {
         let s = &mut Store::<Fr>::default();
         let expr = "(num (quote x))";
-        let error = s.get_cont_error();
+        let x = s.sym("x");
+        let error = s.intern_cont_error_with(ErrorCode::InvalidArgument, x);
         nova_test_aux(s, expr, None, None, Some(error), None, 2);
     }
 
@@ -2179,7 +3093,8 @@ mod tests {
     fn outer_prove_comm_invalid_tag() {
         let s = &mut Store::<Fr>::default();
         let expr = "(comm (quote x))";
-        let error = s.get_cont_error();
+        let x = s.sym("x");
+        let error = s.intern_cont_error_with(ErrorCode::InvalidArgument, x);
         nova_test_aux(s, expr, None, None, Some(error), None, 2);
     }
 
@@ -2187,7 +3102,8 @@ mod tests {
     fn outer_prove_char_invalid_tag() {
         let s = &mut Store::<Fr>::default();
         let expr = "(char (quote x))";
-        let error = s.get_cont_error();
+        let x = s.sym("x");
+        let error = s.intern_cont_error_with(ErrorCode::InvalidArgument, x);
         nova_test_aux(s, expr, None, None, Some(error), None, 2);
     }
 
@@ -2201,26 +3117,74 @@ mod tests {
     }
 
     #[test]
-    #[should_panic = "hidden value could not be opened"]
-    fn outer_prove_open_opaque_commit() {
+    fn outer_prove_improper_forms() {
         let s = &mut Store::<Fr>::default();
-        let expr = "(open 123)";
-        nova_test_aux(s, expr, None, None, None, None, 2);
+
+        // The circuit destructures a value which is not a list as `nil`, as the evaluator does.
+        for src in [
+            "(car . 1)",
+            "(commit . 1)",
+            "(if . 5)",
+            "(quote . 5)",
+            "(let ((x . 1)) x)",
+        ] {
+            let expr = s.read(src).unwrap();
+            assert_eq!(Ok(true), nova_frames_satisfied(s, expr), "{}", src);
+        }
     }
 
     #[test]
-    #[should_panic]
-    fn outer_prove_secret_invalid_tag() {
+    fn outer_prove_unop_invalid_argument() {
         let s = &mut Store::<Fr>::default();
-        let expr = "(secret 123)";
-        nova_test_aux(s, expr, None, None, None, None, 2);
+
+        // The circuit proves the error for an argument of the wrong type, or a number which is
+        // not a character code.
+        for (src, arg) in [
+            ("(open \"x\")", "\"x\""),
+            ("(secret 1)", "1"),
+            ("(secret 'x)", "x"),
+            ("(char 1114112)", "1114112"),
+            ("(char 55296)", "55296"),
+            ("(char 18446744073709551616)", "18446744073709551616"),
+        ] {
+            let arg = s.read(arg).unwrap();
+            let error = s.intern_cont_error_with(ErrorCode::InvalidArgument, arg);
+            nova_test_aux(s, src, Some(arg), None, Some(error), None, 2);
+        }
     }
 
     #[test]
-    #[should_panic = "secret could not be extracted"]
-    fn outer_prove_secret_opaque_commit() {
+    fn outer_prove_malformed_forms_without_panicking() {
         let s = &mut Store::<Fr>::default();
-        let expr = "(secret (comm 123))";
-        nova_test_aux(s, expr, None, None, None, None, 2);
+        let opaque_cons = s.intern_opaque_cons(Fr::from(123));
+        let opaque_fun = s.intern_opaque_fun(Fr::from(123));
+        let car = s.sym("car");
+        let quoted = s.quoted(opaque_cons);
+        let car_opaque = s.list(&[car, quoted]);
+        let call_opaque = s.list(&[opaque_fun]);
+
+        // The circuit has no witness for the contents of an opaque value, or the opening of an
+        // unknown commitment, so there are no frames to prove.
+        let mut unprovable = vec![opaque_cons, car_opaque, call_opaque];
+        for src in ["(open 123)", "(secret (comm 123))"] {
+            unprovable.push(s.read(src).unwrap());
+        }
+        for expr in unprovable {
+            assert_eq!(
+                Err(ReductionError::Opaque),
+                nova_frames_satisfied(s, expr),
+                "{}",
+                expr.fmt_to_string(s)
+            );
+        }
+
+        // Whether or not the circuit proves these, synthesizing their frames does not panic.
+        for src in [
+            "(eval 'x '((1 . 2)))",
+            "(eval '(letrec ((f 1)) f) '((1 . 2)))",
+        ] {
+            let expr = s.read(src).unwrap();
+            nova_frames_satisfied(s, expr).unwrap();
+        }
     }
 }
@@ -0,0 +1,153 @@
+//! Expansion of the quasiquoted templates which the reader produces from `` ` ``, `,` and `,@`.
+//!
+//! A template is expanded into the `cons` and `quote` forms which build it, before evaluation. So
+//! evaluating a template, and proving that evaluation, is the same as for the hand-written forms.
+
+use crate::field::LurkField;
+use crate::store::{Pointer, Ptr, Store, Tag};
+
+// Splicing into the middle of a template needs to append two lists. The function is closed, so
+// it cannot capture any of the names used by the template.
+const APPEND: &str = "(letrec ((append (lambda (xs ys)
+                                         (if xs
+                                             (cons (car xs) (append (cdr xs) ys))
+                                             ys))))
+                        append)";
+
+impl<F: LurkField> Store<F> {
+    /// Expands every `(quasiquote template)` in `expr`, except within quoted data.
+    pub fn expand_quasiquote(&mut self, expr: Ptr<F>) -> Ptr<F> {
+        self.rewrite_forms(expr, &mut |s, expr| {
+            let template = s.form_arg(expr, "quasiquote")?;
+            Some(s.expand_template(template, 1))
+        })
+    }
+
+    // Returns an expression building `template`. Nested quasiquotes increase the depth, and only
+    // unquotes at depth 1 are evaluated.
+    fn expand_template(&mut self, template: Ptr<F>, depth: usize) -> Ptr<F> {
+        match template.tag() {
+            Tag::Sym => return self.quoted(template),
+            Tag::Cons if !template.is_opaque() => (),
+            _ => return template,
+        }
+        if let Some(arg) = self.form_arg(template, "unquote") {
+            return if depth == 1 {
+                self.expand_quasiquote(arg)
+            } else {
+                self.expand_form("unquote", arg, depth - 1)
+            };
+        }
+        if let Some(arg) = self.form_arg(template, "quasiquote") {
+            return self.expand_form("quasiquote", arg, depth + 1);
+        }
+        if depth > 1 {
+            if let Some(arg) = self.form_arg(template, "unquote-splicing") {
+                return self.expand_form("unquote-splicing", arg, depth - 1);
+            }
+        }
+
+        let (car, cdr) = self.car_cdr(&template);
+        let rest = self.expand_template(cdr, depth);
+        if depth == 1 {
+            if let Some(arg) = self.form_arg(car, "unquote-splicing") {
+                let spliced = self.expand_quasiquote(arg);
+                return if cdr.is_nil() {
+                    spliced
+                } else {
+                    let append = self.read(APPEND).expect("APPEND is well-formed");
+                    self.list(&[append, spliced, rest])
+                };
+            }
+        }
+        let first = self.expand_template(car, depth);
+        let cons = self.sym("cons");
+        self.list(&[cons, first, rest])
+    }
+
+    // Returns an expression building the form `(name arg)`, where `arg` is a template.
+    fn expand_form(&mut self, name: &str, arg: Ptr<F>, depth: usize) -> Ptr<F> {
+        let head = self.sym(name);
+        let head = self.quoted(head);
+        let arg = self.expand_template(arg, depth);
+        let nil = self.nil();
+        let cons = self.sym("cons");
+        let tail = self.list(&[cons, arg, nil]);
+        self.list(&[cons, head, tail])
+    }
+}
+
+#[cfg(test)]
+mod test {
+    use super::*;
+    use crate::expand::test::test_expansion;
+    use blstrs::Scalar as Fr;
+
+    #[test]
+    fn expand_quasiquote() {
+        let s = &mut Store::<Fr>::default();
+
+        test_expansion(s, Store::expand_quasiquote, "`a", "'a");
+        test_expansion(s, Store::expand_quasiquote, "`1", "1");
+        test_expansion(s, Store::expand_quasiquote, "`()", "nil");
+        test_expansion(
+            s,
+            Store::expand_quasiquote,
+            "`(a b)",
+            "(cons 'a (cons 'b nil))",
+        );
+        test_expansion(
+            s,
+            Store::expand_quasiquote,
+            "`(a ,b)",
+            "(cons 'a (cons b nil))",
+        );
+        test_expansion(s, Store::expand_quasiquote, "`(a . ,b)", "(cons 'a b)");
+        test_expansion(s, Store::expand_quasiquote, "`(a ,@b)", "(cons 'a b)");
+        test_expansion(
+            s,
+            Store::expand_quasiquote,
+            "`((a ,b) ,(+ 1 2))",
+            "(cons (cons 'a (cons b nil)) (cons (+ 1 2) nil))",
+        );
+        // Templates are expanded anywhere in an expression, except within quoted data.
+        test_expansion(
+            s,
+            Store::expand_quasiquote,
+            "(let ((b 1)) `(a ,b))",
+            "(let ((b 1)) (cons 'a (cons b nil)))",
+        );
+        test_expansion(s, Store::expand_quasiquote, "'`(a ,b)", "'`(a ,b)");
+        test_expansion(s, Store::expand_quasiquote, "(a . `b)", "(a . `b)");
+    }
+
+    #[test]
+    fn expand_splicing() {
+        let s = &mut Store::<Fr>::default();
+        let input = s.read("`(,@a b)").unwrap();
+        let expanded = s.expand_quasiquote(input);
+        let expected = format!("({} a (cons 'b nil))", APPEND);
+        let expected = s.read(&expected).unwrap();
+        assert_eq!(expected, expanded);
+    }
+
+    #[test]
+    fn expand_nested_quasiquote() {
+        let s = &mut Store::<Fr>::default();
+
+        // Only the innermost unquote of `(a `(b ,(c ,d))) is evaluated.
+        test_expansion(
+            s,
+            Store::expand_quasiquote,
+            "`(a `(b ,(c ,d)))",
+            "(cons 'a
+                   (cons (cons 'quasiquote
+                               (cons (cons 'b
+                                           (cons (cons 'unquote
+                                                       (cons (cons 'c (cons d nil)) nil))
+                                                 nil))
+                                     nil))
+                         nil))",
+        );
+    }
+}
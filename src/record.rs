@@ -0,0 +1,186 @@
+//! Record definitions with `defrecord`, expanded into existing forms before evaluation.
+//!
+//! `(defrecord NAME (FIELD...) BODY...)` evaluates the `BODY`s with these functions bound:
+//! - `make-NAME`, which takes one argument per field and returns the record;
+//! - `NAME?`, which returns `t` if its argument has the shape of such a record, and `nil`
+//!   otherwise;
+//! - `NAME-FIELD` for each field, which returns that field of a record.
+//!
+//! A record is the list of its fields, in order, so it hashes the same way as that list does, and
+//! commitments to such lists are commitments to the records. Since any list of the right length
+//! has the shape of a record, `NAME?` cannot tell records of the same length apart.
+
+use crate::field::LurkField;
+use crate::store::{Pointer, Ptr, Store, Tag};
+
+impl<F: LurkField> Store<F> {
+    /// Expands every `defrecord` form in `expr`, except within quoted data. A malformed
+    /// `defrecord` is left as it is.
+    pub fn expand_records(&mut self, expr: Ptr<F>) -> Ptr<F> {
+        self.rewrite_forms(expr, &mut Self::expand_defrecord)
+    }
+
+    // Expands `expr` if it is a well-formed `defrecord`, or returns `None`.
+    fn expand_defrecord(&mut self, expr: Ptr<F>) -> Option<Ptr<F>> {
+        let rest = self.form_rest(expr, "defrecord")?;
+        let [name, fields, body] = self.record_parts(rest)?;
+        let name = self.fetch_sym(&name)?.to_string();
+        let fields = self.record_fields(fields)?;
+
+        let lambda = self.sym("lambda");
+        let r = self.sym("r");
+        let params = self.list(&[r]);
+
+        // make-NAME builds the list of the fields.
+        let nil = self.nil();
+        let cons = self.sym("cons");
+        let list = fields
+            .iter()
+            .rev()
+            .fold(nil, |list, field| self.list(&[cons, *field, list]));
+        let field_list = self.list(&fields);
+        let constructor = self.list(&[lambda, field_list, list]);
+
+        // NAME? checks the length of a proper list, and NAME-FIELD takes the nth element.
+        let is_cons = self.sym("cons?");
+        let car = self.sym("car");
+        let cdr = self.sym("cdr");
+        let mut tests = Vec::new();
+        let mut accessors = Vec::new();
+        let mut element = r;
+        for field in fields.iter() {
+            tests.push(self.list(&[is_cons, element]));
+            let field_element = self.list(&[car, element]);
+            let accessor = self.list(&[lambda, params, field_element]);
+            let field = self.fetch_sym(field)?.to_string();
+            accessors.push((self.record_sym("", &name, &format!("-{}", field)), accessor));
+            element = self.list(&[cdr, element]);
+        }
+        let eq = self.sym("eq");
+        tests.push(self.list(&[eq, element, nil]));
+        let test = if tests.len() == 1 {
+            tests[0]
+        } else {
+            let and = self.sym("and");
+            self.list(&[&[and][..], &tests[..]].concat())
+        };
+        let predicate = self.list(&[lambda, params, test]);
+
+        let mut bindings = vec![
+            (self.record_sym("make-", &name, ""), constructor),
+            (self.record_sym("", &name, "?"), predicate),
+        ];
+        bindings.extend(accessors);
+        let bindings: Vec<_> = bindings
+            .into_iter()
+            .map(|(var, function)| self.list(&[var, function]))
+            .collect();
+        let bindings = self.list(&bindings);
+        let body = self.rewrite_elements(body, &mut Self::expand_defrecord);
+        let let_ = self.sym("let");
+        let definition = self.cons(bindings, body);
+        Some(self.cons(let_, definition))
+    }
+
+    // Returns the name, fields and body of `(defrecord NAME (FIELD...) BODY...)`.
+    fn record_parts(&self, rest: Ptr<F>) -> Option<[Ptr<F>; 3]> {
+        if !rest.is_cons() || rest.is_opaque() {
+            return None;
+        }
+        let (name, more) = self.car_cdr(&rest);
+        if !more.is_cons() || more.is_opaque() {
+            return None;
+        }
+        let (fields, body) = self.car_cdr(&more);
+        if name.tag() != Tag::Sym || self.fetch(&name)?.is_keyword_sym() || !body.is_cons() {
+            return None;
+        }
+        Some([name, fields, body])
+    }
+
+    // Returns the fields of a record, which must be distinct symbols.
+    fn record_fields(&self, mut fields: Ptr<F>) -> Option<Vec<Ptr<F>>> {
+        let mut result = Vec::new();
+        while fields.is_cons() && !fields.is_opaque() {
+            let (field, more) = self.car_cdr(&fields);
+            if field.tag() != Tag::Sym || result.contains(&field) {
+                return None;
+            }
+            result.push(field);
+            fields = more;
+        }
+        if fields.is_nil() {
+            Some(result)
+        } else {
+            None
+        }
+    }
+
+    // Returns the symbol PREFIX-NAME-SUFFIX, converting the case of the prefix and suffix only.
+    fn record_sym(&mut self, prefix: &str, name: &str, suffix: &str) -> Ptr<F> {
+        let mut prefix = prefix.to_string();
+        let mut suffix = suffix.to_string();
+        Self::convert_sym_case(&mut prefix);
+        Self::convert_sym_case(&mut suffix);
+        self.intern_sym(format!("{}{}{}", prefix, name, suffix))
+    }
+}
+
+#[cfg(test)]
+mod test {
+    use super::*;
+    use crate::expand::test::test_expansion;
+    use blstrs::Scalar as Fr;
+
+    #[test]
+    fn expand_defrecord() {
+        let s = &mut Store::<Fr>::default();
+
+        test_expansion(
+            s,
+            Store::expand_records,
+            "(defrecord point (x y) (point-y (make-point 1 2)))",
+            "(let ((make-point (lambda (x y) (cons x (cons y nil))))
+                   (point? (lambda (r) (and (cons? r) (cons? (cdr r)) (eq (cdr (cdr r)) nil))))
+                   (point-x (lambda (r) (car r)))
+                   (point-y (lambda (r) (car (cdr r)))))
+               (point-y (make-point 1 2)))",
+        );
+        test_expansion(
+            s,
+            Store::expand_records,
+            "(defrecord empty () (empty? nil))",
+            "(let ((make-empty (lambda () nil))
+                   (empty? (lambda (r) (eq r nil))))
+               (empty? nil))",
+        );
+        // Records are expanded in the body, except within quoted data.
+        test_expansion(
+            s,
+            Store::expand_records,
+            "(defrecord a () (defrecord b () '(defrecord c () 1)))",
+            "(let ((make-a (lambda () nil)) (a? (lambda (r) (eq r nil))))
+               (let ((make-b (lambda () nil)) (b? (lambda (r) (eq r nil))))
+                 '(defrecord c () 1)))",
+        );
+        // A malformed defrecord is left as it is.
+        test_expansion(
+            s,
+            Store::expand_records,
+            "(defrecord p (x x) 1)",
+            "(defrecord p (x x) 1)",
+        );
+        test_expansion(
+            s,
+            Store::expand_records,
+            "(defrecord p (x))",
+            "(defrecord p (x))",
+        );
+        test_expansion(
+            s,
+            Store::expand_records,
+            "(defrecord (p) (x) 1)",
+            "(defrecord (p) (x) 1)",
+        );
+    }
+}
@@ -1,5 +1,5 @@
 use crate::eval::{empty_sym_env, Evaluator, IO};
-use crate::store::{ContPtr, ContTag, Expression, Pointer, Ptr, Store, Tag};
+use crate::store::{ContPtr, ContTag, Continuation, Expression, Pointer, Ptr, Store, Tag};
 use crate::writer::Write;
 use anyhow::Result;
 use blstrs::Scalar as Fr;
@@ -116,7 +116,13 @@ pub fn repl<P: AsRef<Path>>(lurk_file: Option<P>) -> Result<()> {
                         },
                         iterations,
                         _emitted,
-                    ) = Evaluator::new(expr, repl.state.env, &mut s, limit).eval();
+                    ) = match Evaluator::new(expr, repl.state.env, &mut s, limit).try_eval() {
+                        Ok(output) => output,
+                        Err(e) => {
+                            println!("Evaluation failed: {:?}", e);
+                            continue;
+                        }
+                    };
 
                     print!("[{} iterations] => ", iterations);
 
@@ -126,7 +132,12 @@ pub fn repl<P: AsRef<Path>>(lurk_file: Option<P>) -> Result<()> {
                             result.fmt(&s, &mut handle)?;
                             println!();
                         }
-                        ContTag::Error => println!("ERROR!"),
+                        ContTag::Error => match s.fetch_cont(&next_cont) {
+                            Some(Continuation::Error { code, expr }) => {
+                                println!("ERROR! {}: {}", code, expr.fmt_to_string(&s))
+                            }
+                            _ => println!("ERROR!"),
+                        },
                         _ => println!("Computation incomplete after limit: {}", limit),
                     }
                 }
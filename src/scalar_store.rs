@@ -2,7 +2,10 @@ use std::collections::BTreeMap;
 
 use crate::field::LurkField;
 
-use crate::store::{Op1, Op2, Pointer, Ptr, Rel2, ScalarContPtr, ScalarPtr, Store, Tag};
+use crate::store::{
+    ContPtr, Continuation, ErrorCode, Op1, Op2, Pointer, Ptr, Rel2, ScalarContPtr, ScalarPtr,
+    Store, Tag,
+};
 use crate::Num;
 use serde::Deserialize;
 use serde::Serialize;
@@ -15,6 +18,8 @@ pub struct ScalarStore<F: LurkField> {
     scalar_cont_map: BTreeMap<ScalarContPtr<F>, Option<ScalarContinuation<F>>>,
     #[serde(skip)]
     pending_scalar_ptrs: Vec<ScalarPtr<F>>,
+    #[serde(skip)]
+    pending_scalar_cont_ptrs: Vec<ScalarContPtr<F>>,
 }
 
 impl<'a, F: LurkField> ScalarStore<F> {
@@ -47,6 +52,17 @@ impl<'a, F: LurkField> ScalarStore<F> {
         }
     }
 
+    /// Add the `ScalarContPtr` representing `cont`, and queue it for processing.
+    pub fn add_cont_ptr(
+        &mut self,
+        store: &Store<F>,
+        cont: &ContPtr<F>,
+    ) -> Option<ScalarContPtr<F>> {
+        let scalar_ptr = store.hash_cont(cont)?;
+        self.add_cont(store, cont, scalar_ptr);
+        Some(scalar_ptr)
+    }
+
     /// Add a single `ScalarPtr` and queue it for processing.
     /// NOTE: This requires that `store.scalar_cache` has been hydrated.
     fn add_scalar_ptr(&mut self, store: &Store<F>, scalar_ptr: ScalarPtr<F>) {
@@ -61,6 +77,7 @@ impl<'a, F: LurkField> ScalarStore<F> {
     /// enforce this relationship.
     fn add(&mut self, store: &Store<F>, ptr: &Ptr<F>, scalar_ptr: ScalarPtr<F>) {
         let mut new_pending_scalar_ptrs: Vec<ScalarPtr<F>> = Default::default();
+        let mut new_pending_scalar_cont_ptrs: Vec<ScalarContPtr<F>> = Default::default();
 
         // If `scalar_ptr` is not already in the map, queue its children for processing.
         self.scalar_map.entry(scalar_ptr).or_insert_with(|| {
@@ -68,10 +85,43 @@ impl<'a, F: LurkField> ScalarStore<F> {
             if let Some(more_scalar_ptrs) = Self::child_scalar_ptrs(&scalar_expression) {
                 new_pending_scalar_ptrs.extend(more_scalar_ptrs);
             }
+            if let ScalarExpression::Thunk(thunk) = &scalar_expression {
+                new_pending_scalar_cont_ptrs.push(thunk.continuation);
+            }
             Some(scalar_expression)
         });
 
         self.pending_scalar_ptrs.extend(new_pending_scalar_ptrs);
+        self.pending_scalar_cont_ptrs
+            .extend(new_pending_scalar_cont_ptrs);
+    }
+
+    /// Add a single `ScalarContPtr` and queue it for processing.
+    fn add_scalar_cont_ptr(&mut self, store: &Store<F>, scalar_ptr: ScalarContPtr<F>) {
+        // Find the ptr corresponding to scalar_ptr.
+        if let Some(ptr) = store.fetch_scalar_cont(&scalar_ptr) {
+            self.add_cont(store, &ptr, scalar_ptr);
+        }
+    }
+
+    /// Add the `ScalarContPtr` and `ScalarContinuation` associated with `ptr`. Like `add`, this should only be
+    /// called by `add_cont_ptr` and `add_scalar_cont_ptr`.
+    fn add_cont(&mut self, store: &Store<F>, ptr: &ContPtr<F>, scalar_ptr: ScalarContPtr<F>) {
+        let mut new_pending_scalar_ptrs: Vec<ScalarPtr<F>> = Default::default();
+        let mut new_pending_scalar_cont_ptrs: Vec<ScalarContPtr<F>> = Default::default();
+
+        // If `scalar_ptr` is not already in the map, queue its children for processing.
+        self.scalar_cont_map.entry(scalar_ptr).or_insert_with(|| {
+            let scalar_continuation = ScalarContinuation::from_ptr(store, ptr)?;
+            let (scalar_ptrs, scalar_cont_ptr) = scalar_continuation.children();
+            new_pending_scalar_ptrs.extend(scalar_ptrs);
+            new_pending_scalar_cont_ptrs.extend(scalar_cont_ptr);
+            Some(scalar_continuation)
+        });
+
+        self.pending_scalar_ptrs.extend(new_pending_scalar_ptrs);
+        self.pending_scalar_cont_ptrs
+            .extend(new_pending_scalar_cont_ptrs);
     }
 
     /// All the `ScalarPtr`s directly reachable from `scalar_expression`, if any.
@@ -88,18 +138,24 @@ impl<'a, F: LurkField> ScalarStore<F> {
             } => Some([*arg, *body, *closed_env].into()),
             ScalarExpression::Num(_) => None,
             ScalarExpression::Str(_) => None,
-            ScalarExpression::Thunk(_) => None,
+            ScalarExpression::Thunk(thunk) => Some([thunk.value].into()),
             ScalarExpression::Char(_) => None,
+            ScalarExpression::U64(_) => None,
         }
     }
 
-    /// Unqueue all the pending `ScalarPtr`s and add them, queueing all of their children, then repeat until the queue
-    /// is pending queue is empty.
+    /// Unqueue all the pending `ScalarPtr`s and `ScalarContPtr`s and add them, queueing all of their children, then
+    /// repeat until both pending queues are empty.
     fn add_pending_scalar_ptrs(&mut self, store: &Store<F>) {
-        while let Some(scalar_ptr) = self.pending_scalar_ptrs.pop() {
-            self.add_scalar_ptr(store, scalar_ptr);
+        loop {
+            if let Some(scalar_ptr) = self.pending_scalar_ptrs.pop() {
+                self.add_scalar_ptr(store, scalar_ptr);
+            } else if let Some(scalar_ptr) = self.pending_scalar_cont_ptrs.pop() {
+                self.add_scalar_cont_ptr(store, scalar_ptr);
+            } else {
+                break;
+            }
         }
-        assert!(self.pending_scalar_ptrs.is_empty());
     }
 
     /// Method which finalizes the `ScalarStore`, ensuring that all reachable `ScalarPtr`s have been added.
@@ -117,7 +173,7 @@ impl<'a, F: LurkField> ScalarStore<F> {
     }
 
     pub fn to_store_with_expr(&mut self, ptr: &ScalarPtr<F>) -> Option<(Store<F>, Ptr<F>)> {
-        if self.pending_scalar_ptrs.is_empty() {
+        if self.pending_scalar_ptrs.is_empty() && self.pending_scalar_cont_ptrs.is_empty() {
             let mut store = Store::new();
 
             let ptr = store.intern_scalar_ptr(*ptr, self)?;
@@ -134,7 +190,7 @@ impl<'a, F: LurkField> ScalarStore<F> {
         }
     }
     pub fn to_store(&mut self) -> Option<Store<F>> {
-        if self.pending_scalar_ptrs.is_empty() {
+        if self.pending_scalar_ptrs.is_empty() && self.pending_scalar_cont_ptrs.is_empty() {
             let mut store = Store::new();
 
             for ptr in self.scalar_map.keys() {
@@ -191,7 +247,17 @@ impl<'a, F: LurkField> ScalarExpression<F> {
                 .fetch_str(ptr)
                 .map(|str| ScalarExpression::Str(str.to_string())),
             Tag::Char => store.fetch_char(ptr).map(ScalarExpression::Char),
-            Tag::Thunk => unimplemented!(),
+            Tag::U64 => store.fetch_u64(ptr).map(|n| ScalarExpression::U64(*n)),
+            Tag::Thunk => store.fetch_thunk(ptr).and_then(|thunk| {
+                store.get_expr_hash(&thunk.value).and_then(|value| {
+                    store.hash_cont(&thunk.continuation).map(|continuation| {
+                        ScalarExpression::Thunk(ScalarThunk {
+                            value,
+                            continuation,
+                        })
+                    })
+                })
+            }),
         }
     }
 }
@@ -211,6 +277,7 @@ pub enum ScalarExpression<F: LurkField> {
     Str(String),
     Thunk(ScalarThunk<F>),
     Char(char),
+    U64(u64),
 }
 
 impl<'a, F: LurkField> Default for ScalarExpression<F> {
@@ -219,7 +286,6 @@ impl<'a, F: LurkField> Default for ScalarExpression<F> {
     }
 }
 
-// Unused for now, but will be needed when we serialize Thunks to IPLD.
 #[derive(Debug, Clone, PartialEq, Eq, Serialize, Deserialize)]
 pub struct ScalarThunk<F: LurkField> {
     pub(crate) value: ScalarPtr<F>,
@@ -231,21 +297,31 @@ impl<F: LurkField> Copy for ScalarThunk<F> {}
 #[derive(Debug, Copy, Clone, PartialEq, Eq, Hash, Serialize, Deserialize)]
 pub enum ScalarContinuation<F: LurkField> {
     Outermost,
+    Call0 {
+        continuation: ScalarContPtr<F>,
+        reversed_args: ScalarPtr<F>,
+        collected_args: ScalarPtr<F>,
+    },
     Call {
         unevaled_arg: ScalarPtr<F>,
         saved_env: ScalarPtr<F>,
         continuation: ScalarContPtr<F>,
+        reversed_args: ScalarPtr<F>,
     },
     Call2 {
         function: ScalarPtr<F>,
         saved_env: ScalarPtr<F>,
         continuation: ScalarContPtr<F>,
+        reversed_args: ScalarPtr<F>,
     },
     Tail {
         saved_env: ScalarPtr<F>,
         continuation: ScalarContPtr<F>,
     },
-    Error,
+    Error {
+        code: ErrorCode,
+        expr: ScalarPtr<F>,
+    },
     Lookup {
         saved_env: ScalarPtr<F>,
         continuation: ScalarContPtr<F>,
@@ -263,6 +339,7 @@ pub enum ScalarContinuation<F: LurkField> {
     Binop2 {
         operator: Op2,
         evaled_arg: ScalarPtr<F>,
+        unevaled_args: ScalarPtr<F>,
         continuation: ScalarContPtr<F>,
     },
     Relop {
@@ -274,6 +351,7 @@ pub enum ScalarContinuation<F: LurkField> {
     Relop2 {
         operator: Rel2,
         evaled_arg: ScalarPtr<F>,
+        unevaled_args: ScalarPtr<F>,
         continuation: ScalarContPtr<F>,
     },
     If {
@@ -295,10 +373,333 @@ pub enum ScalarContinuation<F: LurkField> {
     Emit {
         continuation: ScalarContPtr<F>,
     },
+    And {
+        unevaled_args: ScalarPtr<F>,
+        continuation: ScalarContPtr<F>,
+    },
+    Or {
+        unevaled_args: ScalarPtr<F>,
+        continuation: ScalarContPtr<F>,
+    },
+    Eval {
+        saved_env: ScalarPtr<F>,
+        continuation: ScalarContPtr<F>,
+    },
+    Apply {
+        unevaled_args: ScalarPtr<F>,
+        saved_env: ScalarPtr<F>,
+        continuation: ScalarContPtr<F>,
+        reversed_args: ScalarPtr<F>,
+    },
+    Catch {
+        tag: ScalarPtr<F>,
+        saved_env: ScalarPtr<F>,
+        continuation: ScalarContPtr<F>,
+    },
+    Throw {
+        tag: ScalarPtr<F>,
+        continuation: ScalarContPtr<F>,
+    },
+    Fail,
     Dummy,
     Terminal,
 }
 
+impl<F: LurkField> ScalarContinuation<F> {
+    fn from_ptr(store: &Store<F>, ptr: &ContPtr<F>) -> Option<Self> {
+        let expr = |ptr: Ptr<F>| store.get_expr_hash(&ptr);
+        let cont = |ptr: ContPtr<F>| store.hash_cont(&ptr);
+        use Continuation::*;
+        let scalar_continuation = match store.fetch_cont(ptr)? {
+            Outermost => Self::Outermost,
+            Call0 {
+                continuation,
+                reversed_args,
+                collected_args,
+            } => Self::Call0 {
+                continuation: cont(continuation)?,
+                reversed_args: expr(reversed_args)?,
+                collected_args: expr(collected_args)?,
+            },
+            Call {
+                unevaled_arg,
+                saved_env,
+                continuation,
+                reversed_args,
+            } => Self::Call {
+                unevaled_arg: expr(unevaled_arg)?,
+                saved_env: expr(saved_env)?,
+                continuation: cont(continuation)?,
+                reversed_args: expr(reversed_args)?,
+            },
+            Call2 {
+                function,
+                saved_env,
+                continuation,
+                reversed_args,
+            } => Self::Call2 {
+                function: expr(function)?,
+                saved_env: expr(saved_env)?,
+                continuation: cont(continuation)?,
+                reversed_args: expr(reversed_args)?,
+            },
+            Tail {
+                saved_env,
+                continuation,
+            } => Self::Tail {
+                saved_env: expr(saved_env)?,
+                continuation: cont(continuation)?,
+            },
+            Error { code, expr: e } => Self::Error {
+                code,
+                expr: expr(e)?,
+            },
+            Lookup {
+                saved_env,
+                continuation,
+            } => Self::Lookup {
+                saved_env: expr(saved_env)?,
+                continuation: cont(continuation)?,
+            },
+            Unop {
+                operator,
+                continuation,
+            } => Self::Unop {
+                operator,
+                continuation: cont(continuation)?,
+            },
+            Binop {
+                operator,
+                saved_env,
+                unevaled_args,
+                continuation,
+            } => Self::Binop {
+                operator,
+                saved_env: expr(saved_env)?,
+                unevaled_args: expr(unevaled_args)?,
+                continuation: cont(continuation)?,
+            },
+            Binop2 {
+                operator,
+                evaled_arg,
+                unevaled_args,
+                continuation,
+            } => Self::Binop2 {
+                operator,
+                evaled_arg: expr(evaled_arg)?,
+                unevaled_args: expr(unevaled_args)?,
+                continuation: cont(continuation)?,
+            },
+            Relop {
+                operator,
+                saved_env,
+                unevaled_args,
+                continuation,
+            } => Self::Relop {
+                operator,
+                saved_env: expr(saved_env)?,
+                unevaled_args: expr(unevaled_args)?,
+                continuation: cont(continuation)?,
+            },
+            Relop2 {
+                operator,
+                evaled_arg,
+                unevaled_args,
+                continuation,
+            } => Self::Relop2 {
+                operator,
+                evaled_arg: expr(evaled_arg)?,
+                unevaled_args: expr(unevaled_args)?,
+                continuation: cont(continuation)?,
+            },
+            If {
+                unevaled_args,
+                continuation,
+            } => Self::If {
+                unevaled_args: expr(unevaled_args)?,
+                continuation: cont(continuation)?,
+            },
+            Let {
+                var,
+                body,
+                saved_env,
+                continuation,
+            } => Self::Let {
+                var: expr(var)?,
+                body: expr(body)?,
+                saved_env: expr(saved_env)?,
+                continuation: cont(continuation)?,
+            },
+            LetRec {
+                var,
+                saved_env,
+                body,
+                continuation,
+            } => Self::LetRec {
+                var: expr(var)?,
+                body: expr(body)?,
+                saved_env: expr(saved_env)?,
+                continuation: cont(continuation)?,
+            },
+            Emit { continuation } => Self::Emit {
+                continuation: cont(continuation)?,
+            },
+            And {
+                unevaled_args,
+                continuation,
+            } => Self::And {
+                unevaled_args: expr(unevaled_args)?,
+                continuation: cont(continuation)?,
+            },
+            Or {
+                unevaled_args,
+                continuation,
+            } => Self::Or {
+                unevaled_args: expr(unevaled_args)?,
+                continuation: cont(continuation)?,
+            },
+            Eval {
+                saved_env,
+                continuation,
+            } => Self::Eval {
+                saved_env: expr(saved_env)?,
+                continuation: cont(continuation)?,
+            },
+            Apply {
+                unevaled_args,
+                saved_env,
+                continuation,
+                reversed_args,
+            } => Self::Apply {
+                unevaled_args: expr(unevaled_args)?,
+                saved_env: expr(saved_env)?,
+                continuation: cont(continuation)?,
+                reversed_args: expr(reversed_args)?,
+            },
+            Catch {
+                tag,
+                saved_env,
+                continuation,
+            } => Self::Catch {
+                tag: expr(tag)?,
+                saved_env: expr(saved_env)?,
+                continuation: cont(continuation)?,
+            },
+            Throw { tag, continuation } => Self::Throw {
+                tag: expr(tag)?,
+                continuation: cont(continuation)?,
+            },
+            Fail => Self::Fail,
+            Dummy => Self::Dummy,
+            Terminal => Self::Terminal,
+        };
+        Some(scalar_continuation)
+    }
+
+    /// All the `ScalarPtr`s directly reachable from this continuation, and the `ScalarContPtr` of the continuation it
+    /// returns to, if any.
+    fn children(&self) -> (Vec<ScalarPtr<F>>, Option<ScalarContPtr<F>>) {
+        use ScalarContinuation::*;
+        match *self {
+            Outermost | Fail | Dummy | Terminal => (vec![], None),
+            Emit { continuation } | Unop { continuation, .. } => (vec![], Some(continuation)),
+            Error { expr, .. } => (vec![expr], None),
+            Tail {
+                saved_env: a,
+                continuation,
+            }
+            | Lookup {
+                saved_env: a,
+                continuation,
+            }
+            | If {
+                unevaled_args: a,
+                continuation,
+            }
+            | And {
+                unevaled_args: a,
+                continuation,
+            }
+            | Or {
+                unevaled_args: a,
+                continuation,
+            }
+            | Eval {
+                saved_env: a,
+                continuation,
+            }
+            | Throw {
+                tag: a,
+                continuation,
+            } => (vec![a], Some(continuation)),
+            Call0 {
+                continuation,
+                reversed_args: a,
+                collected_args: b,
+            }
+            | Binop {
+                saved_env: a,
+                unevaled_args: b,
+                continuation,
+                ..
+            }
+            | Binop2 {
+                evaled_arg: a,
+                unevaled_args: b,
+                continuation,
+                ..
+            }
+            | Relop {
+                saved_env: a,
+                unevaled_args: b,
+                continuation,
+                ..
+            }
+            | Relop2 {
+                evaled_arg: a,
+                unevaled_args: b,
+                continuation,
+                ..
+            }
+            | Catch {
+                tag: a,
+                saved_env: b,
+                continuation,
+            } => (vec![a, b], Some(continuation)),
+            Call {
+                unevaled_arg: a,
+                saved_env: b,
+                continuation,
+                reversed_args: c,
+            }
+            | Call2 {
+                function: a,
+                saved_env: b,
+                continuation,
+                reversed_args: c,
+            }
+            | Apply {
+                unevaled_args: a,
+                saved_env: b,
+                continuation,
+                reversed_args: c,
+            }
+            | Let {
+                var: a,
+                body: b,
+                saved_env: c,
+                continuation,
+            }
+            | LetRec {
+                var: a,
+                body: b,
+                saved_env: c,
+                continuation,
+            } => (vec![a, b, c], Some(continuation)),
+        }
+    }
+}
+
 #[cfg(test)]
 mod test {
     use super::*;
@@ -362,6 +763,7 @@ mod test {
                     }),
                 ),
                 (100, Box::new(|g| Self::Thunk(ScalarThunk::arbitrary(g)))),
+                (100, Box::new(|g| Self::U64(u64::arbitrary(g)))),
             ];
             frequency(g, input)
         }
@@ -371,12 +773,21 @@ mod test {
         fn arbitrary(g: &mut Gen) -> Self {
             let input: Vec<(i64, Box<dyn Fn(&mut Gen) -> ScalarContinuation<Fr>>)> = vec![
                 (100, Box::new(|_| Self::Outermost)),
+                (
+                    100,
+                    Box::new(|g| Self::Call0 {
+                        continuation: ScalarContPtr::arbitrary(g),
+                        reversed_args: ScalarPtr::arbitrary(g),
+                        collected_args: ScalarPtr::arbitrary(g),
+                    }),
+                ),
                 (
                     100,
                     Box::new(|g| Self::Call {
                         unevaled_arg: ScalarPtr::arbitrary(g),
                         saved_env: ScalarPtr::arbitrary(g),
                         continuation: ScalarContPtr::arbitrary(g),
+                        reversed_args: ScalarPtr::arbitrary(g),
                     }),
                 ),
                 (
@@ -385,6 +796,7 @@ mod test {
                         function: ScalarPtr::arbitrary(g),
                         saved_env: ScalarPtr::arbitrary(g),
                         continuation: ScalarContPtr::arbitrary(g),
+                        reversed_args: ScalarPtr::arbitrary(g),
                     }),
                 ),
                 (
@@ -394,7 +806,13 @@ mod test {
                         continuation: ScalarContPtr::arbitrary(g),
                     }),
                 ),
-                (100, Box::new(|_| Self::Error)),
+                (
+                    100,
+                    Box::new(|g| Self::Error {
+                        code: ErrorCode::arbitrary(g),
+                        expr: ScalarPtr::arbitrary(g),
+                    }),
+                ),
                 (
                     100,
                     Box::new(|g| Self::Lookup {
@@ -423,6 +841,7 @@ mod test {
                     Box::new(|g| Self::Binop2 {
                         operator: Op2::arbitrary(g),
                         evaled_arg: ScalarPtr::arbitrary(g),
+                        unevaled_args: ScalarPtr::arbitrary(g),
                         continuation: ScalarContPtr::arbitrary(g),
                     }),
                 ),
@@ -440,6 +859,7 @@ mod test {
                     Box::new(|g| Self::Relop2 {
                         operator: Rel2::arbitrary(g),
                         evaled_arg: ScalarPtr::arbitrary(g),
+                        unevaled_args: ScalarPtr::arbitrary(g),
                         continuation: ScalarContPtr::arbitrary(g),
                     }),
                 ),
@@ -468,6 +888,52 @@ mod test {
                         continuation: ScalarContPtr::arbitrary(g),
                     }),
                 ),
+                (
+                    100,
+                    Box::new(|g| Self::And {
+                        unevaled_args: ScalarPtr::arbitrary(g),
+                        continuation: ScalarContPtr::arbitrary(g),
+                    }),
+                ),
+                (
+                    100,
+                    Box::new(|g| Self::Or {
+                        unevaled_args: ScalarPtr::arbitrary(g),
+                        continuation: ScalarContPtr::arbitrary(g),
+                    }),
+                ),
+                (
+                    100,
+                    Box::new(|g| Self::Eval {
+                        saved_env: ScalarPtr::arbitrary(g),
+                        continuation: ScalarContPtr::arbitrary(g),
+                    }),
+                ),
+                (
+                    100,
+                    Box::new(|g| Self::Apply {
+                        unevaled_args: ScalarPtr::arbitrary(g),
+                        saved_env: ScalarPtr::arbitrary(g),
+                        continuation: ScalarContPtr::arbitrary(g),
+                        reversed_args: ScalarPtr::arbitrary(g),
+                    }),
+                ),
+                (
+                    100,
+                    Box::new(|g| Self::Catch {
+                        tag: ScalarPtr::arbitrary(g),
+                        saved_env: ScalarPtr::arbitrary(g),
+                        continuation: ScalarContPtr::arbitrary(g),
+                    }),
+                ),
+                (
+                    100,
+                    Box::new(|g| Self::Throw {
+                        tag: ScalarPtr::arbitrary(g),
+                        continuation: ScalarContPtr::arbitrary(g),
+                    }),
+                ),
+                (100, Box::new(|_| Self::Fail)),
                 (100, Box::new(|_| Self::Dummy)),
                 (100, Box::new(|_| Self::Terminal)),
             ];
@@ -523,6 +989,7 @@ mod test {
                 scalar_map: map.into_iter().collect(),
                 scalar_cont_map: cont_map.into_iter().collect(),
                 pending_scalar_ptrs: Vec::new(),
+                pending_scalar_cont_ptrs: Vec::new(),
             }
         }
     }
@@ -626,6 +1093,8 @@ mod test {
         test("(+ 1 2 (* 3 4) \"asdf\" )", 16);
         // Duplicate strings or symbols appear only once.
         test("(+ 1 2 2 (* 3 4) \"asdf\" \"asdf\")", 18);
+        // A u64 is distinct from the num with the same value.
+        test("(1u64 . 1)", 3);
     }
 
     #[test]
@@ -10,6 +10,7 @@ use once_cell::sync::OnceCell;
 
 use libipld::Cid;
 
+use crate::eval::ReductionError;
 use crate::field::{FWrap, LurkField};
 use crate::scalar_store::ScalarContinuation;
 use crate::scalar_store::ScalarExpression;
@@ -79,23 +80,31 @@ pub struct Store<F: LurkField> {
 
     // Other sparse storage format without hashing is likely more efficient
     pub(crate) num_store: IndexSet<Num<F>>,
+    u64_store: IndexSet<u64>,
 
     str_store: StringSet,
     thunk_store: IndexSet<Thunk<F>>,
-    call0_store: IndexSet<ContPtr<F>>,
-    call_store: IndexSet<(Ptr<F>, Ptr<F>, ContPtr<F>)>,
-    call2_store: IndexSet<(Ptr<F>, Ptr<F>, ContPtr<F>)>,
+    call0_store: IndexSet<(ContPtr<F>, Ptr<F>, Ptr<F>)>,
+    call_store: IndexSet<(Ptr<F>, Ptr<F>, ContPtr<F>, Ptr<F>)>,
+    call2_store: IndexSet<(Ptr<F>, Ptr<F>, ContPtr<F>, Ptr<F>)>,
     tail_store: IndexSet<(Ptr<F>, ContPtr<F>)>,
     lookup_store: IndexSet<(Ptr<F>, ContPtr<F>)>,
     unop_store: IndexSet<(Op1, ContPtr<F>)>,
     binop_store: IndexSet<(Op2, Ptr<F>, Ptr<F>, ContPtr<F>)>,
-    binop2_store: IndexSet<(Op2, Ptr<F>, ContPtr<F>)>,
+    binop2_store: IndexSet<(Op2, Ptr<F>, Ptr<F>, ContPtr<F>)>,
     relop_store: IndexSet<(Rel2, Ptr<F>, Ptr<F>, ContPtr<F>)>,
-    relop2_store: IndexSet<(Rel2, Ptr<F>, ContPtr<F>)>,
+    relop2_store: IndexSet<(Rel2, Ptr<F>, Ptr<F>, ContPtr<F>)>,
     if_store: IndexSet<(Ptr<F>, ContPtr<F>)>,
     let_store: IndexSet<(Ptr<F>, Ptr<F>, Ptr<F>, ContPtr<F>)>,
     let_rec_store: IndexSet<(Ptr<F>, Ptr<F>, Ptr<F>, ContPtr<F>)>,
     emit_store: IndexSet<ContPtr<F>>,
+    and_store: IndexSet<(Ptr<F>, ContPtr<F>)>,
+    or_store: IndexSet<(Ptr<F>, ContPtr<F>)>,
+    eval_store: IndexSet<(Ptr<F>, ContPtr<F>)>,
+    apply_store: IndexSet<(Ptr<F>, Ptr<F>, ContPtr<F>, Ptr<F>)>,
+    catch_store: IndexSet<(Ptr<F>, Ptr<F>, ContPtr<F>)>,
+    throw_store: IndexSet<(Ptr<F>, ContPtr<F>)>,
+    error_store: IndexSet<(ErrorCode, Ptr<F>)>,
 
     opaque_map: dashmap::DashMap<Ptr<F>, ScalarPtr<F>>,
     /// Holds a mapping of ScalarPtr -> Ptr for reverse lookups
@@ -199,6 +208,10 @@ impl<F: LurkField> Ptr<F> {
         matches!(self.0, Tag::Fun)
     }
 
+    pub fn is_cons(&self) -> bool {
+        matches!(self.0, Tag::Cons)
+    }
+
     pub fn is_opaque(&self) -> bool {
         self.1.is_opaque()
     }
@@ -458,6 +471,7 @@ pub enum Expression<'a, F: LurkField> {
     Thunk(Thunk<F>),
     Opaque(Ptr<F>),
     Char(char),
+    U64(u64),
 }
 
 impl<F: LurkField> Object<F> for Expression<'_, F> {
@@ -481,24 +495,33 @@ impl<F: LurkField> Hash for Thunk<F> {
 #[derive(Debug, Copy, Clone, PartialEq, Eq, Hash)]
 pub enum Continuation<F: LurkField> {
     Outermost,
+    // The arguments of a variadic function's call are collected in reverse, then moved in order
+    // by Call0 before its rest parameter is bound to them.
     Call0 {
         continuation: ContPtr<F>,
+        reversed_args: Ptr<F>,
+        collected_args: Ptr<F>,
     },
     Call {
         unevaled_arg: Ptr<F>,
         saved_env: Ptr<F>,
         continuation: ContPtr<F>,
+        reversed_args: Ptr<F>,
     },
     Call2 {
         function: Ptr<F>,
         saved_env: Ptr<F>,
         continuation: ContPtr<F>,
+        reversed_args: Ptr<F>,
     },
     Tail {
         saved_env: Ptr<F>,
         continuation: ContPtr<F>,
     },
-    Error,
+    Error {
+        code: ErrorCode,
+        expr: Ptr<F>,
+    },
     Lookup {
         saved_env: Ptr<F>,
         continuation: ContPtr<F>,
@@ -516,6 +539,7 @@ pub enum Continuation<F: LurkField> {
     Binop2 {
         operator: Op2,
         evaled_arg: Ptr<F>,
+        unevaled_args: Ptr<F>,
         continuation: ContPtr<F>,
     },
     Relop {
@@ -527,6 +551,7 @@ pub enum Continuation<F: LurkField> {
     Relop2 {
         operator: Rel2,
         evaled_arg: Ptr<F>,
+        unevaled_args: Ptr<F>,
         continuation: ContPtr<F>,
     },
     If {
@@ -548,6 +573,34 @@ pub enum Continuation<F: LurkField> {
     Emit {
         continuation: ContPtr<F>,
     },
+    And {
+        unevaled_args: Ptr<F>,
+        continuation: ContPtr<F>,
+    },
+    Or {
+        unevaled_args: Ptr<F>,
+        continuation: ContPtr<F>,
+    },
+    Eval {
+        saved_env: Ptr<F>,
+        continuation: ContPtr<F>,
+    },
+    Apply {
+        unevaled_args: Ptr<F>,
+        saved_env: Ptr<F>,
+        continuation: ContPtr<F>,
+        reversed_args: Ptr<F>,
+    },
+    Catch {
+        tag: Ptr<F>,
+        saved_env: Ptr<F>,
+        continuation: ContPtr<F>,
+    },
+    Throw {
+        tag: Ptr<F>,
+        continuation: ContPtr<F>,
+    },
+    Fail,
     Dummy,
     Terminal,
 }
@@ -556,6 +609,39 @@ impl<F: LurkField> Object<F> for Continuation<F> {
     type Pointer = ContPtr<F>;
 }
 
+impl<F: LurkField> Continuation<F> {
+    /// Returns the continuation this one returns to, if any.
+    pub fn continuation(&self) -> Option<ContPtr<F>> {
+        match self {
+            Continuation::Call0 { continuation, .. }
+            | Continuation::Call { continuation, .. }
+            | Continuation::Call2 { continuation, .. }
+            | Continuation::Tail { continuation, .. }
+            | Continuation::Lookup { continuation, .. }
+            | Continuation::Unop { continuation, .. }
+            | Continuation::Binop { continuation, .. }
+            | Continuation::Binop2 { continuation, .. }
+            | Continuation::Relop { continuation, .. }
+            | Continuation::Relop2 { continuation, .. }
+            | Continuation::If { continuation, .. }
+            | Continuation::Let { continuation, .. }
+            | Continuation::LetRec { continuation, .. }
+            | Continuation::Emit { continuation }
+            | Continuation::And { continuation, .. }
+            | Continuation::Or { continuation, .. }
+            | Continuation::Eval { continuation, .. }
+            | Continuation::Apply { continuation, .. }
+            | Continuation::Catch { continuation, .. }
+            | Continuation::Throw { continuation, .. } => Some(*continuation),
+            Continuation::Outermost
+            | Continuation::Error { .. }
+            | Continuation::Fail
+            | Continuation::Dummy
+            | Continuation::Terminal => None,
+        }
+    }
+}
+
 #[derive(Copy, Clone, Debug, PartialEq, PartialOrd, Eq, Hash, Serialize_repr, Deserialize_repr)]
 #[repr(u16)]
 pub enum Op1 {
@@ -569,6 +655,17 @@ pub enum Op1 {
     Num,
     Comm,
     Char,
+    U64,
+    TypeOf,
+    IsNil,
+    IsCons,
+    IsSym,
+    IsFun,
+    IsNum,
+    IsStr,
+    IsChar,
+    IsComm,
+    IsU64,
 }
 
 impl fmt::Display for Op1 {
@@ -584,6 +681,17 @@ impl fmt::Display for Op1 {
             Op1::Num => write!(f, "Num"),
             Op1::Comm => write!(f, "Comm"),
             Op1::Char => write!(f, "Char"),
+            Op1::U64 => write!(f, "U64"),
+            Op1::TypeOf => write!(f, "TypeOf"),
+            Op1::IsNil => write!(f, "IsNil"),
+            Op1::IsCons => write!(f, "IsCons"),
+            Op1::IsSym => write!(f, "IsSym"),
+            Op1::IsFun => write!(f, "IsFun"),
+            Op1::IsNum => write!(f, "IsNum"),
+            Op1::IsStr => write!(f, "IsStr"),
+            Op1::IsChar => write!(f, "IsChar"),
+            Op1::IsComm => write!(f, "IsComm"),
+            Op1::IsU64 => write!(f, "IsU64"),
         }
     }
 }
@@ -601,6 +709,17 @@ impl Op1 {
             x if x == Op1::Num as u16 => Some(Op1::Num),
             x if x == Op1::Comm as u16 => Some(Op1::Comm),
             x if x == Op1::Char as u16 => Some(Op1::Char),
+            x if x == Op1::U64 as u16 => Some(Op1::U64),
+            x if x == Op1::TypeOf as u16 => Some(Op1::TypeOf),
+            x if x == Op1::IsNil as u16 => Some(Op1::IsNil),
+            x if x == Op1::IsCons as u16 => Some(Op1::IsCons),
+            x if x == Op1::IsSym as u16 => Some(Op1::IsSym),
+            x if x == Op1::IsFun as u16 => Some(Op1::IsFun),
+            x if x == Op1::IsNum as u16 => Some(Op1::IsNum),
+            x if x == Op1::IsStr as u16 => Some(Op1::IsStr),
+            x if x == Op1::IsChar as u16 => Some(Op1::IsChar),
+            x if x == Op1::IsComm as u16 => Some(Op1::IsComm),
+            x if x == Op1::IsU64 as u16 => Some(Op1::IsU64),
             _ => None,
         }
     }
@@ -608,6 +727,53 @@ impl Op1 {
     pub fn as_field<F: From<u64> + ff::Field>(&self) -> F {
         F::from(*self as u64)
     }
+
+    /// The ops which only inspect the tag of their argument: `type-of` and the type predicates.
+    pub const TYPE_OPS: [Op1; 10] = [
+        Op1::TypeOf,
+        Op1::IsNil,
+        Op1::IsCons,
+        Op1::IsSym,
+        Op1::IsFun,
+        Op1::IsNum,
+        Op1::IsStr,
+        Op1::IsChar,
+        Op1::IsComm,
+        Op1::IsU64,
+    ];
+
+    /// The symbol naming one of the `TYPE_OPS`.
+    pub fn type_op_name(&self) -> Option<&'static str> {
+        match self {
+            Op1::TypeOf => Some("type-of"),
+            Op1::IsNil => Some("nil?"),
+            Op1::IsCons => Some("cons?"),
+            Op1::IsSym => Some("sym?"),
+            Op1::IsFun => Some("fun?"),
+            Op1::IsNum => Some("num?"),
+            Op1::IsStr => Some("str?"),
+            Op1::IsChar => Some("char?"),
+            Op1::IsComm => Some("comm?"),
+            Op1::IsU64 => Some("u64?"),
+            _ => None,
+        }
+    }
+
+    /// The tag a type predicate tests for.
+    pub fn predicate_tag(&self) -> Option<Tag> {
+        match self {
+            Op1::IsNil => Some(Tag::Nil),
+            Op1::IsCons => Some(Tag::Cons),
+            Op1::IsSym => Some(Tag::Sym),
+            Op1::IsFun => Some(Tag::Fun),
+            Op1::IsNum => Some(Tag::Num),
+            Op1::IsStr => Some(Tag::Str),
+            Op1::IsChar => Some(Tag::Char),
+            Op1::IsComm => Some(Tag::Comm),
+            Op1::IsU64 => Some(Tag::U64),
+            _ => None,
+        }
+    }
 }
 
 #[derive(Copy, Clone, Debug, PartialEq, PartialOrd, Eq, Hash, Serialize_repr, Deserialize_repr)]
@@ -620,6 +786,9 @@ pub enum Op2 {
     Cons,
     Begin,
     Hide,
+    Modulo,
+    IntQuotient,
+    StrCons,
 }
 
 impl Op2 {
@@ -632,6 +801,9 @@ impl Op2 {
             x if x == Op2::Cons as u16 => Some(Op2::Cons),
             x if x == Op2::Begin as u16 => Some(Op2::Begin),
             x if x == Op2::Hide as u16 => Some(Op2::Hide),
+            x if x == Op2::Modulo as u16 => Some(Op2::Modulo),
+            x if x == Op2::IntQuotient as u16 => Some(Op2::IntQuotient),
+            x if x == Op2::StrCons as u16 => Some(Op2::StrCons),
             _ => None,
         }
     }
@@ -650,6 +822,9 @@ impl fmt::Display for Op2 {
             Op2::Cons => write!(f, "Cons"),
             Op2::Begin => write!(f, "Begin"),
             Op2::Hide => write!(f, "Hide"),
+            Op2::Modulo => write!(f, "Modulo"),
+            Op2::IntQuotient => write!(f, "IntQuotient"),
+            Op2::StrCons => write!(f, "StrCons"),
         }
     }
 }
@@ -659,6 +834,10 @@ impl fmt::Display for Op2 {
 pub enum Rel2 {
     Equal = 0b0100_0000_0000_0000,
     NumEqual,
+    Less,
+    Greater,
+    LessEqual,
+    GreaterEqual,
 }
 
 impl Rel2 {
@@ -666,6 +845,10 @@ impl Rel2 {
         match x {
             x if x == Rel2::Equal as u16 => Some(Rel2::Equal),
             x if x == Rel2::NumEqual as u16 => Some(Rel2::NumEqual),
+            x if x == Rel2::Less as u16 => Some(Rel2::Less),
+            x if x == Rel2::Greater as u16 => Some(Rel2::Greater),
+            x if x == Rel2::LessEqual as u16 => Some(Rel2::LessEqual),
+            x if x == Rel2::GreaterEqual as u16 => Some(Rel2::GreaterEqual),
             _ => None,
         }
     }
@@ -679,6 +862,58 @@ impl fmt::Display for Rel2 {
         match self {
             Rel2::Equal => write!(f, "Equal"),
             Rel2::NumEqual => write!(f, "NumEqual"),
+            Rel2::Less => write!(f, "Less"),
+            Rel2::Greater => write!(f, "Greater"),
+            Rel2::LessEqual => write!(f, "LessEqual"),
+            Rel2::GreaterEqual => write!(f, "GreaterEqual"),
+        }
+    }
+}
+
+/// Why evaluation failed, as recorded in an error continuation.
+#[derive(Copy, Clone, Debug, PartialEq, PartialOrd, Eq, Hash, Serialize_repr, Deserialize_repr)]
+#[repr(u16)]
+pub enum ErrorCode {
+    /// Not yet classified. Reduction only produces this internally, before the failure is attributed.
+    Unknown = 0b0101_0000_0000_0000,
+    /// A symbol with no binding in the environment.
+    UnboundVariable,
+    /// A special form or call with the wrong shape.
+    InvalidForm,
+    /// A value which cannot be called was applied, or applied to the wrong number of arguments.
+    InvalidCall,
+    /// A built-in received an argument of the wrong type.
+    InvalidArgument,
+    /// The program failed on purpose, with `fail` or a false `assert`.
+    UserFailure,
+}
+
+impl ErrorCode {
+    pub fn from_u16(x: u16) -> Option<Self> {
+        match x {
+            x if x == ErrorCode::Unknown as u16 => Some(ErrorCode::Unknown),
+            x if x == ErrorCode::UnboundVariable as u16 => Some(ErrorCode::UnboundVariable),
+            x if x == ErrorCode::InvalidForm as u16 => Some(ErrorCode::InvalidForm),
+            x if x == ErrorCode::InvalidCall as u16 => Some(ErrorCode::InvalidCall),
+            x if x == ErrorCode::InvalidArgument as u16 => Some(ErrorCode::InvalidArgument),
+            x if x == ErrorCode::UserFailure as u16 => Some(ErrorCode::UserFailure),
+            _ => None,
+        }
+    }
+    pub fn as_field<F: From<u64> + ff::Field>(&self) -> F {
+        F::from(*self as u64)
+    }
+}
+
+impl fmt::Display for ErrorCode {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            ErrorCode::Unknown => write!(f, "Unknown"),
+            ErrorCode::UnboundVariable => write!(f, "UnboundVariable"),
+            ErrorCode::InvalidForm => write!(f, "InvalidForm"),
+            ErrorCode::InvalidCall => write!(f, "InvalidCall"),
+            ErrorCode::InvalidArgument => write!(f, "InvalidArgument"),
+            ErrorCode::UserFailure => write!(f, "UserFailure"),
         }
     }
 }
@@ -695,6 +930,7 @@ pub enum Tag {
     Str,
     Char,
     Comm,
+    U64,
 }
 
 impl From<Tag> for u64 {
@@ -715,7 +951,7 @@ impl Tag {
             f if f == Tag::Str.as_field() => Some(Tag::Str),
             f if f == Tag::Char.as_field() => Some(Tag::Char),
             f if f == Tag::Comm.as_field() => Some(Tag::Comm),
-            f if f == Tag::Char.as_field() => Some(Tag::Char),
+            f if f == Tag::U64.as_field() => Some(Tag::U64),
             _ => None,
         }
     }
@@ -723,6 +959,22 @@ impl Tag {
     pub fn as_field<F: From<u64> + ff::Field>(&self) -> F {
         F::from(*self as u64)
     }
+
+    /// The keyword returned by `type-of` for values with this tag.
+    pub fn keyword(&self) -> &'static str {
+        match self {
+            Tag::Nil => ":nil",
+            Tag::Cons => ":cons",
+            Tag::Sym => ":sym",
+            Tag::Fun => ":fun",
+            Tag::Num => ":num",
+            Tag::Thunk => ":thunk",
+            Tag::Str => ":str",
+            Tag::Char => ":char",
+            Tag::Comm => ":comm",
+            Tag::U64 => ":u64",
+        }
+    }
 }
 
 #[derive(Debug, Copy, Clone, PartialEq, Eq, Hash)]
@@ -746,6 +998,13 @@ pub enum ContTag {
     Dummy,
     Terminal,
     Emit,
+    And,
+    Or,
+    Eval,
+    Fail,
+    Apply,
+    Catch,
+    Throw,
 }
 
 impl From<ContTag> for u64 {
@@ -773,12 +1032,47 @@ impl ContTag {
             f if f == ContTag::Dummy.as_field() => Some(ContTag::Dummy),
             f if f == ContTag::Terminal.as_field() => Some(ContTag::Terminal),
             f if f == ContTag::Emit.as_field() => Some(ContTag::Emit),
+            f if f == ContTag::And.as_field() => Some(ContTag::And),
+            f if f == ContTag::Or.as_field() => Some(ContTag::Or),
+            f if f == ContTag::Eval.as_field() => Some(ContTag::Eval),
+            f if f == ContTag::Fail.as_field() => Some(ContTag::Fail),
+            f if f == ContTag::Apply.as_field() => Some(ContTag::Apply),
+            f if f == ContTag::Catch.as_field() => Some(ContTag::Catch),
+            f if f == ContTag::Throw.as_field() => Some(ContTag::Throw),
             _ => None,
         }
     }
     pub fn as_field<F: From<u64> + ff::Field>(&self) -> F {
         F::from(*self as u64)
     }
+
+    /// The index, among the hash components of a continuation with this tag, of the continuation
+    /// it returns to, or `None` if it has none.
+    pub fn continuation_index(&self) -> Option<usize> {
+        match self {
+            ContTag::Call0 | ContTag::Emit => Some(0),
+            ContTag::Tail
+            | ContTag::Lookup
+            | ContTag::Unop
+            | ContTag::If
+            | ContTag::And
+            | ContTag::Or
+            | ContTag::Eval
+            | ContTag::Throw => Some(1),
+            ContTag::Call | ContTag::Call2 | ContTag::Apply | ContTag::Catch => Some(2),
+            ContTag::Binop
+            | ContTag::Binop2
+            | ContTag::Relop
+            | ContTag::Relop2
+            | ContTag::Let
+            | ContTag::LetRec => Some(3),
+            ContTag::Outermost
+            | ContTag::Terminal
+            | ContTag::Dummy
+            | ContTag::Error
+            | ContTag::Fail => None,
+        }
+    }
 }
 
 impl<F: LurkField> Default for Store<F> {
@@ -788,6 +1082,7 @@ impl<F: LurkField> Default for Store<F> {
             comm_store: Default::default(),
             sym_store: Default::default(),
             num_store: Default::default(),
+            u64_store: Default::default(),
             fun_store: Default::default(),
             str_store: Default::default(),
             thunk_store: Default::default(),
@@ -805,6 +1100,13 @@ impl<F: LurkField> Default for Store<F> {
             let_store: Default::default(),
             let_rec_store: Default::default(),
             emit_store: Default::default(),
+            and_store: Default::default(),
+            or_store: Default::default(),
+            eval_store: Default::default(),
+            apply_store: Default::default(),
+            catch_store: Default::default(),
+            throw_store: Default::default(),
+            error_store: Default::default(),
             opaque_map: Default::default(),
             scalar_ptr_map: Default::default(),
             scalar_ptr_cont_map: Default::default(),
@@ -819,19 +1121,26 @@ impl<F: LurkField> Default for Store<F> {
             "nil",
             "t",
             "quote",
+            "quasiquote",
+            "unquote",
+            "unquote-splicing",
+            "macrolet",
             "lambda",
             "_",
+            "&more",
             "let",
             "letrec",
             "begin",
             "hide",
             "cons",
+            "strcons",
             "car",
             "cdr",
             "commit",
             "num",
             "comm",
             "char",
+            "u64",
             "open",
             "secret",
             "atom",
@@ -840,17 +1149,54 @@ impl<F: LurkField> Default for Store<F> {
             "-",
             "*",
             "/",
+            "%",
+            "div",
             "=",
+            "<",
+            ">",
+            "<=",
+            ">=",
             "eq",
             "current-env",
             "if",
+            "and",
+            "or",
+            "cond",
+            "eval",
+            "fail",
+            "assert",
+            "apply",
+            "catch",
+            "throw",
+            "witness",
+            "read",
             "terminal",
             "dummy",
             "outermost",
-            "error",
         ] {
             store.sym(sym);
         }
+        for op in Op1::TYPE_OPS.iter() {
+            store.sym(op.type_op_name().unwrap());
+        }
+        for tag in [
+            Tag::Nil,
+            Tag::Cons,
+            Tag::Sym,
+            Tag::Fun,
+            Tag::Num,
+            Tag::Thunk,
+            Tag::Str,
+            Tag::Char,
+            Tag::Comm,
+            Tag::U64,
+        ] {
+            store.sym(tag.keyword());
+        }
+
+        // The unclassified error always lives at index 0. See `get_cont_error`.
+        let nil = store.get_nil();
+        store.error_store.insert((ErrorCode::Unknown, nil));
 
         store
     }
@@ -906,16 +1252,14 @@ impl<F: LurkField> Store<F> {
     }
 
     pub fn open_mut(&mut self, ptr: Ptr<F>) -> Option<Ptr<F>> {
-        assert!(ptr.0 == Tag::Comm || ptr.0 == Tag::Num);
-
         let p = match ptr.0 {
             Tag::Comm => ptr,
             Tag::Num => {
-                let scalar = self.fetch_num(&ptr).map(|x| x.into_scalar()).unwrap();
+                let scalar = self.fetch_num(&ptr).map(|x| x.into_scalar())?;
 
                 self.intern_maybe_opaque_comm(scalar)
             }
-            _ => unreachable!(),
+            _ => return None,
         };
 
         if let Some((_secret, payload)) = self.fetch_comm(&p) {
@@ -936,11 +1280,9 @@ impl<F: LurkField> Store<F> {
     }
 
     pub fn secret_mut(&mut self, ptr: Ptr<F>) -> Option<Ptr<F>> {
-        assert_eq!(Tag::Comm, ptr.0);
-
         let p = match ptr.0 {
             Tag::Comm => ptr,
-            _ => unreachable!(),
+            _ => return None,
         };
 
         if let Some((secret, _payload)) = self.fetch_comm(&p) {
@@ -960,6 +1302,10 @@ impl<F: LurkField> Store<F> {
         self.intern_num(num)
     }
 
+    pub fn uint64(&mut self, n: u64) -> Ptr<F> {
+        self.intern_u64(n)
+    }
+
     pub fn str<T: AsRef<str>>(&mut self, name: T) -> Ptr<F> {
         self.intern_str(name)
     }
@@ -1098,18 +1444,33 @@ impl<F: LurkField> Store<F> {
         use ScalarContinuation::*;
         match (tag, cont) {
             (ContTag::Outermost, Some(Outermost)) => Some(self.intern_cont_outermost()),
+            (
+                ContTag::Call0,
+                Some(Call0 {
+                    continuation,
+                    reversed_args,
+                    collected_args,
+                }),
+            ) => {
+                let cont = self.intern_scalar_cont_ptr(*continuation, scalar_store)?;
+                let reversed = self.intern_scalar_ptr(*reversed_args, scalar_store)?;
+                let collected = self.intern_scalar_ptr(*collected_args, scalar_store)?;
+                Some(self.intern_cont_call0(cont, reversed, collected))
+            }
             (
                 ContTag::Call,
                 Some(Call {
                     unevaled_arg,
                     saved_env,
                     continuation,
+                    reversed_args,
                 }),
             ) => {
                 let arg = self.intern_scalar_ptr(*unevaled_arg, scalar_store)?;
                 let env = self.intern_scalar_ptr(*saved_env, scalar_store)?;
                 let cont = self.intern_scalar_cont_ptr(*continuation, scalar_store)?;
-                Some(self.intern_cont_call(arg, env, cont))
+                let reversed = self.intern_scalar_ptr(*reversed_args, scalar_store)?;
+                Some(self.intern_cont_call(arg, env, cont, reversed))
             }
             (
                 ContTag::Call2,
@@ -1117,12 +1478,14 @@ impl<F: LurkField> Store<F> {
                     function,
                     saved_env,
                     continuation,
+                    reversed_args,
                 }),
             ) => {
                 let fun = self.intern_scalar_ptr(*function, scalar_store)?;
                 let env = self.intern_scalar_ptr(*saved_env, scalar_store)?;
                 let cont = self.intern_scalar_cont_ptr(*continuation, scalar_store)?;
-                Some(self.intern_cont_call2(fun, env, cont))
+                let reversed = self.intern_scalar_ptr(*reversed_args, scalar_store)?;
+                Some(self.intern_cont_call2(fun, env, cont, reversed))
             }
             (
                 ContTag::Tail,
@@ -1135,7 +1498,10 @@ impl<F: LurkField> Store<F> {
                 let cont = self.intern_scalar_cont_ptr(*continuation, scalar_store)?;
                 Some(self.intern_cont_tail(env, cont))
             }
-            (ContTag::Error, Some(Error)) => Some(self.intern_cont_error()),
+            (ContTag::Error, Some(Error { code, expr })) => {
+                let expr = self.intern_scalar_ptr(*expr, scalar_store)?;
+                Some(self.intern_cont_error_with(*code, expr))
+            }
             (
                 ContTag::Lookup,
                 Some(Lookup {
@@ -1176,12 +1542,14 @@ impl<F: LurkField> Store<F> {
                 Some(Binop2 {
                     operator,
                     evaled_arg,
+                    unevaled_args,
                     continuation,
                 }),
             ) => {
                 let arg = self.intern_scalar_ptr(*evaled_arg, scalar_store)?;
+                let args = self.intern_scalar_ptr(*unevaled_args, scalar_store)?;
                 let cont = self.intern_scalar_cont_ptr(*continuation, scalar_store)?;
-                Some(self.intern_cont_binop2(*operator, arg, cont))
+                Some(self.intern_cont_binop2(*operator, arg, args, cont))
             }
             (
                 ContTag::Relop,
@@ -1202,12 +1570,14 @@ impl<F: LurkField> Store<F> {
                 Some(Relop2 {
                     operator,
                     evaled_arg,
+                    unevaled_args,
                     continuation,
                 }),
             ) => {
                 let arg = self.intern_scalar_ptr(*evaled_arg, scalar_store)?;
+                let args = self.intern_scalar_ptr(*unevaled_args, scalar_store)?;
                 let cont = self.intern_scalar_cont_ptr(*continuation, scalar_store)?;
-                Some(self.intern_cont_relop2(*operator, arg, cont))
+                Some(self.intern_cont_relop2(*operator, arg, args, cont))
             }
             (
                 ContTag::If,
@@ -1248,14 +1618,81 @@ impl<F: LurkField> Store<F> {
                 let body = self.intern_scalar_ptr(*body, scalar_store)?;
                 let env = self.intern_scalar_ptr(*saved_env, scalar_store)?;
                 let cont = self.intern_scalar_cont_ptr(*continuation, scalar_store)?;
-                Some(self.intern_cont_let(var, body, env, cont))
+                Some(self.intern_cont_let_rec(var, body, env, cont))
             }
             (ContTag::Emit, Some(Emit { continuation })) => {
                 let cont = self.intern_scalar_cont_ptr(*continuation, scalar_store)?;
                 Some(self.intern_cont_emit(cont))
             }
+            (
+                ContTag::And,
+                Some(And {
+                    unevaled_args,
+                    continuation,
+                }),
+            ) => {
+                let args = self.intern_scalar_ptr(*unevaled_args, scalar_store)?;
+                let cont = self.intern_scalar_cont_ptr(*continuation, scalar_store)?;
+                Some(self.intern_cont_and(args, cont))
+            }
+            (
+                ContTag::Or,
+                Some(Or {
+                    unevaled_args,
+                    continuation,
+                }),
+            ) => {
+                let args = self.intern_scalar_ptr(*unevaled_args, scalar_store)?;
+                let cont = self.intern_scalar_cont_ptr(*continuation, scalar_store)?;
+                Some(self.intern_cont_or(args, cont))
+            }
+            (
+                ContTag::Eval,
+                Some(Eval {
+                    saved_env,
+                    continuation,
+                }),
+            ) => {
+                let env = self.intern_scalar_ptr(*saved_env, scalar_store)?;
+                let cont = self.intern_scalar_cont_ptr(*continuation, scalar_store)?;
+                Some(self.intern_cont_eval(env, cont))
+            }
+            (
+                ContTag::Apply,
+                Some(Apply {
+                    unevaled_args,
+                    saved_env,
+                    continuation,
+                    reversed_args,
+                }),
+            ) => {
+                let args = self.intern_scalar_ptr(*unevaled_args, scalar_store)?;
+                let env = self.intern_scalar_ptr(*saved_env, scalar_store)?;
+                let cont = self.intern_scalar_cont_ptr(*continuation, scalar_store)?;
+                let reversed = self.intern_scalar_ptr(*reversed_args, scalar_store)?;
+                Some(self.intern_cont_apply(args, env, cont, reversed))
+            }
+            (
+                ContTag::Catch,
+                Some(Catch {
+                    tag,
+                    saved_env,
+                    continuation,
+                }),
+            ) => {
+                let tag = self.intern_scalar_ptr(*tag, scalar_store)?;
+                let env = self.intern_scalar_ptr(*saved_env, scalar_store)?;
+                let cont = self.intern_scalar_cont_ptr(*continuation, scalar_store)?;
+                Some(self.intern_cont_catch(tag, env, cont))
+            }
+            (ContTag::Throw, Some(Throw { tag, continuation })) => {
+                let tag = self.intern_scalar_ptr(*tag, scalar_store)?;
+                let cont = self.intern_scalar_cont_ptr(*continuation, scalar_store)?;
+                Some(self.intern_cont_throw(tag, cont))
+            }
             (ContTag::Dummy, Some(Dummy)) => Some(self.intern_cont_dummy()),
             (ContTag::Terminal, Some(Terminal)) => Some(self.intern_cont_terminal()),
+            (ContTag::Fail, Some(Fail)) => Some(self.intern_cont_fail()),
             _ => None,
         }
     }
@@ -1278,6 +1715,7 @@ impl<F: LurkField> Store<F> {
             (Tag::Str, Some(Str(s))) => Some(self.intern_str(s)),
             (Tag::Sym, Some(Sym(s))) => Some(self.intern_sym(s)),
             (Tag::Num, Some(Num(x))) => Some(self.intern_num(crate::Num::Scalar(*x))),
+            (Tag::U64, Some(U64(x))) => Some(self.intern_u64(*x)),
             (Tag::Thunk, Some(Thunk(t))) => {
                 let value = self.intern_scalar_ptr(t.value, scalar_store)?;
                 let continuation = self.intern_scalar_cont_ptr(t.continuation, scalar_store)?;
@@ -1422,6 +1860,18 @@ impl<F: LurkField> Store<F> {
             .map(|x| Ptr(Tag::Num, RawPtr::new(x)))
     }
 
+    pub fn intern_u64(&mut self, n: u64) -> Ptr<F> {
+        let (ptr, _) = self.u64_store.insert_full(n);
+
+        Ptr(Tag::U64, RawPtr::new(ptr))
+    }
+
+    pub fn get_u64(&self, n: u64) -> Option<Ptr<F>> {
+        self.u64_store
+            .get_index_of(&n)
+            .map(|x| Ptr(Tag::U64, RawPtr::new(x)))
+    }
+
     pub fn get_char(&self, c: char) -> Ptr<F> {
         Ptr(Tag::Char, RawPtr::new(u32::from(c) as usize))
     }
@@ -1483,8 +1933,8 @@ impl<F: LurkField> Store<F> {
         ContPtr(ContTag::Outermost, RawPtr::new(ptr.to_usize()))
     }
 
-    pub fn intern_cont_call0(&mut self, a: ContPtr<F>) -> ContPtr<F> {
-        let (p, inserted) = self.call0_store.insert_full(a);
+    pub fn intern_cont_call0(&mut self, a: ContPtr<F>, b: Ptr<F>, c: Ptr<F>) -> ContPtr<F> {
+        let (p, inserted) = self.call0_store.insert_full((a, b, c));
         let ptr = ContPtr(ContTag::Call0, RawPtr::new(p));
         if inserted {
             self.dehydrated_cont.push(ptr)
@@ -1492,8 +1942,14 @@ impl<F: LurkField> Store<F> {
         ptr
     }
 
-    pub fn intern_cont_call(&mut self, a: Ptr<F>, b: Ptr<F>, c: ContPtr<F>) -> ContPtr<F> {
-        let (p, inserted) = self.call_store.insert_full((a, b, c));
+    pub fn intern_cont_call(
+        &mut self,
+        a: Ptr<F>,
+        b: Ptr<F>,
+        c: ContPtr<F>,
+        d: Ptr<F>,
+    ) -> ContPtr<F> {
+        let (p, inserted) = self.call_store.insert_full((a, b, c, d));
         let ptr = ContPtr(ContTag::Call, RawPtr::new(p));
         if inserted {
             self.dehydrated_cont.push(ptr)
@@ -1501,8 +1957,14 @@ impl<F: LurkField> Store<F> {
         ptr
     }
 
-    pub fn intern_cont_call2(&mut self, a: Ptr<F>, b: Ptr<F>, c: ContPtr<F>) -> ContPtr<F> {
-        let (p, inserted) = self.call2_store.insert_full((a, b, c));
+    pub fn intern_cont_call2(
+        &mut self,
+        a: Ptr<F>,
+        b: Ptr<F>,
+        c: ContPtr<F>,
+        d: Ptr<F>,
+    ) -> ContPtr<F> {
+        let (p, inserted) = self.call2_store.insert_full((a, b, c, d));
         let ptr = ContPtr(ContTag::Call2, RawPtr::new(p));
         if inserted {
             self.dehydrated_cont.push(ptr)
@@ -1510,13 +1972,22 @@ impl<F: LurkField> Store<F> {
         ptr
     }
 
+    /// The unclassified error, which reduction returns before attributing the failure.
     pub fn intern_cont_error(&mut self) -> ContPtr<F> {
         self.mark_dehydrated_cont(self.get_cont_error())
     }
 
     pub fn get_cont_error(&self) -> ContPtr<F> {
-        let ptr = self.sym_store.0.get("ERROR").expect("pre stored");
-        ContPtr(ContTag::Error, RawPtr::new(ptr.to_usize()))
+        ContPtr(ContTag::Error, RawPtr::new(0))
+    }
+
+    pub fn intern_cont_error_with(&mut self, code: ErrorCode, expr: Ptr<F>) -> ContPtr<F> {
+        let (p, inserted) = self.error_store.insert_full((code, expr));
+        let ptr = ContPtr(ContTag::Error, RawPtr::new(p));
+        if inserted {
+            self.dehydrated_cont.push(ptr)
+        }
+        ptr
     }
 
     pub fn intern_cont_terminal(&mut self) -> ContPtr<F> {
@@ -1528,6 +1999,15 @@ impl<F: LurkField> Store<F> {
         ContPtr(ContTag::Terminal, RawPtr::new(ptr.to_usize()))
     }
 
+    pub fn intern_cont_fail(&mut self) -> ContPtr<F> {
+        self.mark_dehydrated_cont(self.get_cont_fail())
+    }
+
+    pub fn get_cont_fail(&self) -> ContPtr<F> {
+        let ptr = self.sym_store.0.get("FAIL").expect("pre stored");
+        ContPtr(ContTag::Fail, RawPtr::new(ptr.to_usize()))
+    }
+
     pub fn intern_cont_dummy(&mut self) -> ContPtr<F> {
         self.mark_dehydrated_cont(self.get_cont_dummy())
     }
@@ -1608,8 +2088,14 @@ impl<F: LurkField> Store<F> {
         ptr
     }
 
-    pub fn intern_cont_binop2(&mut self, op: Op2, a: Ptr<F>, b: ContPtr<F>) -> ContPtr<F> {
-        let (p, inserted) = self.binop2_store.insert_full((op, a, b));
+    pub fn intern_cont_binop2(
+        &mut self,
+        op: Op2,
+        a: Ptr<F>,
+        b: Ptr<F>,
+        c: ContPtr<F>,
+    ) -> ContPtr<F> {
+        let (p, inserted) = self.binop2_store.insert_full((op, a, b, c));
         let ptr = ContPtr(ContTag::Binop2, RawPtr::new(p));
         if inserted {
             self.dehydrated_cont.push(ptr)
@@ -1632,8 +2118,14 @@ impl<F: LurkField> Store<F> {
         ptr
     }
 
-    pub fn intern_cont_relop2(&mut self, op: Rel2, a: Ptr<F>, b: ContPtr<F>) -> ContPtr<F> {
-        let (p, inserted) = self.relop2_store.insert_full((op, a, b));
+    pub fn intern_cont_relop2(
+        &mut self,
+        op: Rel2,
+        a: Ptr<F>,
+        b: Ptr<F>,
+        c: ContPtr<F>,
+    ) -> ContPtr<F> {
+        let (p, inserted) = self.relop2_store.insert_full((op, a, b, c));
         let ptr = ContPtr(ContTag::Relop2, RawPtr::new(p));
         if inserted {
             self.dehydrated_cont.push(ptr)
@@ -1650,6 +2142,66 @@ impl<F: LurkField> Store<F> {
         ptr
     }
 
+    pub fn intern_cont_and(&mut self, a: Ptr<F>, b: ContPtr<F>) -> ContPtr<F> {
+        let (p, inserted) = self.and_store.insert_full((a, b));
+        let ptr = ContPtr(ContTag::And, RawPtr::new(p));
+        if inserted {
+            self.dehydrated_cont.push(ptr)
+        }
+        ptr
+    }
+
+    pub fn intern_cont_or(&mut self, a: Ptr<F>, b: ContPtr<F>) -> ContPtr<F> {
+        let (p, inserted) = self.or_store.insert_full((a, b));
+        let ptr = ContPtr(ContTag::Or, RawPtr::new(p));
+        if inserted {
+            self.dehydrated_cont.push(ptr)
+        }
+        ptr
+    }
+
+    pub fn intern_cont_eval(&mut self, a: Ptr<F>, b: ContPtr<F>) -> ContPtr<F> {
+        let (p, inserted) = self.eval_store.insert_full((a, b));
+        let ptr = ContPtr(ContTag::Eval, RawPtr::new(p));
+        if inserted {
+            self.dehydrated_cont.push(ptr)
+        }
+        ptr
+    }
+
+    pub fn intern_cont_apply(
+        &mut self,
+        a: Ptr<F>,
+        b: Ptr<F>,
+        c: ContPtr<F>,
+        d: Ptr<F>,
+    ) -> ContPtr<F> {
+        let (p, inserted) = self.apply_store.insert_full((a, b, c, d));
+        let ptr = ContPtr(ContTag::Apply, RawPtr::new(p));
+        if inserted {
+            self.dehydrated_cont.push(ptr)
+        }
+        ptr
+    }
+
+    pub fn intern_cont_catch(&mut self, a: Ptr<F>, b: Ptr<F>, c: ContPtr<F>) -> ContPtr<F> {
+        let (p, inserted) = self.catch_store.insert_full((a, b, c));
+        let ptr = ContPtr(ContTag::Catch, RawPtr::new(p));
+        if inserted {
+            self.dehydrated_cont.push(ptr)
+        }
+        ptr
+    }
+
+    pub fn intern_cont_throw(&mut self, a: Ptr<F>, b: ContPtr<F>) -> ContPtr<F> {
+        let (p, inserted) = self.throw_store.insert_full((a, b));
+        let ptr = ContPtr(ContTag::Throw, RawPtr::new(p));
+        if inserted {
+            self.dehydrated_cont.push(ptr)
+        }
+        ptr
+    }
+
     pub fn intern_cont_tail(&mut self, a: Ptr<F>, b: ContPtr<F>) -> ContPtr<F> {
         let (p, inserted) = self.tail_store.insert_full((a, b));
         let ptr = ContPtr(ContTag::Tail, RawPtr::new(p));
@@ -1753,7 +2305,12 @@ impl<F: LurkField> Store<F> {
         self.num_store.get_index(ptr.1.idx())
     }
 
-    fn fetch_thunk(&self, ptr: &Ptr<F>) -> Option<&Thunk<F>> {
+    pub(crate) fn fetch_u64(&self, ptr: &Ptr<F>) -> Option<&u64> {
+        debug_assert!(matches!(ptr.0, Tag::U64));
+        self.u64_store.get_index(ptr.1.idx())
+    }
+
+    pub(crate) fn fetch_thunk(&self, ptr: &Ptr<F>) -> Option<&Thunk<F>> {
         debug_assert!(matches!(ptr.0, Tag::Thunk));
         self.thunk_store.get_index(ptr.1.idx())
     }
@@ -1774,6 +2331,7 @@ impl<F: LurkField> Store<F> {
             Tag::Thunk => self.fetch_thunk(ptr).map(|thunk| Expression::Thunk(*thunk)),
             Tag::Str => self.fetch_str(ptr).map(|str| Expression::Str(str)),
             Tag::Char => self.fetch_char(ptr).map(Expression::Char),
+            Tag::U64 => self.fetch_u64(ptr).map(|n| Expression::U64(*n)),
         }
     }
 
@@ -1784,23 +2342,30 @@ impl<F: LurkField> Store<F> {
             Call0 => self
                 .call0_store
                 .get_index(ptr.1.idx())
-                .map(|c| Continuation::Call0 { continuation: *c }),
+                .map(|(a, b, c)| Continuation::Call0 {
+                    continuation: *a,
+                    reversed_args: *b,
+                    collected_args: *c,
+                }),
             Call => self
                 .call_store
                 .get_index(ptr.1.idx())
-                .map(|(a, b, c)| Continuation::Call {
+                .map(|(a, b, c, d)| Continuation::Call {
                     unevaled_arg: *a,
                     saved_env: *b,
                     continuation: *c,
+                    reversed_args: *d,
                 }),
-            Call2 => self
-                .call2_store
-                .get_index(ptr.1.idx())
-                .map(|(a, b, c)| Continuation::Call2 {
-                    function: *a,
-                    saved_env: *b,
-                    continuation: *c,
-                }),
+            Call2 => {
+                self.call2_store
+                    .get_index(ptr.1.idx())
+                    .map(|(a, b, c, d)| Continuation::Call2 {
+                        function: *a,
+                        saved_env: *b,
+                        continuation: *c,
+                        reversed_args: *d,
+                    })
+            }
             Tail => self
                 .tail_store
                 .get_index(ptr.1.idx())
@@ -1808,7 +2373,14 @@ impl<F: LurkField> Store<F> {
                     saved_env: *a,
                     continuation: *b,
                 }),
-            Error => Some(Continuation::Error),
+            Error => {
+                self.error_store
+                    .get_index(ptr.1.idx())
+                    .map(|(code, expr)| Continuation::Error {
+                        code: *code,
+                        expr: *expr,
+                    })
+            }
             Lookup => self
                 .lookup_store
                 .get_index(ptr.1.idx())
@@ -1833,15 +2405,15 @@ impl<F: LurkField> Store<F> {
                         continuation: *d,
                     })
             }
-            Binop2 => {
-                self.binop2_store
-                    .get_index(ptr.1.idx())
-                    .map(|(a, b, c)| Continuation::Binop2 {
-                        operator: *a,
-                        evaled_arg: *b,
-                        continuation: *c,
-                    })
-            }
+            Binop2 => self
+                .binop2_store
+                .get_index(ptr.1.idx())
+                .map(|(a, b, c, d)| Continuation::Binop2 {
+                    operator: *a,
+                    evaled_arg: *b,
+                    unevaled_args: *c,
+                    continuation: *d,
+                }),
             Relop => {
                 self.relop_store
                     .get_index(ptr.1.idx())
@@ -1852,15 +2424,15 @@ impl<F: LurkField> Store<F> {
                         continuation: *d,
                     })
             }
-            Relop2 => {
-                self.relop2_store
-                    .get_index(ptr.1.idx())
-                    .map(|(a, b, c)| Continuation::Relop2 {
-                        operator: *a,
-                        evaled_arg: *b,
-                        continuation: *c,
-                    })
-            }
+            Relop2 => self
+                .relop2_store
+                .get_index(ptr.1.idx())
+                .map(|(a, b, c, d)| Continuation::Relop2 {
+                    operator: *a,
+                    evaled_arg: *b,
+                    unevaled_args: *c,
+                    continuation: *d,
+                }),
             If => self
                 .if_store
                 .get_index(ptr.1.idx())
@@ -1888,26 +2460,74 @@ impl<F: LurkField> Store<F> {
                 }),
             Dummy => Some(Continuation::Dummy),
             Terminal => Some(Continuation::Terminal),
+            Fail => Some(Continuation::Fail),
             Emit => self
                 .emit_store
                 .get_index(ptr.1.idx())
                 .map(|continuation| Continuation::Emit {
                     continuation: *continuation,
                 }),
+            And => self
+                .and_store
+                .get_index(ptr.1.idx())
+                .map(|(a, b)| Continuation::And {
+                    unevaled_args: *a,
+                    continuation: *b,
+                }),
+            Or => self
+                .or_store
+                .get_index(ptr.1.idx())
+                .map(|(a, b)| Continuation::Or {
+                    unevaled_args: *a,
+                    continuation: *b,
+                }),
+            Eval => self
+                .eval_store
+                .get_index(ptr.1.idx())
+                .map(|(a, b)| Continuation::Eval {
+                    saved_env: *a,
+                    continuation: *b,
+                }),
+            Apply => {
+                self.apply_store
+                    .get_index(ptr.1.idx())
+                    .map(|(a, b, c, d)| Continuation::Apply {
+                        unevaled_args: *a,
+                        saved_env: *b,
+                        continuation: *c,
+                        reversed_args: *d,
+                    })
+            }
+            Catch => self
+                .catch_store
+                .get_index(ptr.1.idx())
+                .map(|(a, b, c)| Continuation::Catch {
+                    tag: *a,
+                    saved_env: *b,
+                    continuation: *c,
+                }),
+            Throw => self
+                .throw_store
+                .get_index(ptr.1.idx())
+                .map(|(a, b)| Continuation::Throw {
+                    tag: *a,
+                    continuation: *b,
+                }),
         }
     }
 
     /// Mutable version of car_cdr to handle Str. `(cdr str)` may return a new str (the tail), which must be allocated.
-    pub fn car_cdr_mut(&mut self, ptr: &Ptr<F>) -> Result<(Ptr<F>, Ptr<F>), &str> {
+    /// Fails, rather than panicking, on values which are not lists or whose contents are unknown.
+    pub fn car_cdr_mut(&mut self, ptr: &Ptr<F>) -> Result<(Ptr<F>, Ptr<F>), ReductionError> {
         match ptr.0 {
             Tag::Nil => Ok((self.get_nil(), self.get_nil())),
             Tag::Cons => match self.fetch(ptr) {
                 Some(Expression::Cons(car, cdr)) => Ok((car, cdr)),
-                Some(Expression::Opaque(_)) => panic!("cannot destructure opaque Cons"),
-                _ => unreachable!(),
+                Some(Expression::Opaque(_)) => Err(ReductionError::Opaque),
+                _ => Err(ReductionError::Missing),
             },
-            Tag::Str => {
-                if let Some(Expression::Str(s)) = self.fetch(ptr) {
+            Tag::Str => match self.fetch(ptr) {
+                Some(Expression::Str(s)) => {
                     let mut str = s.chars();
                     if let Some(c) = str.next() {
                         let cdr_str: String = str.collect();
@@ -1916,42 +2536,52 @@ impl<F: LurkField> Store<F> {
                     } else {
                         Ok((self.nil(), self.intern_str(&"")))
                     }
-                } else {
-                    panic!();
                 }
-            }
-            _ => Err("Invalid tag"),
+                Some(Expression::Opaque(_)) => Err(ReductionError::Opaque),
+                _ => Err(ReductionError::Missing),
+            },
+            _ => Err(ReductionError::NotAList),
+        }
+    }
+
+    /// Like `car_cdr_mut`, but a value which is not a list is taken to be `(nil . nil)`, as the
+    /// circuit does when destructuring forms, argument lists and environments.
+    pub fn car_cdr_or_nil(&mut self, ptr: &Ptr<F>) -> Result<(Ptr<F>, Ptr<F>), ReductionError> {
+        match self.car_cdr_mut(ptr) {
+            Err(ReductionError::NotAList) => Ok((self.get_nil(), self.get_nil())),
+            res => res,
         }
     }
 
     pub fn car_cdr(&self, ptr: &Ptr<F>) -> (Ptr<F>, Ptr<F>) {
-        // FIXME: Maybe make error.
+        self.get_car_cdr(ptr)
+            .expect("Can only extract car_cdr from Cons")
+    }
+
+    /// Immutable version of `car_cdr_mut`, for a `(cdr str)` whose tail is already in the store.
+    pub fn get_car_cdr(&self, ptr: &Ptr<F>) -> Result<(Ptr<F>, Ptr<F>), ReductionError> {
         match ptr.0 {
-            Tag::Nil => (self.get_nil(), self.get_nil()),
+            Tag::Nil => Ok((self.get_nil(), self.get_nil())),
             Tag::Cons => match self.fetch(ptr) {
-                Some(Expression::Cons(car, cdr)) => (car, cdr),
-                Some(Expression::Opaque(_)) => panic!("cannot destructure opaque Cons"),
-                _ => unreachable!(),
+                Some(Expression::Cons(car, cdr)) => Ok((car, cdr)),
+                Some(Expression::Opaque(_)) => Err(ReductionError::Opaque),
+                _ => Err(ReductionError::Missing),
             },
-            Tag::Str => {
-                if let Some(Expression::Str(s)) = self.fetch(ptr) {
+            Tag::Str => match self.fetch(ptr) {
+                Some(Expression::Str(s)) => {
                     let mut chars = s.chars();
-                    if let Some(c) = chars.next() {
-                        let cdr_str: String = chars.collect();
-                        let str = self.get_str(&cdr_str).expect("cdr str missing");
-                        (self.get_char(c), str)
-                    } else {
-                        (self.get_nil(), self.get_str(&"").unwrap())
-                    }
-                } else {
-                    panic!();
+                    let car = match chars.next() {
+                        Some(c) => self.get_char(c),
+                        None => self.get_nil(),
+                    };
+                    let cdr_str: String = chars.collect();
+                    let cdr = self.get_str(&cdr_str).ok_or(ReductionError::Missing)?;
+                    Ok((car, cdr))
                 }
-            }
-            _ => {
-                // FIXME: Don't panic. This can happen at runtime in a valid Lurk program,
-                // so it should result in an explicit error.
-                panic!("Can only extract car_cdr from Cons")
-            }
+                Some(Expression::Opaque(_)) => Err(ReductionError::Opaque),
+                _ => Err(ReductionError::Missing),
+            },
+            _ => Err(ReductionError::NotAList),
         }
     }
 
@@ -1966,6 +2596,7 @@ impl<F: LurkField> Store<F> {
             Num => self.hash_num(*ptr),
             Str => self.hash_str(*ptr),
             Char => self.hash_char(*ptr),
+            U64 => self.hash_u64(*ptr),
             Thunk => self.hash_thunk(*ptr),
         }
     }
@@ -1985,6 +2616,7 @@ impl<F: LurkField> Store<F> {
             Num => self.get_hash_num(*ptr),
             Str => self.get_hash_str(*ptr),
             Char => self.get_hash_char(*ptr),
+            U64 => self.get_hash_u64(*ptr),
             Thunk => self.get_hash_thunk(*ptr),
         }
     }
@@ -2032,18 +2664,29 @@ impl<F: LurkField> Store<F> {
         let cont = self.fetch_cont(ptr)?;
 
         let hash = match &cont {
-            Outermost | Terminal | Dummy | Error => self.get_hash_components_default(),
-            Call0 { continuation } => self.get_hash_components_call0(continuation)?,
+            Outermost | Terminal | Dummy | Fail => self.get_hash_components_default(),
+            Error { code, expr } => self.get_hash_components_error(code, expr)?,
+            Call0 {
+                continuation,
+                reversed_args,
+                collected_args,
+            } => self.get_hash_components_call0(continuation, reversed_args, collected_args)?,
             Call {
                 unevaled_arg,
                 saved_env,
                 continuation,
-            } => self.get_hash_components_call(unevaled_arg, saved_env, continuation)?,
+                reversed_args,
+            } => {
+                self.get_hash_components_call(unevaled_arg, saved_env, continuation, reversed_args)?
+            }
             Call2 {
                 function,
                 saved_env,
                 continuation,
-            } => self.get_hash_components_call2(function, saved_env, continuation)?,
+                reversed_args,
+            } => {
+                self.get_hash_components_call2(function, saved_env, continuation, reversed_args)?
+            }
             Tail {
                 saved_env,
                 continuation,
@@ -2067,8 +2710,11 @@ impl<F: LurkField> Store<F> {
             Binop2 {
                 operator,
                 evaled_arg,
+                unevaled_args,
                 continuation,
-            } => self.get_hash_components_binop2(operator, evaled_arg, continuation)?,
+            } => {
+                self.get_hash_components_binop2(operator, evaled_arg, unevaled_args, continuation)?
+            }
             Relop {
                 operator,
                 saved_env,
@@ -2080,8 +2726,11 @@ impl<F: LurkField> Store<F> {
             Relop2 {
                 operator,
                 evaled_arg,
+                unevaled_args,
                 continuation,
-            } => self.get_hash_components_relop2(operator, evaled_arg, continuation)?,
+            } => {
+                self.get_hash_components_relop2(operator, evaled_arg, unevaled_args, continuation)?
+            }
             If {
                 unevaled_args,
                 continuation,
@@ -2099,6 +2748,40 @@ impl<F: LurkField> Store<F> {
                 continuation,
             } => self.get_hash_components_let_rec(var, body, saved_env, continuation)?,
             Emit { continuation } => self.get_hash_components_emit(continuation)?,
+            // And and Or are laid out like If, so the circuit can share the destructuring of
+            // their unevaluated arguments.
+            And {
+                unevaled_args,
+                continuation,
+            }
+            | Or {
+                unevaled_args,
+                continuation,
+            } => self.get_hash_components_if(unevaled_args, continuation)?,
+            // Eval is laid out like Tail.
+            Eval {
+                saved_env,
+                continuation,
+            } => self.get_hash_components_tail(saved_env, continuation)?,
+            // Apply is laid out like Call.
+            Apply {
+                unevaled_args,
+                saved_env,
+                continuation,
+                reversed_args,
+            } => self.get_hash_components_call(
+                unevaled_args,
+                saved_env,
+                continuation,
+                reversed_args,
+            )?,
+            // Catch is laid out like Call without collected arguments, and Throw like If.
+            Catch {
+                tag,
+                saved_env,
+                continuation,
+            } => self.get_hash_components_catch(tag, saved_env, continuation)?,
+            Throw { tag, continuation } => self.get_hash_components_if(tag, continuation)?,
         };
 
         Some([
@@ -2150,13 +2833,14 @@ impl<F: LurkField> Store<F> {
         &self,
         rel: &Rel2,
         arg1: &Ptr<F>,
+        unevaled_args: &Ptr<F>,
         cont: &ContPtr<F>,
     ) -> Option<[[F; 2]; 4]> {
-        let def = [F::zero(), F::zero()];
         let rel = self.hash_rel2(rel).into_hash_components();
         let arg1 = self.get_expr_hash(arg1)?.into_hash_components();
+        let unevaled_args = self.get_expr_hash(unevaled_args)?.into_hash_components();
         let cont = self.hash_cont(cont)?.into_hash_components();
-        Some([rel, arg1, cont, def])
+        Some([rel, arg1, unevaled_args, cont])
     }
 
     fn get_hash_components_relop(
@@ -2177,13 +2861,14 @@ impl<F: LurkField> Store<F> {
         &self,
         op: &Op2,
         arg1: &Ptr<F>,
+        unevaled_args: &Ptr<F>,
         cont: &ContPtr<F>,
     ) -> Option<[[F; 2]; 4]> {
-        let def = [F::zero(), F::zero()];
         let op = self.hash_op2(op).into_hash_components();
         let arg1 = self.get_expr_hash(arg1)?.into_hash_components();
+        let unevaled_args = self.get_expr_hash(unevaled_args)?.into_hash_components();
         let cont = self.hash_cont(cont)?.into_hash_components();
-        Some([op, arg1, cont, def])
+        Some([op, arg1, unevaled_args, cont])
     }
 
     fn get_hash_components_binop(
@@ -2207,6 +2892,13 @@ impl<F: LurkField> Store<F> {
         Some([op, cont, def, def])
     }
 
+    fn get_hash_components_error(&self, code: &ErrorCode, expr: &Ptr<F>) -> Option<[[F; 2]; 4]> {
+        let def = [F::zero(), F::zero()];
+        let code = self.hash_error_code(code).into_hash_components();
+        let expr = self.get_expr_hash(expr)?.into_hash_components();
+        Some([code, expr, def, def])
+    }
+
     fn get_hash_components_lookup(
         &self,
         saved_env: &Ptr<F>,
@@ -2229,12 +2921,19 @@ impl<F: LurkField> Store<F> {
         Some([saved_env, cont, def, def])
     }
 
-    fn get_hash_components_call0(&self, cont: &ContPtr<F>) -> Option<[[F; 2]; 4]> {
+    fn get_hash_components_call0(
+        &self,
+        cont: &ContPtr<F>,
+        reversed_args: &Ptr<F>,
+        collected_args: &Ptr<F>,
+    ) -> Option<[[F; 2]; 4]> {
         let def = [F::zero(), F::zero()];
 
         let cont = self.hash_cont(cont)?.into_hash_components();
+        let reversed_args = self.get_expr_hash(reversed_args)?.into_hash_components();
+        let collected_args = self.get_expr_hash(collected_args)?.into_hash_components();
 
-        Some([cont, def, def, def])
+        Some([cont, reversed_args, collected_args, def])
     }
 
     fn get_hash_components_call(
@@ -2242,13 +2941,28 @@ impl<F: LurkField> Store<F> {
         arg: &Ptr<F>,
         saved_env: &Ptr<F>,
         cont: &ContPtr<F>,
+        reversed_args: &Ptr<F>,
     ) -> Option<[[F; 2]; 4]> {
-        let def = [F::zero(), F::zero()];
         let arg = self.get_expr_hash(arg)?.into_hash_components();
         let saved_env = self.get_expr_hash(saved_env)?.into_hash_components();
         let cont = self.hash_cont(cont)?.into_hash_components();
+        let reversed_args = self.get_expr_hash(reversed_args)?.into_hash_components();
+
+        Some([saved_env, arg, cont, reversed_args])
+    }
+
+    fn get_hash_components_catch(
+        &self,
+        tag: &Ptr<F>,
+        saved_env: &Ptr<F>,
+        cont: &ContPtr<F>,
+    ) -> Option<[[F; 2]; 4]> {
+        let def = [F::zero(), F::zero()];
+        let tag = self.get_expr_hash(tag)?.into_hash_components();
+        let saved_env = self.get_expr_hash(saved_env)?.into_hash_components();
+        let cont = self.hash_cont(cont)?.into_hash_components();
 
-        Some([saved_env, arg, cont, def])
+        Some([saved_env, tag, cont, def])
     }
 
     fn get_hash_components_call2(
@@ -2256,12 +2970,13 @@ impl<F: LurkField> Store<F> {
         fun: &Ptr<F>,
         saved_env: &Ptr<F>,
         cont: &ContPtr<F>,
+        reversed_args: &Ptr<F>,
     ) -> Option<[[F; 2]; 4]> {
-        let def = [F::zero(), F::zero()];
         let fun = self.get_expr_hash(fun)?.into_hash_components();
         let saved_env = self.get_expr_hash(saved_env)?.into_hash_components();
         let cont = self.hash_cont(cont)?.into_hash_components();
-        Some([saved_env, fun, cont, def])
+        let reversed_args = self.get_expr_hash(reversed_args)?.into_hash_components();
+        Some([saved_env, fun, cont, reversed_args])
     }
 
     fn get_hash_components_emit(&self, cont: &ContPtr<F>) -> Option<[[F; 2]; 4]> {
@@ -2401,6 +3116,34 @@ impl<F: LurkField> Store<F> {
         self.poseidon_cache.hash4(&preimage)
     }
 
+    /// Returns the running hash of the `emitted` values, in order, which proofs of an evaluation
+    /// emitting them bind as a public input. It is zero when nothing is emitted.
+    pub fn emitted_hash(&self, emitted: &[Ptr<F>]) -> Option<F> {
+        emitted
+            .iter()
+            .try_fold(F::zero(), |hash, value| self.hash_emitted(hash, value))
+    }
+
+    /// Returns the running hash of values emitted in order, given by their hashes. A verifier
+    /// who only knows the hashes of the emitted values, which cannot all be read back from their
+    /// printed form, checks a proof with this.
+    pub fn emitted_scalars_hash(&self, emitted: &[ScalarPtr<F>]) -> F {
+        emitted.iter().fold(F::zero(), |hash, value| {
+            self.hash_emitted_scalar(hash, value)
+        })
+    }
+
+    // Adds a value to the running hash of the values emitted before it.
+    pub(crate) fn hash_emitted(&self, hash: F, value: &Ptr<F>) -> Option<F> {
+        let value = self.get_expr_hash(value)?;
+        Some(self.hash_emitted_scalar(hash, &value))
+    }
+
+    fn hash_emitted_scalar(&self, hash: F, value: &ScalarPtr<F>) -> F {
+        let preimage = [ContTag::Emit.as_field::<F>(), hash, value.0, value.1];
+        self.poseidon_cache.hash4(&preimage)
+    }
+
     fn get_hash_comm(&self, comm: Ptr<F>) -> Option<ScalarPtr<F>> {
         if comm.is_opaque() {
             return Some(
@@ -2451,6 +3194,17 @@ impl<F: LurkField> Store<F> {
         Some(self.get_scalar_ptr(ptr, n.into_scalar()))
     }
 
+    fn hash_u64(&self, ptr: Ptr<F>) -> Option<ScalarPtr<F>> {
+        let n = self.fetch_u64(&ptr)?;
+
+        Some(self.create_scalar_ptr(ptr, F::from(*n)))
+    }
+    fn get_hash_u64(&self, ptr: Ptr<F>) -> Option<ScalarPtr<F>> {
+        let n = self.fetch_u64(&ptr)?;
+
+        Some(self.get_scalar_ptr(ptr, F::from(*n)))
+    }
+
     fn hash_string(&self, s: &str) -> F {
         if s.is_empty() {
             return F::zero();
@@ -2585,6 +3339,10 @@ impl<F: LurkField> Store<F> {
         ScalarPtr(op.as_field(), F::zero())
     }
 
+    fn hash_error_code(&self, code: &ErrorCode) -> ScalarPtr<F> {
+        ScalarPtr(code.as_field(), F::zero())
+    }
+
     // An opaque Ptr is one for which we have the hash, but not the preimages.
     // So we cannot open or traverse the enclosed data, but we can manipulate
     // it atomically and include it in containing structures, etc.
@@ -2688,6 +3446,10 @@ impl<F: LurkField> Expression<'_, F> {
     pub fn is_num(&self) -> bool {
         matches!(self, Self::Num(_))
     }
+
+    pub fn is_u64(&self) -> bool {
+        matches!(self, Self::U64(_))
+    }
     pub fn is_str(&self) -> bool {
         matches!(self, Self::Str(_))
     }
@@ -2728,6 +3490,7 @@ pub mod test {
                 (100, Box::new(|_| Tag::Num)),
                 (100, Box::new(|_| Tag::Thunk)),
                 (100, Box::new(|_| Tag::Str)),
+                (100, Box::new(|_| Tag::U64)),
             ];
             frequency(g, input)
         }
@@ -2752,6 +3515,13 @@ pub mod test {
                 (100, Box::new(|_| ContTag::Dummy)),
                 (100, Box::new(|_| ContTag::Terminal)),
                 (100, Box::new(|_| ContTag::Emit)),
+                (100, Box::new(|_| ContTag::And)),
+                (100, Box::new(|_| ContTag::Or)),
+                (100, Box::new(|_| ContTag::Eval)),
+                (100, Box::new(|_| ContTag::Fail)),
+                (100, Box::new(|_| ContTag::Apply)),
+                (100, Box::new(|_| ContTag::Catch)),
+                (100, Box::new(|_| ContTag::Throw)),
             ];
             frequency(g, input)
         }
@@ -2828,6 +3598,17 @@ pub mod test {
                 (100, Box::new(|_| Op1::Commit)),
                 (100, Box::new(|_| Op1::Num)),
                 (100, Box::new(|_| Op1::Comm)),
+                (100, Box::new(|_| Op1::U64)),
+                (100, Box::new(|_| Op1::TypeOf)),
+                (100, Box::new(|_| Op1::IsNil)),
+                (100, Box::new(|_| Op1::IsCons)),
+                (100, Box::new(|_| Op1::IsSym)),
+                (100, Box::new(|_| Op1::IsFun)),
+                (100, Box::new(|_| Op1::IsNum)),
+                (100, Box::new(|_| Op1::IsStr)),
+                (100, Box::new(|_| Op1::IsChar)),
+                (100, Box::new(|_| Op1::IsComm)),
+                (100, Box::new(|_| Op1::IsU64)),
             ];
             frequency(g, input)
         }
@@ -2863,6 +3644,9 @@ pub mod test {
                 (100, Box::new(|_| Op2::Quotient)),
                 (100, Box::new(|_| Op2::Cons)),
                 (100, Box::new(|_| Op2::Hide)),
+                (100, Box::new(|_| Op2::Modulo)),
+                (100, Box::new(|_| Op2::IntQuotient)),
+                (100, Box::new(|_| Op2::StrCons)),
             ];
             frequency(g, input)
         }
@@ -2894,6 +3678,10 @@ pub mod test {
             let input: Vec<(i64, Box<dyn Fn(&mut Gen) -> Rel2>)> = vec![
                 (100, Box::new(|_| Rel2::Equal)),
                 (100, Box::new(|_| Rel2::NumEqual)),
+                (100, Box::new(|_| Rel2::Less)),
+                (100, Box::new(|_| Rel2::Greater)),
+                (100, Box::new(|_| Rel2::LessEqual)),
+                (100, Box::new(|_| Rel2::GreaterEqual)),
             ];
             frequency(g, input)
         }
@@ -2920,6 +3708,60 @@ pub mod test {
         );
     }
 
+    impl Arbitrary for ErrorCode {
+        fn arbitrary(g: &mut Gen) -> Self {
+            let input: Vec<(i64, Box<dyn Fn(&mut Gen) -> ErrorCode>)> = vec![
+                (100, Box::new(|_| ErrorCode::Unknown)),
+                (100, Box::new(|_| ErrorCode::UnboundVariable)),
+                (100, Box::new(|_| ErrorCode::InvalidForm)),
+                (100, Box::new(|_| ErrorCode::InvalidCall)),
+                (100, Box::new(|_| ErrorCode::InvalidArgument)),
+                (100, Box::new(|_| ErrorCode::UserFailure)),
+            ];
+            frequency(g, input)
+        }
+    }
+
+    #[quickcheck]
+    fn prop_error_code_ipld(x: ErrorCode) -> bool {
+        if let Ok(ipld) = to_ipld(x) {
+            if let Ok(y) = from_ipld(ipld) {
+                x == y
+            } else {
+                false
+            }
+        } else {
+            false
+        }
+    }
+
+    #[test]
+    fn error_cont_hashes_code_and_expr() {
+        let mut store = Store::<Fr>::default();
+        let x = store.sym("x");
+        let y = store.sym("y");
+
+        let unbound_x = store.intern_cont_error_with(ErrorCode::UnboundVariable, x);
+        let unbound_y = store.intern_cont_error_with(ErrorCode::UnboundVariable, y);
+        let invalid_x = store.intern_cont_error_with(ErrorCode::InvalidForm, x);
+        store.hydrate_scalar_cache();
+
+        let unbound_x_hash = store.hash_cont(&unbound_x).unwrap();
+        assert_ne!(unbound_x_hash, store.hash_cont(&unbound_y).unwrap());
+        assert_ne!(unbound_x_hash, store.hash_cont(&invalid_x).unwrap());
+        assert_ne!(
+            unbound_x_hash,
+            store.hash_cont(&store.get_cont_error()).unwrap()
+        );
+        assert_eq!(
+            Some(Continuation::Error {
+                code: ErrorCode::UnboundVariable,
+                expr: x
+            }),
+            store.fetch_cont(&unbound_x)
+        );
+    }
+
     #[test]
     fn test_print_num() {
         let mut store = Store::<Fr>::default();
@@ -2939,6 +3781,7 @@ pub mod test {
         assert_eq!(6, Tag::Str as u64);
         assert_eq!(7, Tag::Char as u64);
         assert_eq!(8, Tag::Comm as u64);
+        assert_eq!(9, Tag::U64 as u64);
     }
 
     #[test]
@@ -2962,6 +3805,14 @@ pub mod test {
         assert_eq!(0b0001_0000_0000_1110, LetRec as u16);
         assert_eq!(0b0001_0000_0000_1111, Dummy as u16);
         assert_eq!(0b0001_0000_0001_0000, Terminal as u16);
+        assert_eq!(0b0001_0000_0001_0001, Emit as u16);
+        assert_eq!(0b0001_0000_0001_0010, And as u16);
+        assert_eq!(0b0001_0000_0001_0011, Or as u16);
+        assert_eq!(0b0001_0000_0001_0100, Eval as u16);
+        assert_eq!(0b0001_0000_0001_0101, Fail as u16);
+        assert_eq!(0b0001_0000_0001_0110, Apply as u16);
+        assert_eq!(0b0001_0000_0001_0111, Catch as u16);
+        assert_eq!(0b0001_0000_0001_1000, Throw as u16);
     }
 
     #[test]
@@ -2975,6 +3826,24 @@ pub mod test {
         assert_eq!(num, num_again);
     }
 
+    #[test]
+    fn u64_is_distinct_from_num() {
+        let mut store = Store::<Fr>::default();
+
+        let num = store.num(123);
+        let n = store.uint64(123);
+
+        assert_eq!(Some(Expression::U64(123)), store.fetch(&n));
+        assert_eq!(Some(n), store.get_u64(123));
+
+        let num_hash = store.hash_expr(&num).unwrap();
+        let n_hash = store.hash_expr(&n).unwrap();
+
+        assert_eq!(num_hash.value(), n_hash.value());
+        assert_ne!(num_hash, n_hash);
+        assert!(!store.ptr_eq(&num, &n));
+    }
+
     #[test]
     fn equality() {
         let mut store = Store::<Fr>::default();
@@ -53,13 +53,21 @@ impl<F: LurkField> Write<F> for Expression<'_, F> {
             Str(s) => write!(w, "\"{}\"", s),
             Fun(arg, body, _closed_env) => {
                 let is_zero_arg = *arg == store.get_sym("_", true).expect("dummy_arg (_) missing");
-                let arg = store.fetch(arg).unwrap();
                 let body = store.fetch(body).unwrap();
-                write!(w, "<FUNCTION (")?;
-                if !is_zero_arg {
-                    arg.fmt(store, w)?;
+                if arg.is_cons() {
+                    // A variadic function's argument is the list of its rest parameter.
+                    let rest = store.fetch(&store.car(arg)).unwrap();
+                    write!(w, "<FUNCTION ")?;
+                    rest.fmt(store, w)?;
+                    write!(w, " ")?;
+                } else {
+                    let arg = store.fetch(arg).unwrap();
+                    write!(w, "<FUNCTION (")?;
+                    if !is_zero_arg {
+                        arg.fmt(store, w)?;
+                    }
+                    write!(w, ") ")?;
                 }
-                write!(w, ") ")?;
                 assert!(body.is_cons(), "Fun body should be a non-empty list.");
                 body.print_tail(store, w)?;
                 write!(w, ">")
@@ -72,9 +80,26 @@ impl<F: LurkField> Write<F> for Expression<'_, F> {
                 f.continuation.fmt(store, w)?;
                 write!(w, "}}")
             }
-            Cons(_, _) => {
-                write!(w, "(")?;
-                self.print_tail(store, w)
+            Cons(car, cdr) => {
+                // Templates are printed back with the syntax they were read from.
+                let prefix = [
+                    ("quasiquote", "`"),
+                    ("unquote", ","),
+                    ("unquote-splicing", ",@"),
+                ]
+                .iter()
+                .find(|(name, _)| store.get_sym(name, true) == Some(*car))
+                .map(|(_, prefix)| prefix);
+                match (prefix, store.fetch(cdr)) {
+                    (Some(prefix), Some(Cons(arg, end))) if end.is_nil() => {
+                        write!(w, "{}", prefix)?;
+                        arg.fmt(store, w)
+                    }
+                    _ => {
+                        write!(w, "(")?;
+                        self.print_tail(store, w)
+                    }
+                }
             }
             Comm(secret, payload) => {
                 // This requires a run-time coercion.
@@ -88,6 +113,7 @@ impl<F: LurkField> Write<F> for Expression<'_, F> {
             Char(c) => {
                 write!(w, "#\\{}", c)
             }
+            U64(n) => write!(w, "{}u64", n),
         }
     }
 }
@@ -148,15 +174,24 @@ impl<F: LurkField> Write<F> for Continuation<F> {
     fn fmt<W: io::Write>(&self, store: &Store<F>, w: &mut W) -> io::Result<()> {
         match self {
             Continuation::Outermost => write!(w, "Outermost"),
-            Continuation::Call0 { continuation } => {
+            Continuation::Call0 {
+                continuation,
+                reversed_args,
+                collected_args,
+            } => {
                 write!(w, "Call0{{ continuation: ")?;
                 continuation.fmt(store, w)?;
+                write!(w, ", reversed_args: ")?;
+                reversed_args.fmt(store, w)?;
+                write!(w, ", collected_args: ")?;
+                collected_args.fmt(store, w)?;
                 write!(w, " }}")
             }
             Continuation::Call {
                 unevaled_arg,
                 saved_env,
                 continuation,
+                reversed_args,
             } => {
                 write!(w, "Call{{ unevaled_arg: ")?;
                 unevaled_arg.fmt(store, w)?;
@@ -164,12 +199,15 @@ impl<F: LurkField> Write<F> for Continuation<F> {
                 saved_env.fmt(store, w)?;
                 write!(w, ", continuation: ")?;
                 continuation.fmt(store, w)?;
+                write!(w, ", reversed_args: ")?;
+                reversed_args.fmt(store, w)?;
                 write!(w, " }}")
             }
             Continuation::Call2 {
                 function,
                 saved_env,
                 continuation,
+                reversed_args,
             } => {
                 write!(w, "Call2{{ function: ")?;
                 function.fmt(store, w)?;
@@ -177,6 +215,8 @@ impl<F: LurkField> Write<F> for Continuation<F> {
                 saved_env.fmt(store, w)?;
                 write!(w, ", continuation: ")?;
                 continuation.fmt(store, w)?;
+                write!(w, ", reversed_args: ")?;
+                reversed_args.fmt(store, w)?;
                 write!(w, " }}")
             }
             Continuation::Tail {
@@ -189,7 +229,11 @@ impl<F: LurkField> Write<F> for Continuation<F> {
                 continuation.fmt(store, w)?;
                 write!(w, " }}")
             }
-            Continuation::Error => write!(w, "Error"),
+            Continuation::Error { code, expr } => {
+                write!(w, "Error{{ code: {}, expr: ", code)?;
+                expr.fmt(store, w)?;
+                write!(w, " }}")
+            }
             Continuation::Lookup {
                 saved_env,
                 continuation,
@@ -226,10 +270,13 @@ impl<F: LurkField> Write<F> for Continuation<F> {
             Continuation::Binop2 {
                 operator,
                 evaled_arg,
+                unevaled_args,
                 continuation,
             } => {
                 write!(w, "Binop2{{ operator: {}, evaled_arg: ", operator)?;
                 evaled_arg.fmt(store, w)?;
+                write!(w, ", unevaled_args: ")?;
+                unevaled_args.fmt(store, w)?;
                 write!(w, ", continuation: ")?;
                 continuation.fmt(store, w)?;
                 write!(w, " }}")
@@ -251,10 +298,13 @@ impl<F: LurkField> Write<F> for Continuation<F> {
             Continuation::Relop2 {
                 operator,
                 evaled_arg,
+                unevaled_args,
                 continuation,
             } => {
                 write!(w, "Relop2{{ operator: {}, evaled_ag: ", operator)?;
                 evaled_arg.fmt(store, w)?;
+                write!(w, ", unevaled_args: ")?;
+                unevaled_args.fmt(store, w)?;
                 write!(w, ", continuation: ")?;
                 continuation.fmt(store, w)?;
                 write!(w, " }}")
@@ -301,6 +351,7 @@ impl<F: LurkField> Write<F> for Continuation<F> {
                 continuation.fmt(store, w)?;
                 write!(w, " }}")
             }
+            Continuation::Fail => write!(w, "Fail"),
             Continuation::Dummy => write!(w, "Dummy"),
             Continuation::Terminal => write!(w, "Terminal"),
             Continuation::Emit {
@@ -312,6 +363,72 @@ impl<F: LurkField> Write<F> for Continuation<F> {
                                             // continuation.fmt(store, w)?;
                                             // write!(w, " }}")
             }
+            Continuation::And {
+                unevaled_args,
+                continuation,
+            } => {
+                write!(w, "And{{ unevaled_args: ")?;
+                unevaled_args.fmt(store, w)?;
+                write!(w, ", continuation: ")?;
+                continuation.fmt(store, w)?;
+                write!(w, " }}")
+            }
+            Continuation::Or {
+                unevaled_args,
+                continuation,
+            } => {
+                write!(w, "Or{{ unevaled_args: ")?;
+                unevaled_args.fmt(store, w)?;
+                write!(w, ", continuation: ")?;
+                continuation.fmt(store, w)?;
+                write!(w, " }}")
+            }
+            Continuation::Eval {
+                saved_env,
+                continuation,
+            } => {
+                write!(w, "Eval{{ saved_env: ")?;
+                saved_env.fmt(store, w)?;
+                write!(w, ", continuation: ")?;
+                continuation.fmt(store, w)?;
+                write!(w, " }}")
+            }
+            Continuation::Apply {
+                unevaled_args,
+                saved_env,
+                continuation,
+                reversed_args,
+            } => {
+                write!(w, "Apply{{ unevaled_args: ")?;
+                unevaled_args.fmt(store, w)?;
+                write!(w, ", saved_env: ")?;
+                saved_env.fmt(store, w)?;
+                write!(w, ", continuation: ")?;
+                continuation.fmt(store, w)?;
+                write!(w, ", reversed_args: ")?;
+                reversed_args.fmt(store, w)?;
+                write!(w, " }}")
+            }
+            Continuation::Catch {
+                tag,
+                saved_env,
+                continuation,
+            } => {
+                write!(w, "Catch{{ tag: ")?;
+                tag.fmt(store, w)?;
+                write!(w, ", saved_env: ")?;
+                saved_env.fmt(store, w)?;
+                write!(w, ", continuation: ")?;
+                continuation.fmt(store, w)?;
+                write!(w, " }}")
+            }
+            Continuation::Throw { tag, continuation } => {
+                write!(w, "Throw{{ tag: ")?;
+                tag.fmt(store, w)?;
+                write!(w, ", continuation: ")?;
+                continuation.fmt(store, w)?;
+                write!(w, " }}")
+            }
         }
     }
 }